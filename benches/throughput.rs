@@ -0,0 +1,131 @@
+//! Throughput benchmarks of the hot paths of `ConcurrentOption` on 1 to 16 threads.
+//!
+//! Run with `cargo bench --features bench`.
+//!
+//! Each benchmark group measures one operation for every number of threads; in each iteration,
+//! every thread performs the operation once. The reported throughput is the total number of
+//! operations per second over all threads.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use orx_concurrent_option::*;
+use std::{
+    hint::black_box,
+    sync::Barrier,
+    time::{Duration, Instant},
+};
+
+const NUM_THREADS: [usize; 5] = [1, 2, 4, 8, 16];
+
+/// Slot padded to a typical destructive interference size.
+#[repr(align(128))]
+struct Padded<T>(ConcurrentOption<T>);
+
+/// Runs `op` `iters` times on each of the `num_threads` threads, which start together;
+/// and returns the elapsed time until all threads complete.
+fn run<F>(num_threads: usize, iters: u64, op: &F) -> Duration
+where
+    F: Fn(usize, u64) + Sync,
+{
+    let barrier = Barrier::new(num_threads + 1);
+    std::thread::scope(|s| {
+        for t in 0..num_threads {
+            let barrier = &barrier;
+            s.spawn(move || {
+                barrier.wait();
+                for i in 0..iters {
+                    op(t, i);
+                }
+            });
+        }
+
+        barrier.wait();
+        Instant::now()
+    })
+    // the scope joins all threads before returning
+    .elapsed()
+}
+
+fn bench_threads<F>(c: &mut Criterion, name: &str, op: F)
+where
+    F: Fn(usize, u64) + Sync,
+{
+    let mut group = c.benchmark_group(name);
+    for num_threads in NUM_THREADS {
+        group.throughput(Throughput::Elements(num_threads as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(num_threads),
+            &num_threads,
+            |b, &n| b.iter_custom(|iters| run(n, iters, &op)),
+        );
+    }
+    group.finish();
+}
+
+fn initialize_if_none_fast_path(c: &mut Criterion) {
+    let x = ConcurrentOption::some(42u64);
+    bench_threads(c, "initialize_if_none/fast-path", |_, i| {
+        black_box(x.initialize_if_none(i));
+    });
+}
+
+fn contended_take_replace(c: &mut Criterion) {
+    let x = ConcurrentOption::some(0u64);
+    bench_threads(c, "take-replace/contended", |t, i| match t % 2 {
+        0 => _ = black_box(x.replace(i)),
+        _ => _ = black_box(x.take()),
+    });
+}
+
+fn read_combinators(c: &mut Criterion) {
+    let x = ConcurrentOption::some(42u64);
+    bench_threads(c, "map", |_, _| {
+        black_box(x.map(|x| *x));
+    });
+    bench_threads(c, "get_copied", |_, _| {
+        black_box(x.get_copied());
+    });
+    bench_threads(c, "is_some", |_, _| {
+        black_box(x.is_some());
+    });
+}
+
+fn read_orderings(c: &mut Criterion) {
+    let x = ConcurrentOption::some(42u64);
+    let orderings = [
+        ("get_copied_with_order/Relaxed", LoadOrdering::Relaxed),
+        ("get_copied_with_order/Acquire", LoadOrdering::Acquire),
+        ("get_copied_with_order/SeqCst", LoadOrdering::SeqCst),
+    ];
+    for (name, order) in orderings {
+        bench_threads(c, name, |_, _| {
+            black_box(x.get_copied_with_order(order));
+        });
+    }
+}
+
+fn padding(c: &mut Criterion) {
+    let max_threads = NUM_THREADS[NUM_THREADS.len() - 1];
+    let unpadded: Vec<_> = (0..max_threads)
+        .map(|_| ConcurrentOption::some(0u64))
+        .collect();
+    let padded: Vec<_> = (0..max_threads)
+        .map(|_| Padded(ConcurrentOption::some(0u64)))
+        .collect();
+
+    bench_threads(c, "update_if_some/own-slot/unpadded", |t, _| {
+        black_box(unpadded[t].update_if_some(|x| *x += 1));
+    });
+    bench_threads(c, "update_if_some/own-slot/padded", |t, _| {
+        black_box(padded[t].0.update_if_some(|x| *x += 1));
+    });
+}
+
+criterion_group!(
+    benches,
+    initialize_if_none_fast_path,
+    contended_take_replace,
+    read_combinators,
+    read_orderings,
+    padding
+);
+criterion_main!(benches);
@@ -0,0 +1,68 @@
+//! Runs arbitrary sequences of value-moving API calls on real threads and checks that values are conserved:
+//! every inserted value is either taken exactly once or remains in the option at the end.
+//!
+//! Run with `cargo +nightly fuzz run concurrent_conservation` from the repository root.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use orx_concurrent_option::*;
+use std::sync::Mutex;
+
+#[derive(Arbitrary, Debug, Clone, Copy)]
+enum Op {
+    Take,
+    Replace,
+    InitializeIfNone,
+    TakeIfEven,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    threads: Vec<Vec<Op>>,
+}
+
+fuzz_target!(|input: Input| {
+    let threads: Vec<_> = input.threads.into_iter().take(8).collect();
+
+    let x = ConcurrentOption::<u64>::none();
+    let inserted = Mutex::new(Vec::new());
+    let removed = Mutex::new(Vec::new());
+
+    std::thread::scope(|s| {
+        for (t, ops) in threads.iter().enumerate() {
+            let (x, inserted, removed) = (&x, &inserted, &removed);
+            s.spawn(move || {
+                let mut my_inserted = vec![];
+                let mut my_removed = vec![];
+                for (i, op) in ops.iter().enumerate() {
+                    let value = ((t as u64) << 32) | i as u64;
+                    match op {
+                        Op::Take => my_removed.extend(x.take()),
+                        Op::Replace => {
+                            my_inserted.push(value);
+                            my_removed.extend(x.replace(value));
+                        }
+                        Op::InitializeIfNone => {
+                            if x.initialize_if_none(value) {
+                                my_inserted.push(value);
+                            }
+                        }
+                        Op::TakeIfEven => my_removed.extend(x.take_if(|v| v % 2 == 0)),
+                    }
+                }
+                inserted.lock().expect("not poisoned").extend(my_inserted);
+                removed.lock().expect("not poisoned").extend(my_removed);
+            });
+        }
+    });
+
+    let mut inserted = inserted.into_inner().expect("not poisoned");
+    let mut removed = removed.into_inner().expect("not poisoned");
+    removed.extend(x.into_option());
+
+    inserted.sort_unstable();
+    removed.sort_unstable();
+    assert_eq!(inserted, removed);
+});
@@ -0,0 +1,120 @@
+//! Drives arbitrary interleavings of API calls issued by several simulated threads against a
+//! `Mutex<Option<T>>` oracle and compares every observable result.
+//!
+//! Run with `cargo +nightly fuzz run state_transitions` from the repository root.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use orx_concurrent_option::*;
+use std::sync::Mutex;
+
+#[derive(Arbitrary, Debug, Clone, Copy)]
+enum Op {
+    IsSome,
+    Take,
+    TakeIf(u8),
+    Replace(u8),
+    SetSome(u8),
+    InitializeIfNone(u8),
+    UpdateIfSome(u8),
+    Map,
+    GetCloned,
+    TakeOrInit(u8),
+    ReplaceWithFn(u8),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Output {
+    Bool(bool),
+    Value(Option<u32>),
+}
+
+/// Step of a simulated thread; the interleaving of the threads is the order of the steps.
+#[derive(Arbitrary, Debug)]
+struct Step {
+    thread: u8,
+    op: Op,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    initial: Option<u8>,
+    steps: Vec<Step>,
+}
+
+fn apply(x: &ConcurrentOption<u32>, op: Op) -> Output {
+    match op {
+        Op::IsSome => Output::Bool(x.is_some()),
+        Op::Take => Output::Value(x.take()),
+        Op::TakeIf(t) => Output::Value(x.take_if(|v| *v >= t as u32)),
+        Op::Replace(v) => Output::Value(x.replace(v as u32)),
+        Op::SetSome(v) => Output::Bool(x.set_some(v as u32)),
+        Op::InitializeIfNone(v) => Output::Bool(x.initialize_if_none(v as u32)),
+        Op::UpdateIfSome(d) => Output::Bool(x.update_if_some(|v| *v += d as u32)),
+        Op::Map => Output::Value(x.map(|v| v * 2)),
+        Op::GetCloned => Output::Value(x.get_cloned()),
+        Op::TakeOrInit(v) => Output::Value(Some(x.take_or_init(|| v as u32))),
+        Op::ReplaceWithFn(d) => {
+            Output::Value(x.replace_with_fn(|old| old.copied().unwrap_or(0) + d as u32))
+        }
+    }
+}
+
+fn apply_model(m: &Mutex<Option<u32>>, op: Op) -> Output {
+    let mut m = m.lock().expect("oracle is never poisoned");
+    match op {
+        Op::IsSome => Output::Bool(m.is_some()),
+        Op::Take => Output::Value(m.take()),
+        Op::TakeIf(t) => Output::Value(m.take_if(|v| *v >= t as u32)),
+        Op::Replace(v) => Output::Value(m.replace(v as u32)),
+        Op::SetSome(v) => Output::Bool(m.replace(v as u32).is_some()),
+        Op::InitializeIfNone(v) => Output::Bool(match m.is_none() {
+            true => {
+                *m = Some(v as u32);
+                true
+            }
+            false => false,
+        }),
+        Op::UpdateIfSome(d) => Output::Bool(match m.as_mut() {
+            Some(v) => {
+                *v += d as u32;
+                true
+            }
+            None => false,
+        }),
+        Op::Map => Output::Value(m.map(|v| v * 2)),
+        Op::GetCloned => Output::Value(*m),
+        Op::TakeOrInit(v) => Output::Value(Some(m.take().unwrap_or(v as u32))),
+        Op::ReplaceWithFn(d) => {
+            let new = m.unwrap_or(0) + d as u32;
+            Output::Value(m.replace(new))
+        }
+    }
+}
+
+fuzz_target!(|input: Input| {
+    let initial = input.initial.map(|v| v as u32);
+    let x = ConcurrentOption::from(initial);
+    let model = Mutex::new(initial);
+
+    for (i, step) in input.steps.iter().enumerate() {
+        let actual = apply(&x, step.op);
+        let expected = apply_model(&model, step.op);
+        assert_eq!(
+            actual, expected,
+            "divergence at step {} of simulated thread {}: {:?}",
+            i, step.thread, step.op
+        );
+        assert_eq!(
+            x.get_cloned(),
+            *model.lock().expect("oracle is never poisoned")
+        );
+    }
+
+    assert_eq!(
+        x.into_option(),
+        model.into_inner().expect("oracle is never poisoned")
+    );
+});
@@ -0,0 +1,131 @@
+use crate::ConcurrentOption;
+use core::iter::Sum;
+
+impl<T> ConcurrentOption<T> {
+    /// Thread safe method to sum up the values of the `options` which are of Some variant;
+    /// None options are skipped.
+    ///
+    /// Each value is read while it cannot be mutated, the same as [`map`].
+    /// Note that the options are read one after the other; hence, the result is not a consistent snapshot
+    /// of the entire slice when other threads concurrently mutate the options.
+    ///
+    /// [`map`]: ConcurrentOption::map
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let options = [
+    ///     ConcurrentOption::some(3),
+    ///     ConcurrentOption::none(),
+    ///     ConcurrentOption::some(7),
+    /// ];
+    /// assert_eq!(ConcurrentOption::sum_some(&options), 10);
+    ///
+    /// let options: [ConcurrentOption<f64>; 0] = [];
+    /// assert_eq!(ConcurrentOption::sum_some(&options), 0.0);
+    /// ```
+    pub fn sum_some(options: &[Self]) -> T
+    where
+        T: Copy + Sum<T>,
+    {
+        options.iter().filter_map(|x| x.map(|x| *x)).sum()
+    }
+
+    /// Thread safe method to return the maximum of the values of the `options` which are of Some variant;
+    /// returns None if all options are None.
+    ///
+    /// If several values are equally maximum, the last one is returned, the same as [`Iterator::max`].
+    ///
+    /// Each value is read while it cannot be mutated, the same as [`map`].
+    /// Note that the options are read one after the other; hence, the result is not a consistent snapshot
+    /// of the entire slice when other threads concurrently mutate the options.
+    ///
+    /// [`map`]: ConcurrentOption::map
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let options = [
+    ///     ConcurrentOption::some(3),
+    ///     ConcurrentOption::none(),
+    ///     ConcurrentOption::some(7),
+    /// ];
+    /// assert_eq!(ConcurrentOption::max_some(&options), Some(7));
+    ///
+    /// let options = [ConcurrentOption::<i32>::none()];
+    /// assert_eq!(ConcurrentOption::max_some(&options), None);
+    /// ```
+    pub fn max_some(options: &[Self]) -> Option<T>
+    where
+        T: Copy + Ord,
+    {
+        options.iter().filter_map(|x| x.map(|x| *x)).max()
+    }
+
+    /// Thread safe method to return the minimum of the values of the `options` which are of Some variant;
+    /// returns None if all options are None.
+    ///
+    /// If several values are equally minimum, the first one is returned, the same as [`Iterator::min`].
+    ///
+    /// Each value is read while it cannot be mutated, the same as [`map`].
+    /// Note that the options are read one after the other; hence, the result is not a consistent snapshot
+    /// of the entire slice when other threads concurrently mutate the options.
+    ///
+    /// [`map`]: ConcurrentOption::map
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let options = [
+    ///     ConcurrentOption::some(3),
+    ///     ConcurrentOption::none(),
+    ///     ConcurrentOption::some(7),
+    /// ];
+    /// assert_eq!(ConcurrentOption::min_some(&options), Some(3));
+    /// ```
+    pub fn min_some(options: &[Self]) -> Option<T>
+    where
+        T: Copy + Ord,
+    {
+        options.iter().filter_map(|x| x.map(|x| *x)).min()
+    }
+
+    /// Thread safe method to count the `options` which are of Some variant and whose values satisfy the `predicate`.
+    ///
+    /// The `predicate` is called once for each option of Some variant, while its value cannot be mutated,
+    /// the same as [`map`]. Note that the options are read one after the other; hence, the result is not
+    /// a consistent snapshot of the entire slice when other threads concurrently mutate the options.
+    ///
+    /// [`map`]: ConcurrentOption::map
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let options = [
+    ///     ConcurrentOption::some("alpha".to_string()),
+    ///     ConcurrentOption::none(),
+    ///     ConcurrentOption::some("beta".to_string()),
+    ///     ConcurrentOption::some("gamma".to_string()),
+    /// ];
+    ///
+    /// let num_long = ConcurrentOption::count_matching(&options, |x| x.len() > 4);
+    /// assert_eq!(num_long, 2);
+    /// ```
+    pub fn count_matching<P>(options: &[Self], mut predicate: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        options
+            .iter()
+            .filter(|x| x.map(&mut predicate).unwrap_or(false))
+            .count()
+    }
+}
@@ -0,0 +1,143 @@
+use crate::ConcurrentOption;
+use core::{
+    fmt::Debug,
+    mem::{align_of, size_of},
+    ops::{Deref, DerefMut},
+};
+
+/// A zero-sized marker type defining the alignment of an [`Aligned`] payload.
+///
+/// It is implemented by the marker types [`Align16`], [`Align32`], [`Align64`], [`Align128`] and [`Align4096`].
+pub trait Alignment: sealed::Sealed + Copy + Default {
+    /// The alignment in bytes.
+    const ALIGN: usize;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! alignment {
+    ($($name:ident = $align:literal),* $(,)?) => {
+        $(
+            #[doc = concat!("Marker of ", stringify!($align), "-byte alignment; see [`Aligned`].")]
+            #[repr(align($align))]
+            #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+            pub struct $name;
+
+            impl sealed::Sealed for $name {}
+
+            impl Alignment for $name {
+                const ALIGN: usize = $align;
+            }
+
+            const _: () = assert!(size_of::<$name>() == 0 && align_of::<$name>() == $align);
+        )*
+    };
+}
+
+alignment!(
+    Align16 = 16,
+    Align32 = 32,
+    Align64 = 64,
+    Align128 = 128,
+    Align4096 = 4096,
+);
+
+/// A payload over-aligned to the alignment of `A`, such as [`Align64`], for SIMD or DMA use cases.
+///
+/// The wrapper is `repr(C)` and holds the value at offset zero; an additional zero-sized field of type `[A; 0]`
+/// raises its alignment to the maximum of the alignments of `T` and `A` without changing its contents.
+/// Its size is the size of `T` rounded up to this alignment.
+///
+/// A [`ConcurrentOption`] of an aligned payload, aliased as [`AlignedConcurrentOption`], stores the payload at an address
+/// which is a multiple of the alignment. The state and the remaining bookkeeping fields of the option, a few bytes in total,
+/// are placed together in the alignment block following the payload; hence, the size of the option is the size of the
+/// aligned payload plus a single alignment block, unless the bookkeeping exceeds the alignment.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// let x: AlignedConcurrentOption<[f32; 8], Align32> = ConcurrentOption::some(Aligned::new([1.0; 8]));
+///
+/// let address = x.map(|x| x as *const Aligned<[f32; 8], Align32> as usize).unwrap();
+/// assert_eq!(address % 32, 0);
+///
+/// x.update_if_some(|x| x[0] = 2.0);
+/// assert_eq!(x.map(|x| x[0]), Some(2.0));
+/// assert_eq!(x.take().map(Aligned::into_inner), Some([2.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0]));
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Aligned<T, A: Alignment> {
+    value: T,
+    _align: [A; 0],
+}
+
+/// A [`ConcurrentOption`] whose payload is over-aligned to the alignment of `A`; see [`Aligned`] for the layout.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+pub type AlignedConcurrentOption<T, A> = ConcurrentOption<Aligned<T, A>>;
+
+impl<T, A: Alignment> Aligned<T, A> {
+    /// Wraps the `value` as an over-aligned payload.
+    pub const fn new(value: T) -> Self {
+        Self { value, _align: [] }
+    }
+
+    /// Unwraps the payload.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, A: Alignment> From<T> for Aligned<T, A> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, A: Alignment> Deref for Aligned<T, A> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, A: Alignment> DerefMut for Aligned<T, A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T: Debug, A: Alignment> Debug for Aligned<T, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Aligned").field(&self.value).finish()
+    }
+}
+
+// layout of aligned payloads
+const _: () = assert!(align_of::<Aligned<u8, Align64>>() == 64);
+const _: () = assert!(size_of::<Aligned<u8, Align64>>() == 64);
+const _: () = assert!(size_of::<Aligned<[u8; 65], Align64>>() == 128);
+const _: () = assert!(align_of::<Aligned<u16, Align16>>() == 16);
+const _: () = assert!(align_of::<Aligned<Aligned<u8, Align64>, Align16>>() == 64);
+const _: () = assert!(align_of::<Aligned<[u8; 4096], Align4096>>() == 4096);
+
+// layout of concurrent options of aligned payloads:
+// the payload block is followed by the bookkeeping fields, padded only up to the next alignment block
+const fn aligned_option_size(payload_size: usize, align: usize) -> usize {
+    let bookkeeping = size_of::<ConcurrentOption<()>>();
+    payload_size.next_multiple_of(align) + bookkeeping.next_multiple_of(align)
+}
+const _: () = assert!(align_of::<AlignedConcurrentOption<u8, Align64>>() == 64);
+const _: () =
+    assert!(size_of::<AlignedConcurrentOption<u8, Align64>>() == aligned_option_size(1, 64));
+const _: () = assert!(
+    size_of::<AlignedConcurrentOption<[u8; 100], Align128>>() == aligned_option_size(100, 128)
+);
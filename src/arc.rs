@@ -0,0 +1,128 @@
+use crate::ConcurrentOption;
+use alloc::sync::{Arc, Weak};
+use core::mem::MaybeUninit;
+
+/// A [`ConcurrentOption`] shared across threads by an [`Arc`]; created by [`ConcurrentOption::shared`] or
+/// [`ConcurrentOption::shared_none`].
+///
+/// Cloning the shared option is cheap: it only increments the reference count of the Arc,
+/// and all clones refer to the same option.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+pub type SharedOption<T> = Arc<ConcurrentOption<T>>;
+
+impl<T> ConcurrentOption<T> {
+    /// Creates a concurrent option of the Some variant with the given `value`, which is shared by an [`Arc`].
+    ///
+    /// This is a shorthand for `Arc::new(ConcurrentOption::some(value))`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x: SharedOption<String> = ConcurrentOption::shared(3.to_string());
+    ///
+    /// let y = x.clone();
+    /// std::thread::spawn(move || y.update_if_some(|x| x.push('!')))
+    ///     .join()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(x.get_cloned(), Some("3!".to_string()));
+    /// ```
+    pub fn shared(value: T) -> SharedOption<T> {
+        Arc::new(Self::some(value))
+    }
+
+    /// Creates a concurrent option of the None variant, which is shared by an [`Arc`].
+    ///
+    /// This is a shorthand for `Arc::new(ConcurrentOption::none())`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::shared_none();
+    ///
+    /// let handles: Vec<_> = (0..4)
+    ///     .map(|i| {
+    ///         let x = x.clone();
+    ///         std::thread::spawn(move || x.initialize_if_none(i))
+    ///     })
+    ///     .collect();
+    /// let num_initialized = handles
+    ///     .into_iter()
+    ///     .map(|h| h.join().unwrap())
+    ///     .filter(|x| *x)
+    ///     .count();
+    ///
+    /// assert_eq!(num_initialized, 1);
+    /// assert!(x.is_some());
+    /// ```
+    pub fn shared_none() -> SharedOption<T> {
+        Arc::new(Self::none())
+    }
+}
+
+impl<T> ConcurrentOption<Arc<T>> {
+    /// Thread safe method which creates a [`Weak`] pointer to the contained Arc (if any).
+    ///
+    /// The weak pointer is created while holding a read handle on the option; hence, the Arc cannot be
+    /// concurrently taken or replaced during the downgrade.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use std::sync::Arc;
+    ///
+    /// let x = ConcurrentOption::some(Arc::new(42));
+    /// let weak = x.downgrade().unwrap();
+    /// assert_eq!(weak.upgrade().as_deref(), Some(&42));
+    ///
+    /// _ = x.take();
+    /// assert!(weak.upgrade().is_none());
+    /// assert!(x.downgrade().is_none());
+    /// ```
+    pub fn downgrade(&self) -> Option<Weak<T>> {
+        match self.spin_get_read_handle() {
+            Some(_handle) => {
+                let x = unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) };
+                Some(Arc::downgrade(x))
+            }
+            None => None,
+        }
+    }
+
+    /// Thread safe method which reinstalls the Arc pointed to by `weak` into the option
+    /// if the option is None and the value is still alive.
+    ///
+    /// Returns whether or not the option is initialized by the upgraded Arc.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use std::sync::Arc;
+    ///
+    /// let x = ConcurrentOption::some(Arc::new(42));
+    /// let weak = x.downgrade().unwrap();
+    ///
+    /// let evicted = x.take().unwrap();
+    /// assert!(x.upgrade_into(&weak));
+    /// assert_eq!(x.map(|x| **x), Some(42));
+    /// assert!(!x.upgrade_into(&weak)); // already some
+    ///
+    /// _ = x.take();
+    /// drop(evicted);
+    /// assert!(!x.upgrade_into(&weak)); // no longer alive
+    /// assert!(x.is_none());
+    /// ```
+    pub fn upgrade_into(&self, weak: &Weak<T>) -> bool {
+        match weak.upgrade() {
+            Some(arc) => self.initialize_if_none(arc),
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{ConcurrentOption, IntoOption};
+use alloc::sync::Arc;
+use arc_swap::ArcSwapOption;
+
+impl<T> From<ConcurrentOption<Arc<T>>> for ArcSwapOption<T> {
+    /// Converts the concurrent option into an `ArcSwapOption` holding the same Arc (if any).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use arc_swap::ArcSwapOption;
+    /// use std::sync::Arc;
+    ///
+    /// let arc = Arc::new(42);
+    /// let x = ConcurrentOption::some(arc.clone());
+    ///
+    /// let y: ArcSwapOption<i32> = x.into();
+    /// assert!(Arc::ptr_eq(&y.load_full().unwrap(), &arc));
+    /// ```
+    fn from(value: ConcurrentOption<Arc<T>>) -> Self {
+        ArcSwapOption::new(value.into_option())
+    }
+}
+
+impl<T> From<ArcSwapOption<T>> for ConcurrentOption<Arc<T>> {
+    /// Converts the `ArcSwapOption` into a concurrent option holding the same Arc (if any).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use arc_swap::ArcSwapOption;
+    /// use std::sync::Arc;
+    ///
+    /// let arc = Arc::new(42);
+    /// let x = ArcSwapOption::new(Some(arc.clone()));
+    ///
+    /// let y: ConcurrentOption<Arc<i32>> = x.into();
+    /// assert!(Arc::ptr_eq(&y.unwrap(), &arc));
+    /// ```
+    fn from(value: ArcSwapOption<T>) -> Self {
+        value.into_inner().into()
+    }
+}
+
+/// A view of a `ConcurrentOption<Arc<T>>` with the methods of `arc_swap::ArcSwapOption<T>`,
+/// created by [`ConcurrentOption::as_arc_swap`].
+///
+/// It allows code written against the `ArcSwapOption` API to work on a concurrent option with the same semantics:
+/// * `load_full` returns a clone of the current Arc (if any),
+/// * `store` replaces the current Arc,
+/// * `swap` replaces the current Arc and returns the previous one.
+///
+/// [`ConcurrentOption::as_arc_swap`]: crate::ConcurrentOption::as_arc_swap
+pub struct ArcSwapAdapter<'a, T> {
+    option: &'a ConcurrentOption<Arc<T>>,
+}
+
+impl<T> ArcSwapAdapter<'_, T> {
+    /// Returns a clone of the current Arc (if any); corresponds to `ArcSwapOption::load_full`.
+    pub fn load_full(&self) -> Option<Arc<T>> {
+        self.option.get_cloned()
+    }
+
+    /// Replaces the current Arc with the `value`, dropping the previous Arc (if any);
+    /// corresponds to `ArcSwapOption::store`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen by [`freeze`], or if it is in a custom state set by [`transition_custom`],
+    /// and the `value` is Some.
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
+    pub fn store(&self, value: Option<Arc<T>>) {
+        _ = self.swap(value);
+    }
+
+    /// Replaces the current Arc with the `value` and returns the previous Arc (if any);
+    /// corresponds to `ArcSwapOption::swap`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen by [`freeze`], or if it is in a custom state set by [`transition_custom`],
+    /// and the `value` is Some.
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
+    pub fn swap(&self, value: Option<Arc<T>>) -> Option<Arc<T>> {
+        match value {
+            Some(value) => self.option.replace(value),
+            None => self.option.take(),
+        }
+    }
+}
+
+impl<T> ConcurrentOption<Arc<T>> {
+    /// Returns an [`ArcSwapAdapter`] providing the `arc_swap::ArcSwapOption` API over this option.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use std::sync::Arc;
+    ///
+    /// let x = ConcurrentOption::<Arc<String>>::none();
+    /// let y = x.as_arc_swap();
+    ///
+    /// y.store(Some(Arc::new(3.to_string())));
+    /// assert_eq!(y.load_full(), Some(Arc::new(3.to_string())));
+    ///
+    /// let old = y.swap(None);
+    /// assert_eq!(old, Some(Arc::new(3.to_string())));
+    /// assert!(x.is_none());
+    /// ```
+    pub fn as_arc_swap(&self) -> ArcSwapAdapter<'_, T> {
+        ArcSwapAdapter { option: self }
+    }
+}
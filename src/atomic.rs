@@ -0,0 +1,136 @@
+//! Atomic primitives used by the concurrent option.
+//!
+//! By default, these are the atomics of `core::sync::atomic`.
+//!
+//! When the crate is compiled with `--cfg orx_concurrent_option_single_thread`, they are replaced by
+//! `Cell` based counterparts with the same API which perform plain loads and stores and ignore the
+//! memory orderings. This removes compare-and-swap instructions from targets which are guaranteed to be
+//! single-threaded, such as wasm bundles without the atomics target feature, and leads to deterministic
+//! behavior in tests. In this mode, the concurrent option is not `Sync`; hence, the compiler rejects any attempt
+//! to share it across threads, and the methods which spawn threads on the option, such as `scoped_init`, are not available.
+//!
+//! Targets without native compare-and-swap on 8-bit, 16-bit, 32-bit and pointer-sized atomics, such as msp430 and AVR,
+//! can only be built in the single-thread mode; otherwise, compilation fails with an explicit error rather than
+//! with missing methods of the core atomics.
+
+#[cfg(all(
+    not(orx_concurrent_option_single_thread),
+    not(all(
+        target_has_atomic = "8",
+        target_has_atomic = "16",
+        target_has_atomic = "32",
+        target_has_atomic = "ptr"
+    ))
+))]
+compile_error!(
+    "orx-concurrent-option requires native compare-and-swap on 8-bit, 16-bit, 32-bit and pointer-sized atomics; \
+     single-threaded targets without them can be built with RUSTFLAGS=\"--cfg orx_concurrent_option_single_thread\""
+);
+
+#[cfg(all(not(orx_concurrent_option_single_thread), feature = "write-intent"))]
+pub(crate) use core::sync::atomic::AtomicU16;
+#[cfg(all(not(orx_concurrent_option_single_thread), feature = "last-updated"))]
+pub(crate) use core::sync::atomic::AtomicU64;
+#[cfg(all(
+    not(orx_concurrent_option_single_thread),
+    any(
+        miri,
+        feature = "generation",
+        feature = "ref-tracking",
+        feature = "stats"
+    )
+))]
+pub(crate) use core::sync::atomic::AtomicUsize;
+#[cfg(not(orx_concurrent_option_single_thread))]
+pub(crate) use core::sync::atomic::{AtomicU32, AtomicU8};
+
+#[cfg(all(orx_concurrent_option_single_thread, feature = "write-intent"))]
+pub(crate) use single_thread::AtomicU16;
+#[cfg(all(orx_concurrent_option_single_thread, feature = "last-updated"))]
+pub(crate) use single_thread::AtomicU64;
+#[cfg(all(
+    orx_concurrent_option_single_thread,
+    any(
+        miri,
+        feature = "generation",
+        feature = "ref-tracking",
+        feature = "stats"
+    )
+))]
+pub(crate) use single_thread::AtomicUsize;
+#[cfg(orx_concurrent_option_single_thread)]
+pub(crate) use single_thread::{AtomicU32, AtomicU8};
+
+#[cfg(orx_concurrent_option_single_thread)]
+mod single_thread {
+    use core::{cell::Cell, sync::atomic::Ordering};
+
+    macro_rules! cell_atomic {
+        ($name:ident, $t:ty) => {
+            // not Sync; hence, neither is the concurrent option in the single-thread mode
+            pub(crate) struct $name(Cell<$t>);
+
+            #[allow(dead_code)]
+            impl $name {
+                pub(crate) const fn new(value: $t) -> Self {
+                    Self(Cell::new(value))
+                }
+
+                #[inline(always)]
+                pub(crate) fn load(&self, _: Ordering) -> $t {
+                    self.0.get()
+                }
+
+                #[inline(always)]
+                pub(crate) fn store(&self, value: $t, _: Ordering) {
+                    self.0.set(value)
+                }
+
+                #[inline(always)]
+                pub(crate) fn compare_exchange(
+                    &self,
+                    current: $t,
+                    new: $t,
+                    _: Ordering,
+                    _: Ordering,
+                ) -> Result<$t, $t> {
+                    let value = self.0.get();
+                    match value == current {
+                        true => {
+                            self.0.set(new);
+                            Ok(value)
+                        }
+                        false => Err(value),
+                    }
+                }
+
+                #[inline(always)]
+                pub(crate) fn fetch_add(&self, value: $t, _: Ordering) -> $t {
+                    let previous = self.0.get();
+                    self.0.set(previous.wrapping_add(value));
+                    previous
+                }
+
+                #[inline(always)]
+                pub(crate) fn fetch_sub(&self, value: $t, _: Ordering) -> $t {
+                    let previous = self.0.get();
+                    self.0.set(previous.wrapping_sub(value));
+                    previous
+                }
+
+                #[inline(always)]
+                pub(crate) fn get_mut(&mut self) -> &mut $t {
+                    self.0.get_mut()
+                }
+            }
+        };
+    }
+
+    cell_atomic!(AtomicU8, u8);
+    #[cfg(feature = "write-intent")]
+    cell_atomic!(AtomicU16, u16);
+    cell_atomic!(AtomicU32, u32);
+    cell_atomic!(AtomicUsize, usize);
+    #[cfg(feature = "last-updated")]
+    cell_atomic!(AtomicU64, u64);
+}
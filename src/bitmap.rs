@@ -0,0 +1,86 @@
+use crate::{
+    states::{FROZEN, SOME},
+    ConcurrentOption,
+};
+use core::sync::atomic::Ordering;
+
+/// Gathers the Some/None status of the `options` into the packed bitmap `out`.
+///
+/// The `i`-th bit of `out[i / 64]`, counting from the least significant bit, is set if and only if
+/// `options[i]` is of Some variant.
+/// The bits of the last word beyond the length of `options` are cleared; the words beyond are not touched.
+///
+/// The states are read with relaxed loads, the same as [`ConcurrentOption::is_some`].
+/// Therefore, the bitmap is a fast overview of the slots, such as a scheduler would check each tick,
+/// rather than a consistent snapshot; each bit might already be outdated once it is written.
+///
+/// # Panics
+///
+/// Panics if `out` has less than `options.len().div_ceil(64)` words.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// let options: Vec<_> = (0..70)
+///     .map(|i| match i % 3 {
+///         0 => ConcurrentOption::some(i),
+///         _ => ConcurrentOption::none(),
+///     })
+///     .collect();
+///
+/// let mut out = [u64::MAX; 2];
+/// some_bitmap(&options, &mut out);
+///
+/// assert_eq!(out[0], 0x9249249249249249);
+/// assert_eq!(out[1], 0b10_0100);
+/// ```
+#[allow(clippy::panic)]
+pub fn some_bitmap<T>(options: &[ConcurrentOption<T>], out: &mut [u64]) {
+    let num_words = options.len().div_ceil(64);
+    if out.len() < num_words {
+        panic!(
+            "bitmap of {} options requires {} words while out has {}",
+            options.len(),
+            num_words,
+            out.len()
+        );
+    }
+
+    for (chunk, word) in options.chunks(64).zip(out.iter_mut()) {
+        *word = chunk.iter().enumerate().fold(0u64, |bits, (i, x)| {
+            let is_some = matches!(x.state.load(Ordering::Relaxed), SOME | FROZEN);
+            bits | ((is_some as u64) << i)
+        });
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Returns an approximate number of the `options` which are of Some variant.
+    ///
+    /// The states are read with relaxed loads without any fence or reservation, the same as [`some_bitmap`].
+    /// Therefore, the count is a hint for heuristics, such as sizing a batch or selecting a victim in work stealing,
+    /// rather than a consistent snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let options: Vec<_> = (0..10)
+    ///     .map(|i| match i % 2 {
+    ///         0 => ConcurrentOption::some(i),
+    ///         _ => ConcurrentOption::none(),
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(ConcurrentOption::len_hint(&options), 5);
+    /// ```
+    pub fn len_hint(options: &[Self]) -> usize {
+        options
+            .iter()
+            .filter(|x| matches!(x.state.load(Ordering::Relaxed), SOME | FROZEN))
+            .count()
+    }
+}
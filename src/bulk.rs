@@ -0,0 +1,76 @@
+use crate::ConcurrentOption;
+use alloc::{boxed::Box, vec::Vec};
+
+/// Converts a vector of options into a vector of concurrent options.
+///
+/// The allocation of `options` is reused whenever the layouts of `Option<T>` and `ConcurrentOption<T>` permit.
+///
+/// This is useful for phase-structured programs with a single-threaded setup followed by a concurrent run.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// let options = vec![Some(1), None, Some(3)];
+/// let slots = into_concurrent_options(options);
+/// assert_eq!(slots, [ConcurrentOption::some(1), ConcurrentOption::none(), ConcurrentOption::some(3)]);
+/// ```
+pub fn into_concurrent_options<T>(options: Vec<Option<T>>) -> Vec<ConcurrentOption<T>> {
+    options.into_iter().map(ConcurrentOption::from).collect()
+}
+
+/// Converts a vector of concurrent options into a vector of options.
+///
+/// The allocation of `options` is reused whenever the layouts of `Option<T>` and `ConcurrentOption<T>` permit.
+///
+/// This is useful for phase-structured programs where a concurrent run is followed by a single-threaded phase.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// let slots = vec![ConcurrentOption::some(1), ConcurrentOption::none()];
+/// _ = slots[1].initialize_if_none(2);
+///
+/// let options = into_options(slots);
+/// assert_eq!(options, [Some(1), Some(2)]);
+/// ```
+pub fn into_options<T>(options: Vec<ConcurrentOption<T>>) -> Vec<Option<T>> {
+    options.into_iter().map(Option::from).collect()
+}
+
+/// Converts a boxed slice of options into a boxed slice of concurrent options.
+///
+/// The allocation of `options` is reused whenever the layouts of `Option<T>` and `ConcurrentOption<T>` permit.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// let options: Box<[Option<i32>]> = vec![Some(1), None].into_boxed_slice();
+/// let slots = into_concurrent_options_boxed(options);
+/// assert_eq!(slots.as_ref(), [ConcurrentOption::some(1), ConcurrentOption::none()]);
+/// ```
+pub fn into_concurrent_options_boxed<T>(options: Box<[Option<T>]>) -> Box<[ConcurrentOption<T>]> {
+    into_concurrent_options(options.into_vec()).into_boxed_slice()
+}
+
+/// Converts a boxed slice of concurrent options into a boxed slice of options.
+///
+/// The allocation of `options` is reused whenever the layouts of `Option<T>` and `ConcurrentOption<T>` permit.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// let slots: Box<[ConcurrentOption<i32>]> = vec![ConcurrentOption::some(1), ConcurrentOption::none()].into_boxed_slice();
+/// let options = into_options_boxed(slots);
+/// assert_eq!(options.as_ref(), [Some(1), None]);
+/// ```
+pub fn into_options_boxed<T>(options: Box<[ConcurrentOption<T>]>) -> Box<[Option<T>]> {
+    into_options(options.into_vec()).into_boxed_slice()
+}
@@ -0,0 +1,237 @@
+use crate::{
+    atomic::AtomicU8, parking::notify, states::*, wait::block_until, ConcurrentOption, Initialized,
+};
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::{sync::Mutex, vec::Vec};
+
+/// A token to cooperatively cancel the blocking waits on concurrent options, such as
+/// [`ConcurrentOption::wait_for_some_cancellable`].
+///
+/// Once [`cancel`] is called, all waits observing the token return a [`Cancelled`] error right away,
+/// including the ones which are already blocked; and so do all the waits started later.
+/// The cancellation is permanent; a new token must be created for a new operation.
+///
+/// The token is typically shared by reference, or by an `Arc`, among the threads of an operation.
+///
+/// [`cancel`]: CancelToken::cancel
+/// [`ConcurrentOption::wait_for_some_cancellable`]: crate::ConcurrentOption::wait_for_some_cancellable
+#[derive(Default)]
+pub struct CancelToken {
+    cancelled: AtomicBool,
+    waiting: Mutex<Vec<StateRef>>,
+}
+
+/// Pointer to the state of an option which is being waited on; the waiter keeps the option borrowed
+/// until it removes the pointer.
+struct StateRef(*const AtomicU8);
+
+unsafe impl Send for StateRef {}
+
+impl CancelToken {
+    /// Creates a new token which is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the token and wakes up the waits blocked on it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::<String>::none();
+    /// let token = CancelToken::new();
+    ///
+    /// std::thread::scope(|s| {
+    ///     let waiter = s.spawn(|| x.wait_for_some_cancellable(&token).map(|x| x.clone()));
+    ///
+    ///     std::thread::sleep(std::time::Duration::from_millis(10));
+    ///     token.cancel();
+    ///     assert_eq!(waiter.join().unwrap(), Err(Cancelled));
+    /// });
+    /// ```
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        for state in self.lock().iter() {
+            // the option is alive while it is in the list
+            notify(unsafe { &*state.0 });
+        }
+    }
+
+    /// Returns whether or not the token is cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<StateRef>> {
+        // the list is never left in an inconsistent state; hence, it is safe to recover from poisoning
+        self.waiting.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Blocks like [`block_until`] until `done` returns true or the token is cancelled;
+    /// returns whether or not `done` returned true.
+    fn block_until<F>(&self, state: &AtomicU8, mut done: F) -> bool
+    where
+        F: FnMut() -> bool,
+    {
+        self.lock().push(StateRef(state));
+
+        let mut is_done = false;
+        block_until(state, || {
+            is_done = done();
+            is_done || self.is_cancelled()
+        });
+
+        let mut waiting = self.lock();
+        if let Some(i) = waiting.iter().position(|x| core::ptr::eq(x.0, state)) {
+            waiting.swap_remove(i);
+        }
+        is_done
+    }
+}
+
+impl core::fmt::Debug for CancelToken {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CancelToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/// Error of the cancellable waits, such as [`ConcurrentOption::wait_for_some_cancellable`],
+/// indicating that the [`CancelToken`] is cancelled before the awaited state is observed.
+///
+/// [`ConcurrentOption::wait_for_some_cancellable`]: crate::ConcurrentOption::wait_for_some_cancellable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl core::fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "wait on the ConcurrentOption is cancelled")
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Cancellable version of [`wait_for_some`].
+    ///
+    /// Blocks the current thread until the option is observed to be Some, and returns an [`Initialized`] token
+    /// providing safe shared access to the value.
+    ///
+    /// Returns a [`Cancelled`] error as soon as the `token` is cancelled, unless the option is Some at that point.
+    ///
+    /// [`wait_for_some`]: ConcurrentOption::wait_for_some
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    /// let token = CancelToken::new();
+    /// assert_eq!(x.wait_for_some_cancellable(&token).map(|x| *x), Ok(3));
+    ///
+    /// let x = ConcurrentOption::<i32>::none();
+    /// token.cancel();
+    /// assert_eq!(x.wait_for_some_cancellable(&token).map(|x| *x), Err(Cancelled));
+    /// ```
+    pub fn wait_for_some_cancellable(
+        &self,
+        token: &CancelToken,
+    ) -> Result<Initialized<'_, T>, Cancelled> {
+        loop {
+            if let Some(initialized) = self.initialized() {
+                return Ok(initialized);
+            }
+            if !token.block_until(&self.state, || {
+                matches!(self.state.load(ORDER_LOAD), SOME | FROZEN)
+            }) {
+                return Err(Cancelled);
+            }
+        }
+    }
+
+    /// Cancellable version of [`wait_for_none`].
+    ///
+    /// Blocks the current thread until the option is observed to be None.
+    ///
+    /// Returns a [`Cancelled`] error as soon as the `token` is cancelled, unless the option is None at that point.
+    ///
+    /// [`wait_for_none`]: ConcurrentOption::wait_for_none
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::<i32>::none();
+    /// let token = CancelToken::new();
+    /// assert_eq!(x.wait_for_none_cancellable(&token), Ok(()));
+    ///
+    /// let x = ConcurrentOption::some(3);
+    /// token.cancel();
+    /// assert_eq!(x.wait_for_none_cancellable(&token), Err(Cancelled));
+    /// ```
+    pub fn wait_for_none_cancellable(&self, token: &CancelToken) -> Result<(), Cancelled> {
+        match token.block_until(&self.state, || self.state.load(ORDER_LOAD) == NONE) {
+            true => Ok(()),
+            false => Err(Cancelled),
+        }
+    }
+
+    /// Cancellable version of [`wait_and_take`].
+    ///
+    /// Blocks the current thread until the option is observed to be Some, and then takes the value out of the option,
+    /// leaving a None in its place.
+    ///
+    /// Returns a [`Cancelled`] error as soon as the `token` is cancelled, unless a value is taken at that point.
+    ///
+    /// [`wait_and_take`]: ConcurrentOption::wait_and_take
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen, since its value can never be taken through a shared reference.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let slot = ConcurrentOption::none();
+    /// let token = CancelToken::new();
+    ///
+    /// let consumed: Vec<_> = std::thread::scope(|s| {
+    ///     let consumer = s.spawn(|| {
+    ///         let mut consumed = vec![];
+    ///         while let Ok(value) = slot.wait_and_take_cancellable(&token) {
+    ///             consumed.push(value);
+    ///         }
+    ///         consumed
+    ///     });
+    ///
+    ///     for i in 0..10 {
+    ///         slot.wait_for_none();
+    ///         assert!(slot.initialize_if_none(i));
+    ///     }
+    ///     slot.wait_for_none();
+    ///     token.cancel();
+    ///
+    ///     consumer.join().unwrap()
+    /// });
+    ///
+    /// assert_eq!(consumed, (0..10).collect::<Vec<_>>());
+    /// ```
+    pub fn wait_and_take_cancellable(&self, token: &CancelToken) -> Result<T, Cancelled> {
+        loop {
+            if let Some(value) = self.take() {
+                return Ok(value);
+            }
+            self.assert_not_frozen();
+            if !token.block_until(&self.state, || {
+                matches!(self.state.load(ORDER_LOAD), SOME | FROZEN)
+            }) {
+                return Err(Cancelled);
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{handle::Handle, states::*, stats::ExitReason, ConcurrentOption};
+use core::mem::MaybeUninit;
+
+/// An exclusive claim on an empty [`ConcurrentOption`], created by [`ConcurrentOption::try_claim`].
+///
+/// The option is reserved while the claim is alive.
+/// The claim is completed by publishing a value, which turns the option into Some variant;
+/// if the claim is dropped without publishing, the option is released back as None.
+///
+/// This is the low level building block of slot based protocols, such as the [`spsc`] ring,
+/// where claiming a slot and publishing its value are separated.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`ConcurrentOption::try_claim`]: crate::ConcurrentOption::try_claim
+/// [`spsc`]: crate::spsc
+#[must_use = "the claim is released as None as soon as it is dropped without publishing"]
+pub struct SlotClaim<'a, T> {
+    handle: Handle<'a>,
+    option: &'a ConcurrentOption<T>,
+}
+
+impl<T> SlotClaim<'_, T> {
+    /// Writes the `value` into the claimed option and releases it as Some variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::none();
+    ///
+    /// let claim = x.try_claim().unwrap();
+    /// assert!(x.try_claim().is_none()); // already claimed
+    /// claim.publish(42);
+    ///
+    /// assert_eq!(x.take(), Some(42));
+    /// ```
+    pub fn publish(mut self, value: T) {
+        unsafe { &mut *self.option.value.get() }.write(value);
+        self.handle.set_success_state(SOME);
+    }
+
+    /// Initializes the value of the claimed option in place by the function `init` and releases it as Some variant.
+    ///
+    /// This allows constructing large values directly in the slot, rather than moving them in.
+    ///
+    /// # Safety
+    ///
+    /// The function `init` must fully initialize the value it is given; otherwise,
+    /// subsequent reads of the option lead to an **Undefined Behavior**.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::<[u64; 1024]>::none();
+    ///
+    /// let claim = x.try_claim().unwrap();
+    /// unsafe {
+    ///     claim.publish_in_place(|slot| {
+    ///         let ptr = slot.as_mut_ptr() as *mut u64;
+    ///         for i in 0..1024 {
+    ///             ptr.add(i).write(i as u64);
+    ///         }
+    ///     })
+    /// };
+    ///
+    /// assert_eq!(x.map(|x| x.iter().sum::<u64>()), Some(1023 * 512));
+    /// ```
+    pub unsafe fn publish_in_place<F>(mut self, init: F)
+    where
+        F: FnOnce(&mut MaybeUninit<T>),
+    {
+        init(unsafe { &mut *self.option.value.get() });
+        self.handle.set_success_state(SOME);
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Thread safe method which attempts to claim the option if it is None, without waiting.
+    ///
+    /// Returns None if the option is of Some variant or is reserved by another thread at the moment;
+    /// otherwise, returns a [`SlotClaim`] which reserves the option until a value is published through it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(1);
+    /// assert!(x.try_claim().is_none());
+    ///
+    /// _ = x.take();
+    /// let claim = x.try_claim().unwrap();
+    /// assert_eq!(x.state(LoadOrdering::Acquire), State::Reserved);
+    ///
+    /// drop(claim); // released as None without publishing
+    /// assert_eq!(x.state(LoadOrdering::Acquire), State::None);
+    /// ```
+    pub fn try_claim(&self) -> Option<SlotClaim<'_, T>> {
+        self.get_write_handle(NONE, NONE).map(|handle| SlotClaim {
+            handle,
+            option: self,
+        })
+    }
+
+    /// Thread safe method which takes the value out of the option, if Some, into `destination`,
+    /// leaving a None in its place; returns whether or not a value is taken.
+    ///
+    /// The `destination` is left untouched if the option is None.
+    /// This allows the caller to reuse the same destination over consecutive takes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3.to_string());
+    /// let mut destination = None;
+    ///
+    /// assert!(x.take_into(&mut destination));
+    /// assert_eq!(destination, Some(3.to_string()));
+    ///
+    /// assert!(!x.take_into(&mut destination));
+    /// assert_eq!(destination, Some(3.to_string()));
+    /// ```
+    pub fn take_into(&self, destination: &mut Option<T>) -> bool {
+        let taken = match self.spin_get_write_handle(SOME, NONE) {
+            Some(_handle) => {
+                self.record_exit(ExitReason::Taken);
+                let x = unsafe { &*self.value.get() };
+                Some(unsafe { MaybeUninit::assume_init_read(x) })
+            }
+            None => None,
+        };
+
+        // the previous value of the destination is dropped after the option is released
+        match taken {
+            Some(value) => {
+                *destination = Some(value);
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -11,14 +11,13 @@ impl<T: Clone> Clone for ConcurrentOption<T> {
     ///
     /// ```rust
     /// use orx_concurrent_option::*;
-    /// use core::sync::atomic::Ordering;
     ///
     /// let x = ConcurrentOption::some(42);
     /// let y = x.clone(); // clone with default Relaxed ordering
     /// assert_eq!(x, y);
     ///
     /// let x = ConcurrentOption::some(42);
-    /// let y = x.clone_with_order(Ordering::SeqCst).into(); // clone with desired ordering SeqCst
+    /// let y = x.clone_with_order(LoadOrdering::SeqCst).into(); // clone with desired ordering SeqCst
     /// assert_eq!(x, y);
     /// ```
     fn clone(&self) -> Self {
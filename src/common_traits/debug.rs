@@ -1,5 +1,5 @@
-use crate::concurrent_option::ConcurrentOption;
-use core::fmt::Debug;
+use crate::{concurrent_option::ConcurrentOption, LoadOrdering};
+use core::fmt::{Debug, Display};
 
 impl<T: Debug> Debug for ConcurrentOption<T> {
     /// Creates the debug representation.
@@ -21,3 +21,29 @@ impl<T: Debug> Debug for ConcurrentOption<T> {
         write!(f, "Concurrent{:?}", maybe)
     }
 }
+
+/// Formats a [`ConcurrentOption`] with an explicit ordering and without acquiring the reservation (best-effort).
+///
+/// Created by [`ConcurrentOption::snapshot`].
+///
+/// * `Debug` formats the option in the same way as the `Debug` implementation of the concurrent option;
+/// * `Display` formats the value with its `Display` implementation wrapped in `ConcurrentSome(...)`, or `ConcurrentNone`.
+pub struct DisplaySnapshot<'a, T> {
+    pub(crate) option: &'a ConcurrentOption<T>,
+    pub(crate) order: LoadOrdering,
+}
+
+impl<T: Debug> Debug for DisplaySnapshot<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        unsafe { self.option.fmt_with_order(f, self.order) }
+    }
+}
+
+impl<T: Display> Display for DisplaySnapshot<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match unsafe { self.option.as_ref_with_order(self.order) } {
+            Some(x) => write!(f, "ConcurrentSome({})", x),
+            None => write!(f, "ConcurrentNone"),
+        }
+    }
+}
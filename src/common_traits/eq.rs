@@ -7,6 +7,11 @@ impl<T: PartialEq> PartialEq for ConcurrentOption<T> {
     ///
     /// [`eq_with_order`]: ConcurrentOption::eq_with_order
     ///
+    /// Note that the comparison waits while either of the options is reserved by another thread.
+    /// You may call [`try_eq`] which returns None rather than waiting, such as from monitoring threads.
+    ///
+    /// [`try_eq`]: ConcurrentOption::try_eq
+    ///
     /// ```rust
     /// use orx_concurrent_option::*;
     ///
@@ -11,7 +11,7 @@ impl<T> From<T> for ConcurrentOption<T> {
     /// use orx_concurrent_option::*;
     ///
     /// let x: ConcurrentOption<String> = 3.to_string().into();
-    /// assert_eq!(unsafe { x.as_ref() }, Some(&3.to_string()));
+    /// assert_eq!(x.with_ref(|x| x.clone()), Some(3.to_string()));
     /// ```
     fn from(value: T) -> Self {
         ConcurrentOption::some(value)
@@ -27,10 +27,10 @@ impl<T> From<Option<T>> for ConcurrentOption<T> {
     /// use orx_concurrent_option::*;
     ///
     /// let x: ConcurrentOption<String> = Some(3.to_string()).into();
-    /// assert_eq!(unsafe { x.as_ref() }, Some(&3.to_string()));
+    /// assert_eq!(x.with_ref(|x| x.clone()), Some(3.to_string()));
     ///
     /// let x: ConcurrentOption<String> = None.into();
-    /// assert_eq!(unsafe { x.as_ref() }, None);
+    /// assert_eq!(x.with_ref(|x| x.clone()), None);
     /// ```
     fn from(value: Option<T>) -> Self {
         match value {
@@ -40,6 +40,40 @@ impl<T> From<Option<T>> for ConcurrentOption<T> {
     }
 }
 
+impl<T: Clone> From<&T> for ConcurrentOption<T> {
+    /// Wraps a clone of the borrowed value to a `ConcurrentOption` of Some variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let value = 3.to_string();
+    /// let x: ConcurrentOption<String> = (&value).into();
+    /// assert_eq!(x.with_ref(|x| x.clone()), Some(3.to_string()));
+    /// ```
+    fn from(value: &T) -> Self {
+        ConcurrentOption::some_cloned(value)
+    }
+}
+
+impl<T: Clone> From<&mut T> for ConcurrentOption<T> {
+    /// Wraps a clone of the mutably borrowed value to a `ConcurrentOption` of Some variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut value = 3.to_string();
+    /// let x: ConcurrentOption<String> = (&mut value).into();
+    /// assert_eq!(x.with_ref(|x| x.clone()), Some(3.to_string()));
+    /// ```
+    fn from(value: &mut T) -> Self {
+        ConcurrentOption::some_cloned(value)
+    }
+}
+
 // INTO
 
 impl<T> From<ConcurrentOption<T>> for Option<T> {
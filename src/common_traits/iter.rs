@@ -1,5 +1,5 @@
-use crate::ConcurrentOption;
-use core::{iter::FusedIterator, sync::atomic::Ordering};
+use crate::{ConcurrentOption, LoadOrdering, ReadGuard};
+use core::iter::FusedIterator;
 
 // INTO-ITER
 
@@ -8,7 +8,7 @@ impl<'a, T> IntoIterator for &'a ConcurrentOption<T> {
     type IntoIter = Iter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        unsafe { self.iter_with_order(Ordering::Relaxed) }
+        unsafe { self.iter_with_order(LoadOrdering::Relaxed) }
     }
 }
 
@@ -26,8 +26,8 @@ impl<T> IntoIterator for ConcurrentOption<T> {
 
     type IntoIter = core::option::IntoIter<T>;
 
-    fn into_iter(mut self) -> Self::IntoIter {
-        self.exclusive_take().into_iter()
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter_with_order(LoadOrdering::Relaxed)
     }
 }
 
@@ -94,3 +94,77 @@ impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
         self.next()
     }
 }
+
+// ITER-GUARDS
+
+/// Iterator over a slice of `ConcurrentOption`s yielding the index and a [`ReadGuard`] of each option of Some variant.
+///
+/// Created by [`ConcurrentOption::iter_guards`].
+pub struct IterGuards<'a, T> {
+    pub(crate) options: &'a [ConcurrentOption<T>],
+    pub(crate) idx: usize,
+}
+
+impl<'a, T> Iterator for IterGuards<'a, T> {
+    type Item = (usize, ReadGuard<'a, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(option) = self.options.get(self.idx) {
+            let idx = self.idx;
+            self.idx += 1;
+            if let Some(guard) = ReadGuard::new(option) {
+                return Some((idx, guard));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.options.len() - self.idx))
+    }
+}
+
+impl<T> FusedIterator for IterGuards<'_, T> {}
+
+// GUARDED-ITER
+
+/// Safe iterator over the `ConcurrentOption` yielding at most one element, which is a [`ReadGuard`] to the value.
+///
+/// Created by [`ConcurrentOption::iter_guarded`].
+///
+/// The reservation is acquired when the iterator is created; it is held by the iterator until the guard is yielded,
+/// and by the yielded guard afterwards. Therefore, the value cannot be taken or replaced by other threads
+/// while it is being accessed through the guard.
+pub struct GuardedIter<'a, T> {
+    pub(crate) maybe: Option<ReadGuard<'a, T>>,
+}
+
+impl<'a, T> Iterator for GuardedIter<'a, T> {
+    type Item = ReadGuard<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.maybe.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> FusedIterator for GuardedIter<'_, T> {}
+
+impl<T> ExactSizeIterator for GuardedIter<'_, T> {
+    fn len(&self) -> usize {
+        match self.maybe.is_some() {
+            true => 1,
+            false => 0,
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for GuardedIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next()
+    }
+}
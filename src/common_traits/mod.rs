@@ -1,5 +1,5 @@
 mod clone;
-mod debug;
+pub(crate) mod debug;
 mod default;
 mod eq;
 mod from;
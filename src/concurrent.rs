@@ -1,5 +1,8 @@
-use crate::{states::*, ConcurrentOption};
-use core::{mem::MaybeUninit, sync::atomic::Ordering};
+use crate::{
+    handle::ReadHandle, parking::notify, states::*, stats::ExitReason, ConcurrentOption,
+    LoadOrdering,
+};
+use core::mem::MaybeUninit;
 
 impl<T> ConcurrentOption<T> {
     // concurrent state mutation - special
@@ -29,12 +32,12 @@ impl<T> ConcurrentOption<T> {
     /// let x = ConcurrentOption::<String>::none();
     /// let inserted = x.initialize_if_none(3.to_string());
     /// assert!(inserted);
-    /// assert_eq!(unsafe { x.as_ref() }, Some(&3.to_string()));
+    /// assert_eq!(x.with_ref(|x| x.clone()), Some(3.to_string()));
     ///
     /// let x = ConcurrentOption::some(7.to_string());
     /// let inserted = x.initialize_if_none(3.to_string()); // does nothing
     /// assert!(!inserted);
-    /// assert_eq!(unsafe { x.as_ref() }, Some(&7.to_string()));
+    /// assert_eq!(x.with_ref(|x| x.clone()), Some(7.to_string()));
     /// ```
     ///
     /// A more advanced and useful example is demonstrated below:
@@ -103,7 +106,7 @@ impl<T> ConcurrentOption<T> {
     /// assert_eq!(maybe.unwrap(), 7.to_string());
     /// ```
     pub fn initialize_if_none(&self, value: T) -> bool {
-        match self.get_handle(NONE, SOME) {
+        match self.get_write_handle(NONE, SOME) {
             Some(_handle) => {
                 unsafe { &mut *self.value.get() }.write(value);
                 true
@@ -148,13 +151,13 @@ impl<T> ConcurrentOption<T> {
     ///
     /// let x = ConcurrentOption::<String>::none();
     /// unsafe { x.initialize_unchecked(3.to_string()) };
-    /// assert_eq!(unsafe { x.as_ref() }, Some(&3.to_string()));
+    /// assert_eq!(x.with_ref(|x| x.clone()), Some(3.to_string()));
     ///
     /// #[cfg(not(miri))]
     /// {
     ///     let x = ConcurrentOption::some(7.to_string());
     ///     unsafe { x.initialize_unchecked(3.to_string()) }; // undefined behavior!
-    ///     assert_eq!(unsafe { x.as_ref() }, Some(&3.to_string()));
+    ///     assert_eq!(x.with_ref(|x| x.clone()), Some(3.to_string()));
     /// }
     /// ```
     ///
@@ -217,8 +220,129 @@ impl<T> ConcurrentOption<T> {
     /// assert_eq!(maybe.unwrap(), 7.to_string());
     /// ```
     pub unsafe fn initialize_unchecked(&self, value: T) {
+        self.generation.assert_no_ref_tokens();
         unsafe { &mut *self.value.get() }.write(value);
-        self.state.store(SOME, Ordering::Release);
+        self.generation.bump();
+        self.state.store(SOME, ORDER_STORE);
+        notify(&self.state);
+        self.generation.notify_transition(NONE, SOME);
+    }
+
+    /// Thread safe method to initialize the None options among `options` with the `values`, in order;
+    /// returns the number of values installed.
+    ///
+    /// * The options are visited in order and each value is installed to the next option which `is_none`.
+    /// * Options of Some variant are skipped, leaving their values untouched.
+    /// * Initialization stops when either all options are visited or the values are exhausted.
+    ///
+    /// Each option is initialized with the same atomic protocol as [`initialize_if_none`];
+    /// therefore, it is safe to call this method while other threads are reading or writing the options.
+    /// If an option is concurrently initialized by another thread, the value is simply moved on to the next option.
+    ///
+    /// [`initialize_if_none`]: ConcurrentOption::initialize_if_none
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let slots = vec![
+    ///     ConcurrentOption::none(),
+    ///     ConcurrentOption::some(10),
+    ///     ConcurrentOption::none(),
+    ///     ConcurrentOption::none(),
+    /// ];
+    ///
+    /// let num_installed = ConcurrentOption::initialize_from_iter(&slots, [1, 2]);
+    /// assert_eq!(num_installed, 2);
+    /// assert_eq!(
+    ///     slots.iter().map(|x| x.clone_into_option()).collect::<Vec<_>>(),
+    ///     [Some(1), Some(10), Some(2), None],
+    /// );
+    ///
+    /// let num_installed = ConcurrentOption::initialize_from_iter(&slots, 3..);
+    /// assert_eq!(num_installed, 1);
+    /// assert_eq!(
+    ///     slots.iter().map(|x| x.clone_into_option()).collect::<Vec<_>>(),
+    ///     [Some(1), Some(10), Some(2), Some(3)],
+    /// );
+    /// ```
+    pub fn initialize_from_iter(options: &[Self], values: impl IntoIterator<Item = T>) -> usize {
+        let mut values = values.into_iter();
+        let mut pending = None;
+        let mut num_installed = 0;
+
+        for option in options {
+            if !option.is_none() {
+                continue;
+            }
+
+            let value = match pending.take().or_else(|| values.next()) {
+                Some(value) => value,
+                None => break,
+            };
+
+            match option.spin_get_write_handle(NONE, SOME) {
+                Some(_handle) => {
+                    unsafe { &mut *option.value.get() }.write(value);
+                    num_installed += 1;
+                }
+                None => pending = Some(value),
+            }
+        }
+
+        num_installed
+    }
+
+    /// Thread safe method to apply the sequence of `updates` to the option, in order;
+    /// returns the number of updates applied.
+    ///
+    /// * Some(value) sets the value of the option as in [`replace`], dropping the old value if any.
+    /// * None clears the option as in [`take`], dropping the old value if any.
+    ///
+    /// Each step is applied atomically; however, the sequence as a whole is not.
+    /// Other threads might observe or modify the option between two steps.
+    ///
+    /// This is useful for replay or rehydration code that reconstructs the state of a slot from a log.
+    ///
+    /// [`replace`]: ConcurrentOption::replace
+    /// [`take`]: ConcurrentOption::take
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen by [`freeze`], or if it is in a custom state set by [`transition_custom`],
+    /// and one of the `updates` is Some.
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let log = [Some(1), Some(2), None, Some(3)];
+    ///
+    /// let x = ConcurrentOption::none();
+    /// assert_eq!(x.apply_updates(log), 4);
+    /// assert_eq!(x, ConcurrentOption::some(3));
+    ///
+    /// assert_eq!(x.apply_updates([Some(4), None]), 2);
+    /// assert!(x.is_none());
+    /// ```
+    pub fn apply_updates<I>(&self, updates: I) -> usize
+    where
+        I: IntoIterator<Item = Option<T>>,
+    {
+        let mut num_applied = 0;
+        for update in updates {
+            match update {
+                Some(value) => _ = self.replace(value),
+                None => _ = self.take(),
+            }
+            num_applied += 1;
+        }
+        num_applied
     }
 
     // concurrent state mutation
@@ -242,11 +366,11 @@ impl<T> ConcurrentOption<T> {
     /// assert!(maybe.is_some_and(|x| x == &"42!".to_string()));
     /// assert_eq!(updated, true);
     /// ```
-    pub fn update_if_some<F>(&self, mut f: F) -> bool
+    pub fn update_if_some<F>(&self, f: F) -> bool
     where
-        F: FnMut(&mut T),
+        F: FnOnce(&mut T),
     {
-        match self.spin_get_handle(SOME, SOME) {
+        match self.spin_get_write_handle(SOME, SOME) {
             Some(_handle) => {
                 let x = unsafe { MaybeUninit::assume_init_mut(&mut *self.value.get()) };
                 f(x);
@@ -277,8 +401,9 @@ impl<T> ConcurrentOption<T> {
     /// assert_eq!(y, None);
     /// ```
     pub fn take(&self) -> Option<T> {
-        match self.spin_get_handle(SOME, NONE) {
+        match self.spin_get_write_handle(SOME, NONE) {
             Some(_handle) => {
+                self.record_exit(ExitReason::Taken);
                 let x = unsafe { &*self.value.get() };
                 Some(unsafe { MaybeUninit::assume_init_read(x) })
             }
@@ -286,6 +411,116 @@ impl<T> ConcurrentOption<T> {
         }
     }
 
+    /// Thread safe method to take the value out of the option if Some, leaving a None in its place,
+    /// and to move it into a fresh heap allocation.
+    ///
+    /// The value is moved directly from the option into the allocation while the option is reserved;
+    /// hence, it is never returned by value on the stack. This is useful for very large `T`.
+    ///
+    /// Has no impact and returns None, if the option is of None variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some([7u8; 1024]);
+    /// let y = x.take_boxed();
+    /// assert!(x.is_none());
+    /// assert_eq!(y.as_deref(), Some(&[7u8; 1024]));
+    ///
+    /// assert_eq!(x.take_boxed(), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn take_boxed(&self) -> Option<alloc::boxed::Box<T>> {
+        match self.spin_get_write_handle(SOME, NONE) {
+            Some(_handle) => {
+                self.record_exit(ExitReason::Taken);
+                let mut boxed = alloc::boxed::Box::<T>::new_uninit();
+                let x = unsafe { &*self.value.get() };
+                unsafe { core::ptr::copy_nonoverlapping(x.as_ptr(), boxed.as_mut_ptr(), 1) };
+                Some(unsafe { boxed.assume_init() })
+            }
+            None => None,
+        }
+    }
+
+    /// Thread safe method to take the value out of the option if Some, or to create a fresh value with `f` otherwise;
+    /// leaving a None in its place in both cases.
+    ///
+    /// This is useful for allocate-or-reuse code paths, such as pools, where an existing value is reused if available.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(vec![1, 2]);
+    /// let y = x.take_or_init(Vec::new);
+    /// assert_eq!(x, ConcurrentOption::none());
+    /// assert_eq!(y, vec![1, 2]);
+    ///
+    /// let y = x.take_or_init(|| vec![42]);
+    /// assert_eq!(x, ConcurrentOption::none());
+    /// assert_eq!(y, vec![42]);
+    /// ```
+    pub fn take_or_init<F>(&self, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        self.take().unwrap_or_else(f)
+    }
+
+    /// Thread safe method to drain the `options` by repeatedly scanning and taking their values;
+    /// calls `f(idx, value)` for each value taken out of the option at position `idx`.
+    ///
+    /// The method returns once a complete scan of the options observes all of them as None.
+    /// In other words, values installed by producers before the final scan are guaranteed to be consumed.
+    ///
+    /// This is designed for single-consumer drain loops which compete with many producers,
+    /// where getting the termination condition right by hand is subtle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let slots: Vec<_> = (0..4).map(|_| ConcurrentOption::none()).collect();
+    ///
+    /// let mut total = 0;
+    /// std::thread::scope(|s| {
+    ///     for i in 0..4 {
+    ///         let slot = &slots[i];
+    ///         s.spawn(move || _ = slot.initialize_if_none(i));
+    ///     }
+    /// });
+    ///
+    /// ConcurrentOption::for_each_take(&slots, |idx, value| {
+    ///     assert_eq!(idx, value);
+    ///     total += value;
+    /// });
+    /// assert_eq!(total, 6);
+    /// assert!(slots.iter().all(|x| x.is_none()));
+    /// ```
+    pub fn for_each_take<F>(options: &[Self], mut f: F)
+    where
+        F: FnMut(usize, T),
+    {
+        loop {
+            let mut all_none = true;
+            for (idx, option) in options.iter().enumerate() {
+                if let Some(value) = option.take() {
+                    all_none = false;
+                    f(idx, value);
+                }
+            }
+
+            if all_none {
+                return;
+            }
+        }
+    }
+
     /// Thread safe method to take the value out of the option, but only if the predicate evaluates to
     /// `true` on a mutable reference to the value.
     ///
@@ -312,40 +547,124 @@ impl<T> ConcurrentOption<T> {
     /// assert_eq!(x, ConcurrentOption::none());
     /// assert_eq!(prev, Some(43));
     /// ```
-    #[allow(clippy::missing_panics_doc, clippy::unwrap_in_result)]
     pub fn take_if<P>(&self, predicate: P) -> Option<T>
     where
         P: FnOnce(&mut T) -> bool,
     {
-        loop {
-            match self
-                .state
-                .compare_exchange(SOME, RESERVED, ORDER_LOAD, ORDER_LOAD)
-            {
-                Ok(_) => {
-                    let x = unsafe { &mut *self.value.get() };
-                    let x_mut = unsafe { MaybeUninit::assume_init_mut(x) };
-                    let output = match predicate(x_mut) {
-                        false => None,
-                        true => Some(unsafe { MaybeUninit::assume_init_read(x) }),
-                    };
-
-                    let success_state = match output.is_some() {
-                        true => NONE,
-                        false => SOME,
-                    };
-                    self.state
-                        .compare_exchange(RESERVED, success_state, ORDER_STORE, ORDER_STORE)
-                        .expect(
-                            "Failed to update the concurrent state after concurrent state mutation",
-                        );
-
-                    return output;
-                }
-                Err(previous_state) => match previous_state {
-                    RESERVED => continue,
-                    _ => return None,
-                },
+        let mut handle = self.spin_get_write_handle(SOME, SOME)?;
+        let x = unsafe { &mut *self.value.get() };
+        match predicate(unsafe { MaybeUninit::assume_init_mut(x) }) {
+            false => None,
+            true => {
+                self.record_exit(ExitReason::Taken);
+                handle.set_success_state(NONE);
+                Some(unsafe { MaybeUninit::assume_init_read(x) })
+            }
+        }
+    }
+
+    /// Thread safe method to take the value out of the option together with a projection computed from it,
+    /// but only if `f` evaluates to `Some` on a mutable reference to the value.
+    ///
+    /// Returns
+    /// * `Some((value, f(&mut value)))` if the option is Some and `f` returns Some; leaving a None in its place;
+    /// * None if the option is None or `f` returns None; the option stays as it is (except for the mutations
+    ///   that `f` might have applied on the value).
+    ///
+    /// The decision and the removal happen atomically; i.e., no other thread can read or mutate the value in between.
+    /// This method generalizes [`ConcurrentOption::take_if`] for the cases where the data computed while deciding
+    /// is also required.
+    ///
+    /// If `f` panics, the option is released as it is; i.e., it keeps its value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some("foo".to_string());
+    ///
+    /// let taken = x.take_if_map(|v| match v.len() > 3 {
+    ///     true => Some(v.len()),
+    ///     false => None,
+    /// });
+    /// assert_eq!(taken, None);
+    /// assert_eq!(x, ConcurrentOption::some("foo".to_string()));
+    ///
+    /// let taken = x.take_if_map(|v| v.chars().next());
+    /// assert_eq!(taken, Some(("foo".to_string(), 'f')));
+    /// assert_eq!(x, ConcurrentOption::none());
+    /// ```
+    pub fn take_if_map<U, F>(&self, f: F) -> Option<(T, U)>
+    where
+        F: FnOnce(&mut T) -> Option<U>,
+    {
+        let mut handle = self.spin_get_write_handle(SOME, SOME)?;
+        let x = unsafe { &mut *self.value.get() };
+        let projection = f(unsafe { MaybeUninit::assume_init_mut(x) })?;
+        self.record_exit(ExitReason::Taken);
+        handle.set_success_state(NONE);
+        Some((unsafe { MaybeUninit::assume_init_read(x) }, projection))
+    }
+
+    /// Thread safe method to take the value out of the option, but only if the `predicate` evaluates to
+    /// `Ok(true)` on a mutable reference to the value; the predicate can abort by returning an `Err` with a reason.
+    ///
+    /// Returns
+    /// * `Ok(Some(value))` if the option is Some and the predicate returns `Ok(true)`; leaving a None in its place;
+    /// * `Ok(None)` if the option is None or the predicate returns `Ok(false)`;
+    /// * `Err(reason)` if the predicate returns `Err(reason)`.
+    ///
+    /// The option stays as it is in the latter two cases, except for the mutations that the predicate might have
+    /// applied on the value.
+    ///
+    /// This method generalizes [`ConcurrentOption::take_if`] for validation-before-consume flows,
+    /// where the caller needs to know why the value is not taken.
+    ///
+    /// If the predicate panics, the option is released as it is; i.e., it keeps its value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Rejected {
+    ///     Expired,
+    /// }
+    ///
+    /// let ticket = ConcurrentOption::some(("ticket", 0u32));
+    ///
+    /// let validate = |(_, age): &mut (&str, u32)| match *age {
+    ///     0 => Ok(false), // not ready yet, but valid
+    ///     1..=3 => Ok(true),
+    ///     _ => Err(Rejected::Expired),
+    /// };
+    ///
+    /// assert_eq!(ticket.take_if_else(validate), Ok(None));
+    ///
+    /// ticket.update_if_some(|(_, age)| *age = 9);
+    /// assert_eq!(ticket.take_if_else(validate), Err(Rejected::Expired));
+    /// assert!(ticket.is_some());
+    ///
+    /// ticket.update_if_some(|(_, age)| *age = 2);
+    /// assert_eq!(ticket.take_if_else(validate), Ok(Some(("ticket", 2))));
+    /// assert!(ticket.is_none());
+    /// ```
+    pub fn take_if_else<U, P>(&self, predicate: P) -> Result<Option<T>, U>
+    where
+        P: FnOnce(&mut T) -> Result<bool, U>,
+    {
+        let Some(mut handle) = self.spin_get_write_handle(SOME, SOME) else {
+            return Ok(None);
+        };
+        let x = unsafe { &mut *self.value.get() };
+        match predicate(unsafe { MaybeUninit::assume_init_mut(x) })? {
+            false => Ok(None),
+            true => {
+                self.record_exit(ExitReason::Taken);
+                handle.set_success_state(NONE);
+                Ok(Some(unsafe { MaybeUninit::assume_init_read(x) }))
             }
         }
     }
@@ -354,6 +673,13 @@ impl<T> ConcurrentOption<T> {
     /// returning the old value if present,
     /// leaving a Some in its place without de-initializing either one.
     ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen by [`freeze`], or if it is in a custom state set by [`transition_custom`].
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -371,34 +697,157 @@ impl<T> ConcurrentOption<T> {
     /// ```
     pub fn replace(&self, value: T) -> Option<T> {
         loop {
-            if let Some(_handle) = self.spin_get_handle(SOME, SOME) {
+            if let Some(_handle) = self.spin_get_write_handle(SOME, SOME) {
                 let x = unsafe { (*self.value.get()).assume_init_mut() };
                 let old = core::mem::replace(x, value);
+                self.record_exit(ExitReason::Replaced);
                 return Some(old);
             }
 
-            if let Some(_handle) = self.spin_get_handle(NONE, SOME) {
+            if let Some(_handle) = self.spin_get_write_handle(NONE, SOME) {
                 let x = unsafe { &mut *self.value.get() };
                 x.write(value);
                 return None;
             }
+
+            self.assert_not_frozen();
+            self.assert_not_custom();
+        }
+    }
+
+    /// Thread safe method to replace the actual value in the option by the value computed by `f`,
+    /// returning the old value if present, leaving a Some in its place.
+    ///
+    /// The replacement is computed from a reference to the current value (if any) while the option is reserved;
+    /// hence, the current value cannot be concurrently mutated between the read and the replacement.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen by [`freeze`], or if it is in a custom state set by [`transition_custom`].
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(2);
+    /// let old = x.replace_with_fn(|old| old.map(|x| x * 10).unwrap_or(1));
+    /// assert_eq!(x, ConcurrentOption::some(20));
+    /// assert_eq!(old, Some(2));
+    ///
+    /// let x: ConcurrentOption<u32> = ConcurrentOption::none();
+    /// let old = x.replace_with_fn(|old| old.map(|x| x * 10).unwrap_or(1));
+    /// assert_eq!(x, ConcurrentOption::some(1));
+    /// assert_eq!(old, None);
+    /// ```
+    pub fn replace_with_fn<F>(&self, f: F) -> Option<T>
+    where
+        F: FnOnce(Option<&T>) -> T,
+    {
+        loop {
+            if let Some(_handle) = self.spin_get_write_handle(SOME, SOME) {
+                let x = unsafe { (*self.value.get()).assume_init_mut() };
+                let value = f(Some(x));
+                let old = core::mem::replace(x, value);
+                self.record_exit(ExitReason::Replaced);
+                return Some(old);
+            }
+
+            if let Some(_handle) = self.spin_get_write_handle(NONE, SOME) {
+                let x = unsafe { &mut *self.value.get() };
+                x.write(f(None));
+                return None;
+            }
+
+            self.assert_not_frozen();
+            self.assert_not_custom();
         }
     }
 
     /// true if updated; false if initiated
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen by [`freeze`], or if it is in a custom state set by [`transition_custom`].
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
     pub fn set_some(&self, value: T) -> bool {
         loop {
-            if let Some(_handle) = self.spin_get_handle(SOME, SOME) {
+            if let Some(_handle) = self.spin_get_write_handle(SOME, SOME) {
                 let x = unsafe { (*self.value.get()).assume_init_mut() };
                 let _old = core::mem::replace(x, value);
+                self.record_drop(ExitReason::Replaced);
                 return true;
             }
 
-            if let Some(_handle) = self.spin_get_handle(NONE, SOME) {
+            if let Some(_handle) = self.spin_get_write_handle(NONE, SOME) {
                 let x = unsafe { &mut *self.value.get() };
                 x.write(value);
                 return false;
             }
+
+            self.assert_not_frozen();
+            self.assert_not_custom();
+        }
+    }
+
+    /// Thread safe method to set the `value` of the option, dropping the old value if any, unless the option is
+    /// reserved by another thread at the moment; in which case, the `value` is dropped instead without waiting.
+    ///
+    /// Returns whether or not the `value` is written to the option.
+    ///
+    /// This provides last-writer-wins semantics for slots which only care about the freshest value,
+    /// such as telemetry or update broadcasts, where a dropped write is preferred over contention.
+    /// Note that the value of a concurrent writer holding the reservation is considered to be the fresher one.
+    ///
+    /// Only the contention with other writers is skipped. Shared readers do not reserve the option; hence, once the
+    /// option is reserved, the method waits until the active readers, such as [`ReadGuard`]s or [`Initialized`] tokens,
+    /// are dropped before overwriting the value. Therefore, the thread holding a reader of the option must not call
+    /// this method on the same option, which would wait for its own reader forever.
+    ///
+    /// [`ReadGuard`]: crate::ReadGuard
+    /// [`Initialized`]: crate::Initialized
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::none();
+    /// assert!(x.set_lazy(1));
+    /// assert!(x.set_lazy(2));
+    /// assert_eq!(x.get_cloned(), Some(2));
+    ///
+    /// let handle = unsafe { x.mut_handle(SOME, SOME) }; // reserved by another writer
+    /// assert!(!x.set_lazy(3));
+    /// drop(handle);
+    ///
+    /// assert_eq!(x.take(), Some(2));
+    /// ```
+    pub fn set_lazy(&self, value: T) -> bool {
+        loop {
+            match self.state.load(ORDER_LOAD) {
+                SOME => {
+                    if let Some(_handle) = self.get_write_handle(SOME, SOME) {
+                        let x = unsafe { (*self.value.get()).assume_init_mut() };
+                        let _old = core::mem::replace(x, value);
+                        self.record_drop(ExitReason::Replaced);
+                        return true;
+                    }
+                }
+                NONE => {
+                    if let Some(_handle) = self.get_write_handle(NONE, SOME) {
+                        let x = unsafe { &mut *self.value.get() };
+                        x.write(value);
+                        return true;
+                    }
+                }
+                _ => return false,
+            }
         }
     }
 
@@ -417,9 +866,16 @@ impl<T> ConcurrentOption<T> {
     ///
     /// * It is safe to use this method if the returned mutable reference is discarded (miri would still complain).
     /// * It is also safe to use this method if the caller is able to guarantee that there exist
-    /// no concurrent reads or writes while mutating the value.
+    ///   no concurrent reads or writes while mutating the value.
     /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
     ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen by [`freeze`], or if it is in a custom state set by [`transition_custom`].
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -429,7 +885,7 @@ impl<T> ConcurrentOption<T> {
     ///
     /// let val = unsafe { opt.insert(1) };
     /// assert_eq!(*val, 1);
-    /// assert_eq!(unsafe { opt.as_ref() }, Some(&1));
+    /// assert_eq!(opt.with_ref(|x| *x), Some(1));
     ///
     /// let val = unsafe { opt.insert(2) };
     /// assert_eq!(*val, 2);
@@ -439,17 +895,21 @@ impl<T> ConcurrentOption<T> {
     #[allow(clippy::mut_from_ref)]
     pub unsafe fn insert(&self, value: T) -> &mut T {
         loop {
-            if let Some(_handle) = self.spin_get_handle(SOME, SOME) {
+            if let Some(_handle) = self.spin_get_write_handle(SOME, SOME) {
                 let x = unsafe { (*self.value.get()).assume_init_mut() };
                 let _old = core::mem::replace(x, value);
+                self.record_drop(ExitReason::Replaced);
                 return x;
             }
 
-            if let Some(_handle) = self.spin_get_handle(NONE, SOME) {
+            if let Some(_handle) = self.spin_get_write_handle(NONE, SOME) {
                 let x = unsafe { &mut *self.value.get() };
                 x.write(value);
                 return unsafe { x.assume_init_mut() };
             }
+
+            self.assert_not_frozen();
+            self.assert_not_custom();
         }
     }
 
@@ -467,9 +927,16 @@ impl<T> ConcurrentOption<T> {
     ///
     /// * It is safe to use this method if the returned mutable reference is discarded (miri would still complain).
     /// * It is also safe to use this method if the caller is able to guarantee that there exist
-    /// no concurrent reads or writes while mutating the value.
+    ///   no concurrent reads or writes while mutating the value.
     /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
     ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen by [`freeze`], or if it is in a custom state set by [`transition_custom`].
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -489,18 +956,66 @@ impl<T> ConcurrentOption<T> {
     #[allow(clippy::mut_from_ref)]
     pub unsafe fn get_or_insert(&self, value: T) -> &mut T {
         loop {
-            if let Some(_handle) = self.spin_get_handle(SOME, SOME) {
+            if let Some(_handle) = self.spin_get_write_handle(SOME, SOME) {
                 return unsafe { (*self.value.get()).assume_init_mut() };
             }
 
-            if let Some(_handle) = self.spin_get_handle(NONE, SOME) {
+            if let Some(_handle) = self.spin_get_write_handle(NONE, SOME) {
                 let x = unsafe { &mut *self.value.get() };
                 x.write(value);
                 return unsafe { x.assume_init_mut() };
             }
+
+            self.assert_not_frozen();
+            self.assert_not_custom();
         }
     }
 
+    /// Partially thread safe method to insert the default value into the option if it is None,
+    /// then returns a mutable reference to the contained value.
+    ///
+    /// # Safety
+    ///
+    /// Note that the insertion part of this method is thread safe.
+    ///
+    /// The method is `unsafe` due to the returned mutable reference to the underlying value.
+    ///
+    /// * It is safe to use this method if the returned mutable reference is discarded (miri would still complain).
+    /// * It is also safe to use this method if the caller is able to guarantee that there exist
+    ///   no concurrent reads or writes while mutating the value.
+    /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen by [`freeze`], or if it is in a custom state set by [`transition_custom`].
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::none();
+    ///
+    /// {
+    ///     let y: &mut u32 = unsafe { x.get_or_insert_default() };
+    ///     assert_eq!(y, &0);
+    ///
+    ///     *y = 7;
+    /// }
+    ///
+    /// assert_eq!(x, ConcurrentOption::some(7));
+    /// ```
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn get_or_insert_default(&self) -> &mut T
+    where
+        T: Default,
+    {
+        unsafe { self.get_or_insert_with(T::default) }
+    }
+
     /// Partially thread safe method to insert a value computed from `f` into the option if it is None,
     /// then returns a mutable reference to the contained value.
     ///
@@ -512,9 +1027,16 @@ impl<T> ConcurrentOption<T> {
     ///
     /// * It is safe to use this method if the returned mutable reference is discarded (miri would still complain).
     /// * It is also safe to use this method if the caller is able to guarantee that there exist
-    /// no concurrent reads or writes while mutating the value.
+    ///   no concurrent reads or writes while mutating the value.
     /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
     ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen by [`freeze`], or if it is in a custom state set by [`transition_custom`].
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -533,19 +1055,149 @@ impl<T> ConcurrentOption<T> {
     /// ```
     #[allow(clippy::mut_from_ref)]
     pub unsafe fn get_or_insert_with<F>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        unsafe { self.get_or_insert_with_status(f) }.0
+    }
+
+    /// Partially thread safe method to insert a value computed from `f` into the option if it is None,
+    /// then returns a mutable reference to the contained value together with whether or not
+    /// `f` is called and its value is inserted.
+    ///
+    /// This is useful, for instance, to count the misses of a cache without a side-effecting closure.
+    ///
+    /// # Safety
+    ///
+    /// Note that the insertion part of this method is thread safe.
+    ///
+    /// The method is `unsafe` due to the returned mutable reference to the underlying value.
+    ///
+    /// * It is safe to use this method if the returned mutable reference is discarded (miri would still complain).
+    /// * It is also safe to use this method if the caller is able to guarantee that there exist
+    ///   no concurrent reads or writes while mutating the value.
+    /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen by [`freeze`], or if it is in a custom state set by [`transition_custom`].
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::none();
+    ///
+    /// let (y, inserted) = unsafe { x.get_or_insert_with_status(|| 5) };
+    /// assert_eq!((*y, inserted), (5, true));
+    ///
+    /// let (y, inserted) = unsafe { x.get_or_insert_with_status(|| 7) };
+    /// assert_eq!((*y, inserted), (5, false));
+    /// ```
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn get_or_insert_with_status<F>(&self, f: F) -> (&mut T, bool)
     where
         F: FnOnce() -> T,
     {
         loop {
-            if let Some(_handle) = self.spin_get_handle(SOME, SOME) {
-                return unsafe { (*self.value.get()).assume_init_mut() };
+            if let Some(_handle) = self.spin_get_write_handle(SOME, SOME) {
+                return (unsafe { (*self.value.get()).assume_init_mut() }, false);
             }
 
-            if let Some(_handle) = self.spin_get_handle(NONE, SOME) {
+            if let Some(_handle) = self.spin_get_write_handle(NONE, SOME) {
                 let x = unsafe { &mut *self.value.get() };
                 x.write(f());
-                return unsafe { x.assume_init_mut() };
+                return (unsafe { x.assume_init_mut() }, true);
             }
+
+            self.assert_not_frozen();
+            self.assert_not_custom();
         }
     }
+
+    /// Thread safe method which returns whether or not the current value of the option is equal to the `snapshot`,
+    /// such as one taken earlier by [`get_cloned`].
+    ///
+    /// Unlike the `PartialEq` implementation, the comparison is made while no mutation can take place on the option;
+    /// hence, this method can be used as the validation step of optimistic concurrency.
+    ///
+    /// You may call [`eq_snapshot_with_order`] to use the desired ordering.
+    ///
+    /// [`get_cloned`]: ConcurrentOption::get_cloned
+    /// [`eq_snapshot_with_order`]: ConcurrentOption::eq_snapshot_with_order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3.to_string());
+    ///
+    /// let snapshot = x.get_cloned();
+    /// let len = snapshot.as_ref().map(|x| x.len()); // computation on the snapshot
+    ///
+    /// // the computation is valid only if no other thread has changed the value in between
+    /// assert!(x.eq_snapshot(&snapshot));
+    /// assert_eq!(len, Some(1));
+    ///
+    /// x.update_if_some(|x| x.push('!'));
+    /// assert!(!x.eq_snapshot(&snapshot));
+    /// assert!(!x.eq_snapshot(&None));
+    /// ```
+    pub fn eq_snapshot(&self, snapshot: &Option<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.eq_snapshot_with_order(snapshot, LoadOrdering::Acquire)
+    }
+
+    /// Returns whether or not self is equal to the `other`; returns None without waiting
+    /// if either of the options is reserved by another thread.
+    ///
+    /// Unlike the `PartialEq` implementation, the values are compared while no mutation can take place on the options;
+    /// and unlike [`eq_snapshot`], the method never spins behind a writer.
+    /// This makes it suitable for monitoring threads which must not be blocked by the threads they observe.
+    ///
+    /// [`eq_snapshot`]: ConcurrentOption::eq_snapshot
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    /// let y = ConcurrentOption::some(3);
+    /// let z = ConcurrentOption::<i32>::none();
+    ///
+    /// assert_eq!(x.try_eq(&y), Some(true));
+    /// assert_eq!(x.try_eq(&z), Some(false));
+    /// assert_eq!(z.try_eq(&z), Some(true));
+    ///
+    /// let handle = unsafe { y.mut_handle(SOME, SOME) }; // reserved by a writer
+    /// assert_eq!(x.try_eq(&y), None);
+    /// assert_eq!(y.try_eq(&z), None);
+    ///
+    /// drop(handle);
+    /// assert_eq!(x.try_eq(&y), Some(true));
+    /// ```
+    pub fn try_eq(&self, other: &Self) -> Option<bool>
+    where
+        T: PartialEq,
+    {
+        let l = ReadHandle::get(&self.state, &self.generation).ok()?;
+        let r = ReadHandle::get(&other.state, &other.generation).ok()?;
+        Some(match (l, r) {
+            (Some(_l), Some(_r)) => {
+                let l = unsafe { (*self.value.get()).assume_init_ref() };
+                let r = unsafe { (*other.value.get()).assume_init_ref() };
+                l.eq(r)
+            }
+            (None, None) => true,
+            _ => false,
+        })
+    }
 }
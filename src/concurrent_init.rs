@@ -0,0 +1,141 @@
+use crate::{ConcurrentOption, SlotClaim};
+
+/// A list of options to be initialized together by a [`ConcurrentInit`], together with their values.
+///
+/// It is implemented by the lists built by [`ConcurrentInit::with`].
+pub trait InitTargets: sealed::Sealed {}
+
+mod sealed {
+    pub trait Sealed {
+        type Claims;
+
+        fn claim(&self) -> Option<Self::Claims>;
+
+        fn publish(self, claims: Self::Claims);
+    }
+}
+
+impl sealed::Sealed for () {
+    type Claims = ();
+
+    fn claim(&self) -> Option<Self::Claims> {
+        Some(())
+    }
+
+    fn publish(self, _: Self::Claims) {}
+}
+
+impl InitTargets for () {}
+
+impl<'a, L, T> sealed::Sealed for (L, (&'a ConcurrentOption<T>, T))
+where
+    L: InitTargets,
+{
+    type Claims = (L::Claims, SlotClaim<'a, T>);
+
+    fn claim(&self) -> Option<Self::Claims> {
+        let claims = self.0.claim()?;
+        // claims of the previous targets are released as None if this one fails
+        let claim = self.1 .0.try_claim()?;
+        Some((claims, claim))
+    }
+
+    fn publish(self, (claims, claim): Self::Claims) {
+        self.0.publish(claims);
+        claim.publish(self.1 .1);
+    }
+}
+
+impl<L: InitTargets, T> InitTargets for (L, (&ConcurrentOption<T>, T)) {}
+
+/// A builder which initializes multiple options, possibly of different types, all-or-nothing.
+///
+/// Targets are added by [`with`], and the values are written by [`commit`] in two phases:
+/// * all options are reserved, which succeeds only if every option is None at the moment;
+/// * the values are published to all options.
+///
+/// If any of the options cannot be reserved, the reservations acquired so far are rolled back,
+/// leaving all options as None; and none of the values is written.
+/// Therefore, other threads either observe none or all of the options initialized by the builder.
+/// Note that they might observe some of the options as reserved while the commit is in progress.
+///
+/// The reservations do not wait; an option which is reserved by another thread at the moment is considered
+/// not to be None. Hence, concurrent commits on overlapping options never deadlock.
+///
+/// [`with`]: ConcurrentInit::with
+/// [`commit`]: ConcurrentInit::commit
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// let name = ConcurrentOption::none();
+/// let port = ConcurrentOption::none();
+///
+/// let init = ConcurrentInit::new()
+///     .with(&name, "server".to_string())
+///     .with(&port, 8080);
+/// assert!(init.commit().is_ok());
+///
+/// assert_eq!(name.get_cloned(), Some("server".to_string()));
+/// assert_eq!(port.get_cloned(), Some(8080));
+///
+/// // fails as a whole since the name is already initialized
+/// _ = port.take();
+/// let init = ConcurrentInit::new()
+///     .with(&name, "client".to_string())
+///     .with(&port, 9090);
+/// assert!(init.commit().is_err());
+///
+/// assert_eq!(name.get_cloned(), Some("server".to_string()));
+/// assert_eq!(port.get_cloned(), None);
+/// ```
+#[must_use = "values are written only once the builder is committed"]
+pub struct ConcurrentInit<L = ()> {
+    targets: L,
+}
+
+impl ConcurrentInit {
+    /// Creates a builder without any targets.
+    pub fn new() -> Self {
+        Self { targets: () }
+    }
+}
+
+impl Default for ConcurrentInit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L: InitTargets> ConcurrentInit<L> {
+    /// Adds the `option` to the targets which is to be initialized with the `value`.
+    ///
+    /// Note that an option added more than once can never be committed, since the second reservation fails.
+    pub fn with<T>(
+        self,
+        option: &ConcurrentOption<T>,
+        value: T,
+    ) -> ConcurrentInit<(L, (&ConcurrentOption<T>, T))> {
+        ConcurrentInit {
+            targets: (self.targets, (option, value)),
+        }
+    }
+
+    /// Thread safe method to initialize all targets with their values if all of them are None;
+    /// and to leave all of them untouched otherwise.
+    ///
+    /// Returns back the builder as the error if the commit fails, which can be committed again later.
+    ///
+    /// See [`ConcurrentInit`] for details.
+    pub fn commit(self) -> Result<(), Self> {
+        match sealed::Sealed::claim(&self.targets) {
+            Some(claims) => {
+                self.targets.publish(claims);
+                Ok(())
+            }
+            None => Err(self),
+        }
+    }
+}
@@ -1,5 +1,11 @@
-use crate::{handle::Handle, mut_handle::MutHandle, states::StateU8};
-use core::{cell::UnsafeCell, mem::MaybeUninit, sync::atomic::AtomicU8};
+use crate::{
+    atomic::AtomicU8,
+    generation::Generation,
+    handle::{Handle, ReadHandle},
+    mut_handle::{InvalidTransition, MutHandle, SomeMutHandle},
+    states::{State, StateU8},
+};
+use core::{cell::UnsafeCell, mem::MaybeUninit};
 
 /// ConcurrentOption is a thread-safe and lock-free read-write option type.
 ///
@@ -35,6 +41,8 @@ use core::{cell::UnsafeCell, mem::MaybeUninit, sync::atomic::AtomicU8};
 ///
 /// These methods guarantee that there exist no mutation while reading the data.
 ///
+/// Read methods do not reserve the option exclusively; hence, any number of threads can read the value concurrently without waiting for each other. A writer, on the other hand, waits until the active readers complete before mutating the value.
+///
 /// ### ⬤ Partially thread safe methods
 ///
 /// Methods which return a shared reference `&T` or mutable reference `&mut T` to the underlying value of the optional are marked as `unsafe`.
@@ -45,9 +53,15 @@ use core::{cell::UnsafeCell, mem::MaybeUninit, sync::atomic::AtomicU8};
 ///
 /// Some example methods are `as_ref`, `as_deref`, `insert`, etc.
 ///
+/// ### ⬤ Closures
+///
+/// Closures passed to the methods are called at most once per call. Accordingly, these methods accept `FnOnce` closures, which allows side effects such as moving captured values. A method which may call its closure more than once, such as a retrying implementation or a method acting on multiple options like `for_each_take`, accepts an `FnMut` closure and documents how many times the closure can be called.
+///
+/// Closures of the mutating methods, such as `update_if_some`, `take_if` or `replace_with_fn`, are called while the option is reserved exclusively. Closures of the read methods, such as `map`, `and_then` or `map_or_default`, are called while holding a read handle; they can run concurrently with other readers, while writers wait until they complete.
+///
 /// ### ⬤ Methods to allow manual control on concurrency
 ///
-/// ConcurrentOption also exposes methods which accepts a `core::sync::atomic::Ordering` and gives the control to the caller. These methods are suffixed with **with_order**, except for the state.
+/// ConcurrentOption also exposes methods which accept a `LoadOrdering`, a `core::sync::atomic::Ordering` restricted to the orderings valid for loads, and give the control to the caller. These methods are suffixed with **with_order**, except for the state.
 ///
 /// Some such methods are `state`, `as_ref_with_order`, `get_raw_with_order`, `clone_with_order`, etc.
 ///
@@ -201,24 +215,144 @@ use core::{cell::UnsafeCell, mem::MaybeUninit, sync::atomic::AtomicU8};
 pub struct ConcurrentOption<T> {
     pub(crate) value: UnsafeCell<MaybeUninit<T>>,
     pub(crate) state: AtomicU8,
+    pub(crate) generation: Generation,
+    #[cfg(feature = "write-intent")]
+    pub(crate) num_pending_writers: crate::atomic::AtomicU16,
+    #[cfg(feature = "init-once")]
+    pub(crate) once_begun: AtomicU8,
+    #[cfg(feature = "stats")]
+    pub(crate) stats: crate::stats::Stats,
 }
 
+// without the optional bookkeeping features, the option adds a state byte and a 32-bit reader count to the value
+#[cfg(not(any(
+    miri,
+    feature = "last-updated",
+    feature = "tokio",
+    feature = "stats",
+    feature = "ref-tracking",
+    feature = "generation",
+    feature = "init-once",
+    feature = "write-intent"
+)))]
+const _: () = assert!(
+    core::mem::size_of::<ConcurrentOption<u8>>() == 8
+        && core::mem::size_of::<ConcurrentOption<u64>>() == 16
+);
+
 impl<T> ConcurrentOption<T> {
-    pub(crate) fn get_handle(
+    #[inline(always)]
+    pub(crate) fn spin_get_handle(
         &self,
         initial_state: StateU8,
         success_state: StateU8,
     ) -> Option<Handle<'_>> {
-        Handle::get(&self.state, initial_state, success_state)
+        Handle::spin_get(&self.state, initial_state, success_state, None)
     }
 
+    /// Handle of a shared reader which does not reserve the option;
+    /// returns None if the option is not of Some variant.
     #[inline(always)]
-    pub(crate) fn spin_get_handle(
+    pub(crate) fn spin_get_read_handle(&self) -> Option<ReadHandle<'_>> {
+        ReadHandle::spin_get(&self.state, &self.generation)
+    }
+
+    /// Handle which increments the generation of the optional once it is dropped;
+    /// must be used whenever the value might be mutated while the handle is alive.
+    pub(crate) fn get_write_handle(
         &self,
         initial_state: StateU8,
         success_state: StateU8,
     ) -> Option<Handle<'_>> {
-        Handle::spin_get(&self.state, initial_state, success_state)
+        Handle::get(
+            &self.state,
+            initial_state,
+            success_state,
+            Some(&self.generation),
+        )
+    }
+
+    /// Handle which increments the generation of the optional once it is dropped;
+    /// must be used whenever the value might be mutated while the handle is alive.
+    #[inline(always)]
+    pub(crate) fn spin_get_write_handle(
+        &self,
+        initial_state: StateU8,
+        success_state: StateU8,
+    ) -> Option<Handle<'_>> {
+        Handle::spin_get(
+            &self.state,
+            initial_state,
+            success_state,
+            Some(&self.generation),
+        )
+    }
+
+    /// Increments the generation of the optional through an exclusive reference.
+    #[inline(always)]
+    pub(crate) fn exclusive_bump_generation(&mut self) {
+        self.generation.bump_mut();
+    }
+
+    /// Returns a [`Watcher`] which can be used to cheaply poll whether or not the optional
+    /// has been mutated since the last check.
+    ///
+    /// See [`Watcher::has_changed`] for details.
+    ///
+    /// Available with the `generation` feature, which adds the generation counter to every option.
+    ///
+    /// [`Watcher`]: crate::Watcher
+    /// [`Watcher::has_changed`]: crate::Watcher::has_changed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::<String>::none();
+    /// let mut watcher = x.watcher();
+    /// assert!(!watcher.has_changed());
+    ///
+    /// x.initialize_if_none(3.to_string());
+    /// assert!(watcher.has_changed());
+    /// assert!(!watcher.has_changed());
+    /// ```
+    #[cfg(feature = "generation")]
+    pub fn watcher(&self) -> crate::Watcher<'_> {
+        crate::Watcher::new(&self.generation)
+    }
+
+    /// Returns the instant of the last successful mutation of the optional;
+    /// None if the optional has not been mutated since it is created.
+    ///
+    /// Every mutating transition, such as an initialization, update, replacement or take,
+    /// records the instant right before the optional is released.
+    ///
+    /// Available with the `last-updated` feature, which reads the clock on every mutation
+    /// and adds the 64-bit timestamp to every option.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::<String>::none();
+    /// assert_eq!(x.last_updated(), None);
+    ///
+    /// let before = std::time::Instant::now();
+    /// x.initialize_if_none(3.to_string());
+    /// let first = x.last_updated().unwrap();
+    /// assert!(first >= before);
+    ///
+    /// _ = x.map(|x| x.len()); // reads are not updates
+    /// assert_eq!(x.last_updated(), Some(first));
+    ///
+    /// _ = x.take();
+    /// assert!(x.last_updated().unwrap() >= first);
+    /// ```
+    #[cfg(feature = "last-updated")]
+    pub fn last_updated(&self) -> Option<std::time::Instant> {
+        self.generation.last_updated(crate::states::ORDER_LOAD)
     }
 
     /// Provides the mut handle on the value of the optional:
@@ -233,11 +367,93 @@ impl<T> ConcurrentOption<T> {
         &self,
         initial_state: StateU8,
         success_state: StateU8,
-    ) -> Option<MutHandle<T>> {
+    ) -> Option<MutHandle<'_, T>> {
         MutHandle::spin_get(self, initial_state, success_state)
     }
+
+    /// Typed version of [`mut_handle`] which validates the requested transition before reserving the optional.
+    ///
+    /// Provides the mut handle on the value of the optional:
+    /// * the optional must be in the `initial_state` for this method to succeed, returns `Ok(None)` otherwise;
+    /// * the optional will be brought to `success_state` once the handle is dropped.
+    ///
+    /// [`mut_handle`]: ConcurrentOption::mut_handle
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidTransition`] error without touching the optional if
+    /// * the `initial_state` is [`State::Reserved`] or [`State::Frozen`];
+    /// * the `success_state` is [`State::Reserved`] or [`State::Frozen`]; or
+    /// * either of the states is a [`State::Custom`] whose raw value is not a custom state.
+    ///
+    /// # Safety
+    ///
+    /// This method is unsafe since the handle provides direct access to the underlying
+    /// value, skipping thread-safety guarantees.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::<String>::none();
+    ///
+    /// let handle = unsafe { x.mut_handle_typed(State::None, State::Some) };
+    /// let handle = handle.unwrap().unwrap();
+    /// unsafe { (*handle.value.get()).write(3.to_string()) };
+    /// drop(handle);
+    /// assert_eq!(x.get_cloned(), Some(3.to_string()));
+    ///
+    /// // the optional is not None
+    /// let handle = unsafe { x.mut_handle_typed(State::None, State::Some) };
+    /// assert!(handle.unwrap().is_none());
+    ///
+    /// let handle = unsafe { x.mut_handle_typed(State::Reserved, State::Some) };
+    /// assert_eq!(handle.unwrap_err(), InvalidTransition::ReservedInitialState);
+    /// ```
+    pub unsafe fn mut_handle_typed(
+        &self,
+        initial_state: State,
+        success_state: State,
+    ) -> Result<Option<MutHandle<'_, T>>, InvalidTransition> {
+        let (initial_state, success_state) =
+            InvalidTransition::validate(initial_state, success_state)?;
+        Ok(MutHandle::spin_get(self, initial_state, success_state))
+    }
+
+    /// Provides the mut handle on the value of the optional if it is of Some variant; returns None otherwise.
+    /// The optional will be brought to `success_state` once the handle is dropped.
+    ///
+    /// Unlike [`mut_handle`], the returned [`SomeMutHandle`] provides access to the value through `Deref` and `DerefMut`.
+    ///
+    /// [`mut_handle`]: ConcurrentOption::mut_handle
+    ///
+    /// # Safety
+    ///
+    /// This method is unsafe since the value is left in the optional when it is brought to the `success_state`;
+    /// hence, the caller must ensure that the `success_state` is consistent with the value being initialized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    ///
+    /// if let Some(mut handle) = unsafe { x.some_mut_handle(SOME) } {
+    ///     *handle += 1;
+    /// }
+    /// assert_eq!(x.take(), Some(4));
+    ///
+    /// assert!(unsafe { x.some_mut_handle(SOME) }.is_none());
+    /// ```
+    pub unsafe fn some_mut_handle(&self, success_state: StateU8) -> Option<SomeMutHandle<'_, T>> {
+        MutHandle::spin_get(self, crate::states::SOME, success_state)
+            .map(|handle| SomeMutHandle { handle })
+    }
 }
 
 unsafe impl<T: Send> Send for ConcurrentOption<T> {}
 
+#[cfg(not(orx_concurrent_option_single_thread))]
 unsafe impl<T: Sync> Sync for ConcurrentOption<T> {}
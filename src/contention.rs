@@ -0,0 +1,247 @@
+use crate::{handle::Handle, states::*, stats::ExitReason, ConcurrentOption};
+use core::mem::MaybeUninit;
+
+/// A reasonable limit on the number of spins of the bounded **try_** methods, such as [`ConcurrentOption::try_take`].
+///
+/// It is `2^20`, or `2^16 - 1` on 16-bit targets.
+///
+/// The limit is a budget of a single call: it bounds the total number of spins of that call, over all of its
+/// attempts to reserve the option. It is neither shared with the concurrent calls of other threads nor a process-wide
+/// setting; a global contention policy is applied by passing the same limit, such as this constant, to every call.
+#[cfg(not(target_pointer_width = "16"))]
+pub const DEFAULT_MAX_SPINS: usize = 1 << 20;
+
+/// A reasonable limit on the number of spins of the bounded **try_** methods, such as [`ConcurrentOption::try_take`].
+///
+/// It is `2^20`, or `2^16 - 1` on 16-bit targets.
+///
+/// The limit is a budget of a single call: it bounds the total number of spins of that call, over all of its
+/// attempts to reserve the option. It is neither shared with the concurrent calls of other threads nor a process-wide
+/// setting; a global contention policy is applied by passing the same limit, such as this constant, to every call.
+#[cfg(target_pointer_width = "16")]
+pub const DEFAULT_MAX_SPINS: usize = u16::MAX as usize;
+
+/// Error of the bounded **try_** methods, such as [`ConcurrentOption::try_take`], indicating that the option
+/// remained reserved by another thread during the given number of spins.
+///
+/// The value which could not be written, if any, is returned back in the error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contended<T = ()>(pub T);
+
+impl<T> Contended<T> {
+    /// Returns the value which could not be written.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> core::fmt::Display for Contended<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ConcurrentOption remained reserved during the maximum number of spins"
+        )
+    }
+}
+
+/// Error of the bounded replace methods, such as [`ConcurrentOption::try_replace`], returning back the value
+/// which could not be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplaceError<T> {
+    /// The option remained reserved by another thread during the limit of the method; see [`Contended`].
+    Contended(T),
+    /// The option is frozen by [`ConcurrentOption::freeze`]; hence, its value can never be replaced
+    /// through a shared reference.
+    Frozen(T),
+}
+
+impl<T> ReplaceError<T> {
+    /// Returns the value which could not be written.
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Contended(value) => value,
+            Self::Frozen(value) => value,
+        }
+    }
+}
+
+impl<T> core::fmt::Display for ReplaceError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Contended(_) => write!(
+                f,
+                "ConcurrentOption remained reserved during the maximum number of spins"
+            ),
+            Self::Frozen(_) => write!(
+                f,
+                "frozen ConcurrentOption cannot be mutated through a shared reference"
+            ),
+        }
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    fn spin_get_write_handle_bounded(
+        &self,
+        initial_state: StateU8,
+        success_state: StateU8,
+        spins_left: &mut usize,
+    ) -> Result<Option<Handle<'_>>, Contended> {
+        Handle::spin_get_bounded(
+            &self.state,
+            initial_state,
+            success_state,
+            Some(&self.generation),
+            spins_left,
+        )
+    }
+
+    /// Bounded version of [`update_if_some`].
+    ///
+    /// Thread safe method to update the value of the option if it is of Some variant.
+    /// Does nothing if it is None.
+    /// Returns whether or not the value is updated.
+    ///
+    /// Spins at most `max_spins` times while the option is reserved by another thread; and then,
+    /// gives up and returns a [`Contended`] error. [`DEFAULT_MAX_SPINS`] is a reasonable limit.
+    /// The limit applies to this call only; see [`DEFAULT_MAX_SPINS`] for the semantics of the budget.
+    ///
+    /// [`DEFAULT_MAX_SPINS`]: crate::DEFAULT_MAX_SPINS
+    ///
+    /// [`update_if_some`]: ConcurrentOption::update_if_some
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    /// assert_eq!(x.try_update_if_some(|x| *x += 1, DEFAULT_MAX_SPINS), Ok(true));
+    /// assert_eq!(x.map(|x| *x), Some(4));
+    ///
+    /// let x = ConcurrentOption::<i32>::none();
+    /// assert_eq!(x.try_update_if_some(|x| *x += 1, DEFAULT_MAX_SPINS), Ok(false));
+    /// ```
+    pub fn try_update_if_some<F>(&self, f: F, max_spins: usize) -> Result<bool, Contended>
+    where
+        F: FnOnce(&mut T),
+    {
+        let mut spins_left = max_spins;
+        match self.spin_get_write_handle_bounded(SOME, SOME, &mut spins_left)? {
+            Some(_handle) => {
+                let x = unsafe { MaybeUninit::assume_init_mut(&mut *self.value.get()) };
+                f(x);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Bounded version of [`take`].
+    ///
+    /// Thread safe method to take the value out of the option if Some, leaving a None in its place.
+    /// Has no impact and returns None, if the option is of None variant.
+    ///
+    /// Spins at most `max_spins` times while the option is reserved by another thread; and then,
+    /// gives up and returns a [`Contended`] error. [`DEFAULT_MAX_SPINS`] is a reasonable limit.
+    /// The limit applies to this call only; see [`DEFAULT_MAX_SPINS`] for the semantics of the budget.
+    ///
+    /// [`DEFAULT_MAX_SPINS`]: crate::DEFAULT_MAX_SPINS
+    ///
+    /// [`take`]: ConcurrentOption::take
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(42);
+    /// assert_eq!(x.try_take(DEFAULT_MAX_SPINS), Ok(Some(42)));
+    /// assert_eq!(x.try_take(DEFAULT_MAX_SPINS), Ok(None));
+    ///
+    /// let x = ConcurrentOption::some(42);
+    /// let handle = unsafe { x.mut_handle(SOME, SOME) }; // reserved by a stalled writer
+    /// assert_eq!(x.try_take(16), Err(Contended(())));
+    ///
+    /// drop(handle);
+    /// assert_eq!(x.try_take(DEFAULT_MAX_SPINS), Ok(Some(42)));
+    /// ```
+    pub fn try_take(&self, max_spins: usize) -> Result<Option<T>, Contended> {
+        let mut spins_left = max_spins;
+        match self.spin_get_write_handle_bounded(SOME, NONE, &mut spins_left)? {
+            Some(_handle) => {
+                self.record_exit(ExitReason::Taken);
+                let x = unsafe { &*self.value.get() };
+                Ok(Some(unsafe { MaybeUninit::assume_init_read(x) }))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Bounded version of [`replace`].
+    ///
+    /// Thread safe method to replace the value in the option by the given `value`,
+    /// returning the old value if present, leaving a Some in its place.
+    ///
+    /// Returns the `value` back in a [`ReplaceError`]:
+    /// * [`ReplaceError::Contended`] if the option remains reserved by other threads
+    ///   during `max_spins` spins in total, for which [`DEFAULT_MAX_SPINS`] is a reasonable limit;
+    /// * [`ReplaceError::Frozen`] if the option is frozen by [`freeze`].
+    ///
+    /// The limit applies to this call only; see [`DEFAULT_MAX_SPINS`] for the semantics of the budget.
+    ///
+    /// [`replace`]: ConcurrentOption::replace
+    /// [`freeze`]: ConcurrentOption::freeze
+    /// [`DEFAULT_MAX_SPINS`]: crate::DEFAULT_MAX_SPINS
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is in a custom state set by [`transition_custom`].
+    ///
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::none();
+    /// assert_eq!(x.try_replace(3, DEFAULT_MAX_SPINS), Ok(None));
+    /// assert_eq!(x.try_replace(7, DEFAULT_MAX_SPINS), Ok(Some(3)));
+    /// assert_eq!(x.map(|x| *x), Some(7));
+    ///
+    /// _ = x.freeze();
+    /// assert_eq!(x.try_replace(8, DEFAULT_MAX_SPINS), Err(ReplaceError::Frozen(8)));
+    /// ```
+    pub fn try_replace(&self, value: T, max_spins: usize) -> Result<Option<T>, ReplaceError<T>> {
+        // the budget is shared by all attempts of this call
+        let mut spins_left = max_spins;
+        loop {
+            match self.spin_get_write_handle_bounded(SOME, SOME, &mut spins_left) {
+                Ok(Some(_handle)) => {
+                    let x = unsafe { (*self.value.get()).assume_init_mut() };
+                    let old = core::mem::replace(x, value);
+                    self.record_exit(ExitReason::Replaced);
+                    return Ok(Some(old));
+                }
+                Ok(None) => {}
+                Err(_) => return Err(ReplaceError::Contended(value)),
+            }
+
+            match self.spin_get_write_handle_bounded(NONE, SOME, &mut spins_left) {
+                Ok(Some(_handle)) => {
+                    let x = unsafe { &mut *self.value.get() };
+                    x.write(value);
+                    return Ok(None);
+                }
+                Ok(None) => {}
+                Err(_) => return Err(ReplaceError::Contended(value)),
+            }
+
+            if self.is_frozen() {
+                return Err(ReplaceError::Frozen(value));
+            }
+            self.assert_not_custom();
+        }
+    }
+}
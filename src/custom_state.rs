@@ -0,0 +1,201 @@
+use crate::{
+    parking::{notify, snooze},
+    states::*,
+    ConcurrentOption,
+};
+use core::sync::atomic::Ordering;
+
+/// Flag of the raw state which is set for all custom states.
+const CUSTOM_STATE_FLAG: StateU8 = 0b0100_0000;
+/// Flag of the raw state which is set for custom states in which the optional holds a value.
+const HOLDS_VALUE_FLAG: StateU8 = 0b1000_0000;
+/// Maximum number of distinct custom states.
+pub const MAX_NUM_CUSTOM_STATES: u8 = CUSTOM_STATE_FLAG;
+
+/// A user-defined state of the optional in addition to the built-in NONE, SOME and RESERVED states.
+///
+/// Custom states allow concurrent protocols to use one more slot state, such as "claimed" or "dirty",
+/// without requiring a second atomic per slot.
+///
+/// A custom state is defined by:
+/// * `id`: an identifier which is unique among the custom states of the application and less than [`MAX_NUM_CUSTOM_STATES`];
+/// * `holds_value`: whether or not the optional holds an initialized value while it is in this state.
+///
+/// The optional can move in and out of custom states by [`ConcurrentOption::transition_custom`].
+///
+/// Note that the built-in methods keep their semantics and treat custom states as unavailable:
+/// * `is_some` returns false and `is_none` returns true,
+/// * read methods such as `map` or `as_ref` behave as if the optional is None,
+/// * mutating methods such as `take` or `initialize_if_none` have no impact,
+/// * mutating methods which cannot report a failure, such as `replace`, `set_some` or `insert`, panic
+///   rather than waiting for the optional to leave the custom state.
+///
+/// When dropped in a custom state which holds a value, the value of the optional is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// #[derive(Clone, Copy)]
+/// struct Claimed;
+///
+/// impl CustomState for Claimed {
+///     fn id(self) -> u8 {
+///         0
+///     }
+///
+///     fn holds_value(self) -> bool {
+///         true
+///     }
+/// }
+///
+/// let x = ConcurrentOption::some(3.to_string());
+///
+/// assert!(x.transition_custom(SOME, Claimed.state()));
+/// assert_eq!(x.state(LoadOrdering::Relaxed), State::Custom(Claimed.state()));
+/// assert_eq!(x.map(|x| x.len()), None);
+///
+/// assert!(!x.transition_custom(SOME, Claimed.state()));
+///
+/// assert!(x.transition_custom(Claimed.state(), SOME));
+/// assert_eq!(x.map(|x| x.len()), Some(1));
+/// ```
+pub trait CustomState: Copy {
+    /// Identifier of the custom state which must be unique among the custom states of the application;
+    /// and must be less than [`MAX_NUM_CUSTOM_STATES`].
+    fn id(self) -> u8;
+
+    /// Returns whether or not the optional holds an initialized value while it is in this state.
+    fn holds_value(self) -> bool;
+
+    /// Raw state value corresponding to this custom state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not less than [`MAX_NUM_CUSTOM_STATES`].
+    fn state(self) -> StateU8 {
+        let id = self.id();
+        assert!(
+            id < MAX_NUM_CUSTOM_STATES,
+            "custom state id must be less than MAX_NUM_CUSTOM_STATES"
+        );
+        match self.holds_value() {
+            true => CUSTOM_STATE_FLAG | HOLDS_VALUE_FLAG | id,
+            false => CUSTOM_STATE_FLAG | id,
+        }
+    }
+}
+
+/// Returns whether or not the raw `state` is a custom state.
+#[inline(always)]
+pub(crate) fn is_custom(state: StateU8) -> bool {
+    state & CUSTOM_STATE_FLAG == CUSTOM_STATE_FLAG
+}
+
+/// Returns whether or not the optional holds an initialized value in the raw `state`.
+#[inline(always)]
+pub(crate) fn holds_value(state: StateU8) -> bool {
+    match state {
+        SOME | FROZEN => true,
+        x => is_custom(x) && x & HOLDS_VALUE_FLAG == HOLDS_VALUE_FLAG,
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Thread safe method to transition the optional from state `from` to state `to`,
+    /// where at least one of them is a custom state defined by [`CustomState`].
+    ///
+    /// Returns
+    /// * true if the optional was in state `from` and is transitioned to state `to`;
+    /// * false if the optional was not in state `from`, in which case the state is not changed.
+    ///
+    /// If the optional is currently reserved for a mutation, the method waits until the mutation completes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * either of `from` or `to` is `RESERVED` or not a valid state,
+    /// * neither `from` nor `to` is a custom state,
+    /// * `from` and `to` disagree on whether or not the optional holds a value;
+    ///   for instance, transitioning from `SOME` to a custom state which does not hold a value would lose the value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// #[derive(Clone, Copy)]
+    /// enum SlotState {
+    ///     Dirty,
+    ///     Claimed,
+    /// }
+    ///
+    /// impl CustomState for SlotState {
+    ///     fn id(self) -> u8 {
+    ///         self as u8
+    ///     }
+    ///
+    ///     fn holds_value(self) -> bool {
+    ///         match self {
+    ///             Self::Dirty => true,
+    ///             Self::Claimed => false,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let x = ConcurrentOption::<String>::none();
+    ///
+    /// assert!(x.transition_custom(NONE, SlotState::Claimed.state()));
+    /// assert!(!x.initialize_if_none(3.to_string())); // claimed slots are unavailable
+    /// assert!(x.transition_custom(SlotState::Claimed.state(), NONE));
+    ///
+    /// assert!(x.initialize_if_none(3.to_string()));
+    /// assert!(x.transition_custom(SOME, SlotState::Dirty.state()));
+    /// assert!(x.is_none());
+    /// assert!(x.transition_custom(SlotState::Dirty.state(), SOME));
+    /// assert!(x.is_some());
+    /// ```
+    #[allow(clippy::panic)]
+    pub fn transition_custom(&self, from: StateU8, to: StateU8) -> bool {
+        let is_valid = |s: StateU8| s == NONE || s == SOME || is_custom(s);
+        if !(is_valid(from) && is_valid(to)) {
+            panic!("transition_custom requires valid non-reserved states");
+        }
+        if !(is_custom(from) || is_custom(to)) {
+            panic!("transition_custom requires at least one of the states to be a custom state");
+        }
+        if holds_value(from) != holds_value(to) {
+            panic!("transition_custom cannot change whether or not the optional holds a value");
+        }
+
+        loop {
+            match self
+                .state
+                .compare_exchange(from, to, Ordering::SeqCst, ORDER_LOAD)
+            {
+                Ok(_) => {
+                    if from == SOME {
+                        self.generation.wait_for_readers();
+                    }
+                    self.generation.bump();
+                    notify(&self.state);
+                    self.generation.notify_transition(from, to);
+                    return true;
+                }
+                Err(previous_state) => match previous_state {
+                    RESERVED => snooze(&self.state, RESERVED),
+                    _ => return false,
+                },
+            }
+        }
+    }
+
+    /// Panics if the option is in a custom state; used by mutating methods which cannot report a failure.
+    #[allow(clippy::panic)]
+    pub(crate) fn assert_not_custom(&self) {
+        if is_custom(self.state.load(ORDER_LOAD)) {
+            panic!("ConcurrentOption in a custom state cannot be mutated by built-in methods; use transition_custom first");
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    handle::Handle, states::*, stats::ExitReason, ConcurrentOption, Contended, ReplaceError,
+};
+use core::mem::MaybeUninit;
+use std::time::Instant;
+
+impl<T> ConcurrentOption<T> {
+    fn spin_get_write_handle_until(
+        &self,
+        initial_state: StateU8,
+        success_state: StateU8,
+        deadline: Instant,
+    ) -> Result<Option<Handle<'_>>, Contended> {
+        Handle::spin_get_while(
+            &self.state,
+            initial_state,
+            success_state,
+            Some(&self.generation),
+            || Instant::now() < deadline,
+        )
+    }
+
+    /// Deadline bounded version of [`update_if_some`].
+    ///
+    /// Thread safe method to update the value of the option if it is of Some variant.
+    /// Does nothing if it is None.
+    /// Returns whether or not the value is updated.
+    ///
+    /// Returns a [`Contended`] error if the option remains reserved by another thread until the `deadline`.
+    ///
+    /// [`update_if_some`]: ConcurrentOption::update_if_some
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let deadline = Instant::now() + Duration::from_millis(10);
+    ///
+    /// let x = ConcurrentOption::some(3);
+    /// assert_eq!(x.update_if_some_deadline(|x| *x += 1, deadline), Ok(true));
+    /// assert_eq!(x.map(|x| *x), Some(4));
+    ///
+    /// let x = ConcurrentOption::<i32>::none();
+    /// assert_eq!(x.update_if_some_deadline(|x| *x += 1, deadline), Ok(false));
+    /// ```
+    pub fn update_if_some_deadline<F>(&self, f: F, deadline: Instant) -> Result<bool, Contended>
+    where
+        F: FnOnce(&mut T),
+    {
+        match self.spin_get_write_handle_until(SOME, SOME, deadline)? {
+            Some(_handle) => {
+                let x = unsafe { MaybeUninit::assume_init_mut(&mut *self.value.get()) };
+                f(x);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Deadline bounded version of [`take`].
+    ///
+    /// Thread safe method to take the value out of the option if Some, leaving a None in its place.
+    /// Has no impact and returns None, if the option is of None variant.
+    ///
+    /// Returns a [`Contended`] error if the option remains reserved by another thread until the `deadline`.
+    ///
+    /// Since the deadline is an absolute point in time, a single deadline can be shared by a retry loop
+    /// coordinating several options, without accumulating per-call timeouts.
+    ///
+    /// [`take`]: ConcurrentOption::take
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let slots = [ConcurrentOption::some(1), ConcurrentOption::none(), ConcurrentOption::some(3)];
+    ///
+    /// let deadline = Instant::now() + Duration::from_millis(10);
+    /// let taken: Result<Vec<_>, _> = slots.iter().map(|x| x.take_deadline(deadline)).collect();
+    /// assert_eq!(taken, Ok(vec![Some(1), None, Some(3)]));
+    ///
+    /// let x = ConcurrentOption::some(42);
+    /// let handle = unsafe { x.mut_handle(SOME, SOME) }; // reserved by a stalled writer
+    /// assert_eq!(x.take_deadline(Instant::now()), Err(Contended(())));
+    ///
+    /// drop(handle);
+    /// assert_eq!(x.take_deadline(Instant::now()), Ok(Some(42)));
+    /// ```
+    pub fn take_deadline(&self, deadline: Instant) -> Result<Option<T>, Contended> {
+        match self.spin_get_write_handle_until(SOME, NONE, deadline)? {
+            Some(_handle) => {
+                self.record_exit(ExitReason::Taken);
+                let x = unsafe { &*self.value.get() };
+                Ok(Some(unsafe { MaybeUninit::assume_init_read(x) }))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Deadline bounded version of [`replace`].
+    ///
+    /// Thread safe method to replace the value in the option by the given `value`,
+    /// returning the old value if present, leaving a Some in its place.
+    ///
+    /// Returns the `value` back in a [`ReplaceError`]:
+    /// * [`ReplaceError::Contended`] if the option remains reserved by another thread until the `deadline`;
+    /// * [`ReplaceError::Frozen`] if the option is frozen by [`freeze`].
+    ///
+    /// [`replace`]: ConcurrentOption::replace
+    /// [`ReplaceError`]: crate::ReplaceError
+    /// [`ReplaceError::Contended`]: crate::ReplaceError::Contended
+    /// [`ReplaceError::Frozen`]: crate::ReplaceError::Frozen
+    /// [`freeze`]: ConcurrentOption::freeze
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is in a custom state set by [`transition_custom`].
+    ///
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let deadline = Instant::now() + Duration::from_millis(10);
+    ///
+    /// let x = ConcurrentOption::none();
+    /// assert_eq!(x.replace_deadline(3, deadline), Ok(None));
+    /// assert_eq!(x.replace_deadline(7, deadline), Ok(Some(3)));
+    ///
+    /// let handle = unsafe { x.mut_handle(SOME, SOME) }; // reserved by a stalled writer
+    /// assert_eq!(x.replace_deadline(8, Instant::now()), Err(ReplaceError::Contended(8)));
+    /// drop(handle);
+    ///
+    /// assert_eq!(x.map(|x| *x), Some(7));
+    /// ```
+    pub fn replace_deadline(
+        &self,
+        value: T,
+        deadline: Instant,
+    ) -> Result<Option<T>, ReplaceError<T>> {
+        loop {
+            match self.spin_get_write_handle_until(SOME, SOME, deadline) {
+                Ok(Some(_handle)) => {
+                    let x = unsafe { (*self.value.get()).assume_init_mut() };
+                    let old = core::mem::replace(x, value);
+                    self.record_exit(ExitReason::Replaced);
+                    return Ok(Some(old));
+                }
+                Ok(None) => {}
+                Err(_) => return Err(ReplaceError::Contended(value)),
+            }
+
+            match self.spin_get_write_handle_until(NONE, SOME, deadline) {
+                Ok(Some(_handle)) => {
+                    let x = unsafe { &mut *self.value.get() };
+                    x.write(value);
+                    return Ok(None);
+                }
+                Ok(None) => {}
+                Err(_) => return Err(ReplaceError::Contended(value)),
+            }
+
+            if self.is_frozen() {
+                return Err(ReplaceError::Frozen(value));
+            }
+            self.assert_not_custom();
+        }
+    }
+}
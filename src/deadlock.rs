@@ -0,0 +1,143 @@
+//! Instrumentation detecting threads which spin on a reserved [`ConcurrentOption`] for suspiciously long,
+//! available with the `deadlock-detection` feature.
+//!
+//! Whenever a thread keeps spinning on a reserved option for longer than the [`stuck_threshold`],
+//! the stuck hook is called once for that wait, with a [`StuckSpin`] report.
+//! The default hook prints the thread and the backtrace to the standard error;
+//! it can be replaced by [`set_stuck_hook`].
+//!
+//! [`ConcurrentOption`]: crate::ConcurrentOption
+
+use std::{
+    backtrace::Backtrace,
+    boxed::Box,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        RwLock,
+    },
+    thread::Thread,
+    time::{Duration, Instant},
+};
+
+/// Default threshold on the duration of spinning on a reserved option before the stuck hook is called.
+pub const DEFAULT_STUCK_THRESHOLD: Duration = Duration::from_secs(1);
+
+/// Number of spins between two consecutive checks of the elapsed time.
+const CHECK_INTERVAL: u32 = 1024;
+
+static STUCK_THRESHOLD_NANOS: AtomicU64 = AtomicU64::new(DEFAULT_STUCK_THRESHOLD.as_nanos() as u64);
+
+type StuckHook = Box<dyn Fn(&StuckSpin) + Send + Sync>;
+
+static STUCK_HOOK: RwLock<Option<StuckHook>> = RwLock::new(None);
+
+/// Report of a thread which has been spinning on a reserved option for longer than the [`stuck_threshold`].
+#[derive(Debug)]
+pub struct StuckSpin {
+    /// The spinning thread.
+    pub thread: Thread,
+    /// Duration the thread has been spinning for when the report is created.
+    pub waited: Duration,
+    /// Backtrace of the spinning thread; captured according to the `RUST_BACKTRACE` and `RUST_LIB_BACKTRACE`
+    /// environment variables.
+    pub backtrace: Backtrace,
+}
+
+/// Sets the threshold on the duration of spinning on a reserved option before the stuck hook is called.
+///
+/// The threshold is [`DEFAULT_STUCK_THRESHOLD`] unless it is set.
+pub fn set_stuck_threshold(threshold: Duration) {
+    let nanos = u64::try_from(threshold.as_nanos()).unwrap_or(u64::MAX);
+    STUCK_THRESHOLD_NANOS.store(nanos, Ordering::Relaxed);
+}
+
+/// Returns the threshold on the duration of spinning on a reserved option before the stuck hook is called.
+pub fn stuck_threshold() -> Duration {
+    Duration::from_nanos(STUCK_THRESHOLD_NANOS.load(Ordering::Relaxed))
+}
+
+/// Sets the `hook` which is called once per wait of a thread which has been spinning on a reserved option
+/// for longer than the [`stuck_threshold`].
+///
+/// The hook is called on the spinning thread, which resumes spinning once the hook returns.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::deadlock::*;
+///
+/// set_stuck_hook(|stuck| {
+///     eprintln!("{:?} is stuck for {:?}", stuck.thread.id(), stuck.waited);
+/// });
+///
+/// reset_stuck_hook();
+/// ```
+pub fn set_stuck_hook<F>(hook: F)
+where
+    F: Fn(&StuckSpin) + Send + Sync + 'static,
+{
+    let mut current = STUCK_HOOK.write().unwrap_or_else(|e| e.into_inner());
+    *current = Some(Box::new(hook));
+}
+
+/// Restores the default stuck hook which prints the thread and the backtrace to the standard error.
+pub fn reset_stuck_hook() {
+    let mut current = STUCK_HOOK.write().unwrap_or_else(|e| e.into_inner());
+    *current = None;
+}
+
+fn default_stuck_hook(stuck: &StuckSpin) {
+    std::eprintln!(
+        "thread {:?} ({:?}) has been spinning on a reserved ConcurrentOption for {:?}\n{}",
+        stuck.thread.name().unwrap_or("<unnamed>"),
+        stuck.thread.id(),
+        stuck.waited,
+        stuck.backtrace
+    );
+}
+
+/// Tracks a single wait of a thread spinning on a reserved option.
+pub(crate) struct SpinWatch {
+    start: Option<Instant>,
+    num_spins: u32,
+    reported: bool,
+}
+
+impl SpinWatch {
+    pub fn new() -> Self {
+        Self {
+            start: None,
+            num_spins: 0,
+            reported: false,
+        }
+    }
+
+    #[inline(always)]
+    pub fn on_spin(&mut self) {
+        if self.reported {
+            return;
+        }
+
+        let start = *self.start.get_or_insert_with(Instant::now);
+        self.num_spins = self.num_spins.wrapping_add(1);
+        if self.num_spins.is_multiple_of(CHECK_INTERVAL) {
+            let waited = start.elapsed();
+            if waited >= stuck_threshold() {
+                self.reported = true;
+                let stuck = StuckSpin {
+                    thread: std::thread::current(),
+                    waited,
+                    backtrace: Backtrace::capture(),
+                };
+                match STUCK_HOOK
+                    .read()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .as_ref()
+                {
+                    Some(hook) => hook(&stuck),
+                    None => default_stuck_hook(&stuck),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Rendering of the state machine of [`ConcurrentOption`] as a Graphviz dot graph.
+//!
+//! The graph is generated from the same rules the option uses to validate its transitions;
+//! hence, protocols extending the state machine with [`CustomState`]s can generate accurate diagrams
+//! rather than maintaining them by hand.
+//!
+//! Available with the `dot` feature.
+//!
+//! [`ConcurrentOption`]: crate::ConcurrentOption
+//! [`CustomState`]: crate::CustomState
+
+use crate::{
+    custom_state::{holds_value, is_custom},
+    states::*,
+};
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Built-in transitions of the state machine as (from, to, label) triplets.
+const BUILT_IN_TRANSITIONS: [(StateU8, StateU8, &str); 5] = [
+    (NONE, RESERVED, "reserve"),
+    (SOME, RESERVED, "reserve"),
+    (RESERVED, SOME, "release as some"),
+    (RESERVED, NONE, "release as none"),
+    (SOME, FROZEN, "freeze"),
+];
+
+/// Renders the built-in state machine of the option, consisting of the NONE, RESERVED, SOME and FROZEN states, as a dot graph.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// let dot = dot::transitions_as_dot();
+/// assert!(dot.starts_with("digraph ConcurrentOption {"));
+/// assert!(dot.contains("NONE -> RESERVED [label=\"reserve\"];"));
+/// assert!(dot.contains("RESERVED -> SOME [label=\"release as some\"];"));
+/// assert!(dot.contains("SOME -> FROZEN [label=\"freeze\"];"));
+/// ```
+pub fn transitions_as_dot() -> String {
+    export_dot(&[])
+}
+
+/// Renders the state machine of the option extended by the named `custom_states` as a dot graph.
+///
+/// In addition to the built-in transitions, an edge is drawn between every pair of states,
+/// at least one of which is custom, that can be transitioned by [`ConcurrentOption::transition_custom`];
+/// i.e., states which agree on whether or not the option holds a value.
+///
+/// States holding a value are drawn with a double border.
+///
+/// [`ConcurrentOption::transition_custom`]: crate::ConcurrentOption::transition_custom
+///
+/// # Panics
+///
+/// Panics if any of the `custom_states` is not a custom state created by [`CustomState::state`].
+///
+/// [`CustomState::state`]: crate::CustomState::state
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// #[derive(Clone, Copy)]
+/// enum SlotState {
+///     Dirty,
+///     Claimed,
+/// }
+///
+/// impl CustomState for SlotState {
+///     fn id(self) -> u8 {
+///         self as u8
+///     }
+///
+///     fn holds_value(self) -> bool {
+///         matches!(self, Self::Dirty)
+///     }
+/// }
+///
+/// let dot = dot::export_dot(&[
+///     ("DIRTY", SlotState::Dirty.state()),
+///     ("CLAIMED", SlotState::Claimed.state()),
+/// ]);
+///
+/// assert!(dot.contains("SOME -> DIRTY;"));
+/// assert!(dot.contains("CLAIMED -> NONE;"));
+/// assert!(!dot.contains("DIRTY -> CLAIMED;"));
+/// ```
+#[allow(clippy::panic)]
+pub fn export_dot(custom_states: &[(&str, StateU8)]) -> String {
+    if custom_states.iter().any(|(_, s)| !is_custom(*s)) {
+        panic!("export_dot requires custom states created by CustomState::state");
+    }
+
+    let built_in = [
+        ("NONE", NONE),
+        ("RESERVED", RESERVED),
+        ("SOME", SOME),
+        ("FROZEN", FROZEN),
+    ];
+    let name = |state: StateU8| {
+        built_in
+            .iter()
+            .chain(custom_states)
+            .find(|(_, s)| *s == state)
+            .map(|(name, _)| *name)
+            .unwrap_or_default()
+    };
+
+    let mut dot = String::from("digraph ConcurrentOption {\n");
+    let mut push = |line: core::fmt::Arguments| {
+        _ = dot.write_fmt(line);
+        dot.push('\n');
+    };
+
+    for (name, state) in built_in.iter().chain(custom_states) {
+        let peripheries = match holds_value(*state) {
+            true => 2,
+            false => 1,
+        };
+        push(format_args!("    {} [peripheries={}];", name, peripheries));
+    }
+
+    for (from, to, label) in BUILT_IN_TRANSITIONS {
+        push(format_args!(
+            "    {} -> {} [label=\"{}\"];",
+            name(from),
+            name(to),
+            label
+        ));
+    }
+
+    let transitionable = [NONE, SOME]
+        .into_iter()
+        .chain(custom_states.iter().map(|(_, s)| *s));
+    for from in transitionable.clone() {
+        for to in transitionable.clone() {
+            let is_custom_transition = from != to && (is_custom(from) || is_custom(to));
+            if is_custom_transition && holds_value(from) == holds_value(to) {
+                push(format_args!("    {} -> {};", name(from), name(to)));
+            }
+        }
+    }
+
+    dot.push('}');
+    dot.push('\n');
+    dot
+}
@@ -1,14 +1,10 @@
-use crate::{
-    concurrent_option::ConcurrentOption,
-    states::{RESERVED, SOME},
-};
-use core::sync::atomic::Ordering;
+use crate::{concurrent_option::ConcurrentOption, custom_state::holds_value, states::RESERVED};
 
 impl<T> Drop for ConcurrentOption<T> {
     #[allow(clippy::panic)]
     fn drop(&mut self) {
-        match self.state.load(Ordering::Relaxed) {
-            SOME => {
+        match *self.state.get_mut() {
+            x if holds_value(x) => {
                 let x = unsafe { &mut *self.value.get() };
                 unsafe { x.assume_init_drop() };
             }
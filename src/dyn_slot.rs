@@ -0,0 +1,58 @@
+use crate::{ConcurrentOption, LoadOrdering, State};
+
+/// Object safe, type-erased view of a [`ConcurrentOption`], implemented for all `ConcurrentOption<T>`.
+///
+/// Options of different value types can be collected as `&dyn DynSlot` references;
+/// for instance, a registry can monitor the slots of all subsystems or clear them during a shutdown sequence.
+///
+/// All methods are thread safe.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// let connection = ConcurrentOption::some(String::from("localhost:8080"));
+/// let retries = ConcurrentOption::some(3u32);
+/// let cache = ConcurrentOption::<Vec<u8>>::none();
+///
+/// let registry: [&(dyn DynSlot + Sync); 3] = [&connection, &retries, &cache];
+///
+/// let num_live = registry.iter().filter(|x| x.is_some()).count();
+/// assert_eq!(num_live, 2);
+///
+/// // shutdown
+/// for slot in registry {
+///     slot.clear();
+/// }
+/// assert!(registry.iter().all(|x| x.debug_state() == State::None));
+/// assert!(connection.is_none());
+/// ```
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+pub trait DynSlot {
+    /// Returns `true` if the option is a Some variant.
+    fn is_some(&self) -> bool;
+
+    /// Takes the value out of the option and drops it, leaving a None in its place.
+    ///
+    /// Returns whether or not there was a value to drop.
+    fn clear(&self) -> bool;
+
+    /// Returns the current state of the option loaded with acquire ordering.
+    fn debug_state(&self) -> State;
+}
+
+impl<T> DynSlot for ConcurrentOption<T> {
+    fn is_some(&self) -> bool {
+        ConcurrentOption::is_some(self)
+    }
+
+    fn clear(&self) -> bool {
+        self.take().is_some()
+    }
+
+    fn debug_state(&self) -> State {
+        self.state(LoadOrdering::Acquire)
+    }
+}
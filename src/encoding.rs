@@ -0,0 +1,262 @@
+//! Compact binary encoding of snapshots of [`ConcurrentOption`]s, which allows shipping the value over
+//! IPC or network without pulling a serialization framework for simple payloads.
+//!
+//! The payload is encoded by an [`Encoder`], such as the built-in [`LittleEndian`] encoder of primitive numbers.
+//!
+//! # Format
+//!
+//! The encoding of an option consists of a single header byte followed by the encoded payload, if any:
+//! * the upper four bits of the header is the [`FORMAT_VERSION`];
+//! * the lower four bits of the header is `0` for None and `1` for Some;
+//! * the payload is present only for the Some variant, and is exactly the bytes written by the encoder.
+//!
+//! Therefore, a None option of the current version is encoded as `[0x10]`,
+//! while `Some(7u16)` is encoded as `[0x11, 7, 0]` by the [`LittleEndian`] encoder.
+//!
+//! [`ConcurrentOption`]: crate::ConcurrentOption
+
+use crate::ConcurrentOption;
+use core::mem::MaybeUninit;
+
+/// Version of the encoding format, stored in the upper four bits of the header byte.
+pub const FORMAT_VERSION: u8 = 1;
+
+const VARIANT_NONE: u8 = 0;
+const VARIANT_SOME: u8 = 1;
+
+/// Encoder of the payload of a [`ConcurrentOption`].
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+pub trait Encoder<T> {
+    /// Returns the number of bytes that the `value` is encoded into.
+    fn encoded_len(&self, value: &T) -> usize;
+
+    /// Encodes the `value` into the `buffer` whose length is exactly [`encoded_len`] of the value.
+    ///
+    /// [`encoded_len`]: Encoder::encoded_len
+    fn encode(&self, value: &T, buffer: &mut [u8]);
+
+    /// Decodes the value from the `bytes` of the payload; returns None if the bytes are not a valid encoding.
+    fn decode(&self, bytes: &[u8]) -> Option<T>;
+}
+
+/// Encoder of primitive numbers in their little-endian byte representation.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+/// use orx_concurrent_option::encoding::LittleEndian;
+///
+/// let x = ConcurrentOption::some(0x0102u16);
+///
+/// let mut buffer = [0u8; 8];
+/// let len = x.encode_into(&LittleEndian, &mut buffer).unwrap();
+/// assert_eq!(&buffer[..len], &[0x11, 0x02, 0x01]);
+///
+/// let y = ConcurrentOption::<u16>::from_bytes(&LittleEndian, &buffer[..len]).unwrap();
+/// assert_eq!(y, x);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LittleEndian;
+
+macro_rules! little_endian {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Encoder<$t> for LittleEndian {
+                fn encoded_len(&self, _: &$t) -> usize {
+                    core::mem::size_of::<$t>()
+                }
+
+                fn encode(&self, value: &$t, buffer: &mut [u8]) {
+                    buffer.copy_from_slice(&value.to_le_bytes());
+                }
+
+                fn decode(&self, bytes: &[u8]) -> Option<$t> {
+                    bytes.try_into().ok().map(<$t>::from_le_bytes)
+                }
+            }
+        )*
+    };
+}
+
+little_endian!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Error of encoding or decoding an option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodingError {
+    /// The buffer is shorter than the `required` number of bytes.
+    BufferTooSmall {
+        /// Number of bytes required to encode the option.
+        required: usize,
+    },
+    /// The bytes to decode are empty.
+    Empty,
+    /// The header byte is of an unsupported format version.
+    UnsupportedVersion(u8),
+    /// The header byte has an invalid variant.
+    InvalidVariant(u8),
+    /// The payload could not be decoded by the encoder, or a None option has a payload.
+    InvalidPayload,
+}
+
+impl core::fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BufferTooSmall { required } => {
+                write!(f, "buffer is too small; {} bytes are required", required)
+            }
+            Self::Empty => write!(f, "encoded option is empty"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported encoding format version {}", version)
+            }
+            Self::InvalidVariant(variant) => write!(f, "invalid variant {}", variant),
+            Self::InvalidPayload => write!(f, "invalid payload"),
+        }
+    }
+}
+
+const fn header(variant: u8) -> u8 {
+    (FORMAT_VERSION << 4) | variant
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Calls `f` with a snapshot of the value, if any, while the value cannot be mutated.
+    fn with_snapshot<R>(&self, f: impl FnOnce(Option<&T>) -> R) -> R {
+        match self.spin_get_read_handle() {
+            Some(_handle) => f(Some(unsafe {
+                MaybeUninit::assume_init_ref(&*self.value.get())
+            })),
+            None => f(None),
+        }
+    }
+
+    /// Thread safe method to encode a snapshot of the option into the `buffer` by the `encoder`, without allocating;
+    /// returns the number of bytes written.
+    ///
+    /// See the [`encoding`] module for the format.
+    ///
+    /// [`encoding`]: crate::encoding
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodingError::BufferTooSmall`] if the `buffer` cannot hold the encoding,
+    /// in which case the buffer is not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use orx_concurrent_option::encoding::{EncodingError, LittleEndian};
+    ///
+    /// let mut buffer = [0u8; 4];
+    ///
+    /// let x = ConcurrentOption::<u32>::none();
+    /// assert_eq!(x.encode_into(&LittleEndian, &mut buffer), Ok(1));
+    /// assert_eq!(buffer[0], 0x10);
+    ///
+    /// let x = ConcurrentOption::some(42u32);
+    /// let result = x.encode_into(&LittleEndian, &mut buffer);
+    /// assert_eq!(result, Err(EncodingError::BufferTooSmall { required: 5 }));
+    /// ```
+    pub fn encode_into<E: Encoder<T>>(
+        &self,
+        encoder: &E,
+        buffer: &mut [u8],
+    ) -> Result<usize, EncodingError> {
+        self.with_snapshot(|value| {
+            let required = 1 + value.map(|x| encoder.encoded_len(x)).unwrap_or(0);
+            match buffer.get_mut(..required) {
+                None => Err(EncodingError::BufferTooSmall { required }),
+                Some(buffer) => {
+                    match value {
+                        Some(x) => {
+                            buffer[0] = header(VARIANT_SOME);
+                            encoder.encode(x, &mut buffer[1..]);
+                        }
+                        None => buffer[0] = header(VARIANT_NONE),
+                    }
+                    Ok(required)
+                }
+            }
+        })
+    }
+
+    /// Thread safe method to encode a snapshot of the option into a vector of bytes by the `encoder`.
+    ///
+    /// See the [`encoding`] module for the format.
+    ///
+    /// [`encoding`]: crate::encoding
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use orx_concurrent_option::encoding::LittleEndian;
+    ///
+    /// let x = ConcurrentOption::some(-1i16);
+    /// assert_eq!(x.to_bytes(&LittleEndian), vec![0x11, 0xff, 0xff]);
+    ///
+    /// _ = x.take();
+    /// assert_eq!(x.to_bytes(&LittleEndian), vec![0x10]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_bytes<E: Encoder<T>>(&self, encoder: &E) -> alloc::vec::Vec<u8> {
+        self.with_snapshot(|value| match value {
+            Some(x) => {
+                let mut bytes = alloc::vec![0; 1 + encoder.encoded_len(x)];
+                bytes[0] = header(VARIANT_SOME);
+                encoder.encode(x, &mut bytes[1..]);
+                bytes
+            }
+            None => alloc::vec![header(VARIANT_NONE)],
+        })
+    }
+
+    /// Decodes an option from the `bytes` created by [`encode_into`] or [`to_bytes`] with the same `encoder`.
+    ///
+    /// See the [`encoding`] module for the format.
+    ///
+    /// [`encode_into`]: ConcurrentOption::encode_into
+    /// [`to_bytes`]: ConcurrentOption::to_bytes
+    /// [`encoding`]: crate::encoding
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EncodingError`] describing why the `bytes` are not a valid encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use orx_concurrent_option::encoding::{EncodingError, LittleEndian};
+    ///
+    /// let x = ConcurrentOption::<u8>::from_bytes(&LittleEndian, &[0x11, 7]);
+    /// assert_eq!(x, Ok(ConcurrentOption::some(7)));
+    ///
+    /// let x = ConcurrentOption::<u8>::from_bytes(&LittleEndian, &[0x10]);
+    /// assert_eq!(x, Ok(ConcurrentOption::none()));
+    ///
+    /// let x = ConcurrentOption::<u8>::from_bytes(&LittleEndian, &[0x21, 7]);
+    /// assert_eq!(x, Err(EncodingError::UnsupportedVersion(2)));
+    /// ```
+    pub fn from_bytes<E: Encoder<T>>(encoder: &E, bytes: &[u8]) -> Result<Self, EncodingError> {
+        let (header, payload) = bytes.split_first().ok_or(EncodingError::Empty)?;
+        let version = header >> 4;
+        if version != FORMAT_VERSION {
+            return Err(EncodingError::UnsupportedVersion(version));
+        }
+
+        match header & 0x0f {
+            VARIANT_NONE => match payload.is_empty() {
+                true => Ok(Self::none()),
+                false => Err(EncodingError::InvalidPayload),
+            },
+            VARIANT_SOME => encoder
+                .decode(payload)
+                .map(Self::some)
+                .ok_or(EncodingError::InvalidPayload),
+            variant => Err(EncodingError::InvalidVariant(variant)),
+        }
+    }
+}
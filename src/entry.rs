@@ -0,0 +1,273 @@
+use crate::{handle::Handle, states::*, stats::ExitReason, ConcurrentOption, WriteGuard};
+use core::{
+    fmt::Debug,
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+};
+
+/// A view into a [`ConcurrentOption`] which is reserved either as occupied or vacant, created by [`ConcurrentOption::entry`].
+///
+/// The option is reserved while the entry is alive; hence, the variant observed by the entry cannot change
+/// until the entry is dropped. This allows expressing compound conditional logic, such as
+/// "mutate if Some, insert otherwise", atomically without racing between `is_some` and `insert` calls.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`ConcurrentOption::entry`]: crate::ConcurrentOption::entry
+pub enum Entry<'a, T> {
+    /// The option is of Some variant.
+    Occupied(OccupiedEntry<'a, T>),
+    /// The option is of None variant.
+    Vacant(VacantEntry<'a, T>),
+}
+
+impl<'a, T> Entry<'a, T> {
+    /// Inserts the `value` if the entry is vacant;
+    /// returns a [`WriteGuard`] to the value of the option in either case.
+    ///
+    /// [`WriteGuard`]: crate::WriteGuard
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::none();
+    /// *x.entry().or_insert(1) += 10;
+    /// *x.entry().or_insert(1) += 10;
+    ///
+    /// assert_eq!(x.take(), Some(21));
+    /// ```
+    pub fn or_insert(self, value: T) -> WriteGuard<'a, T> {
+        match self {
+            Self::Occupied(entry) => entry.into_guard(),
+            Self::Vacant(entry) => entry.insert(value),
+        }
+    }
+
+    /// Inserts the value computed by `f` if the entry is vacant;
+    /// returns a [`WriteGuard`] to the value of the option in either case.
+    ///
+    /// The function `f` is called only if the entry is vacant, while the option is reserved.
+    ///
+    /// [`WriteGuard`]: crate::WriteGuard
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(vec![1]);
+    /// x.entry().or_insert_with(|| unreachable!()).push(2);
+    /// assert_eq!(x.take(), Some(vec![1, 2]));
+    ///
+    /// x.entry().or_insert_with(Vec::new).push(3);
+    /// assert_eq!(x.take(), Some(vec![3]));
+    /// ```
+    pub fn or_insert_with<F>(self, f: F) -> WriteGuard<'a, T>
+    where
+        F: FnOnce() -> T,
+    {
+        match self {
+            Self::Occupied(entry) => entry.into_guard(),
+            Self::Vacant(entry) => entry.insert(f()),
+        }
+    }
+}
+
+impl<T: Debug> Debug for Entry<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Occupied(entry) => f.debug_tuple("Entry").field(entry).finish(),
+            Self::Vacant(entry) => f.debug_tuple("Entry").field(entry).finish(),
+        }
+    }
+}
+
+/// An occupied entry of a [`ConcurrentOption`] of Some variant, providing exclusive mutable access to its value.
+///
+/// The option is released back as Some when the entry is dropped, unless the value is removed by [`OccupiedEntry::remove`].
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+pub struct OccupiedEntry<'a, T> {
+    handle: Handle<'a>,
+    option: &'a ConcurrentOption<T>,
+}
+
+impl<'a, T> OccupiedEntry<'a, T> {
+    /// Takes the value out of the option, releasing it as None variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    ///
+    /// if let Entry::Occupied(entry) = x.entry() {
+    ///     if *entry > 2 {
+    ///         assert_eq!(entry.remove(), 3);
+    ///     }
+    /// }
+    ///
+    /// assert!(x.is_none());
+    /// ```
+    pub fn remove(mut self) -> T {
+        self.option.record_exit(ExitReason::Taken);
+        self.handle.set_success_state(NONE);
+        let x = unsafe { &*self.option.value.get() };
+        unsafe { MaybeUninit::assume_init_read(x) }
+    }
+
+    /// Replaces the value of the option with the given `value`, returning the old value;
+    /// the option remains of Some variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    ///
+    /// if let Entry::Occupied(mut entry) = x.entry() {
+    ///     assert_eq!(entry.replace(7), 3);
+    ///     assert_eq!(*entry, 7);
+    /// }
+    ///
+    /// assert_eq!(x.take(), Some(7));
+    /// ```
+    pub fn replace(&mut self, value: T) -> T {
+        self.option.record_exit(ExitReason::Replaced);
+        core::mem::replace(&mut **self, value)
+    }
+
+    /// Turns the entry into a [`WriteGuard`] to the value, keeping the reservation.
+    ///
+    /// [`WriteGuard`]: crate::WriteGuard
+    pub fn into_guard(self) -> WriteGuard<'a, T> {
+        let Self { handle, option } = self;
+        WriteGuard { handle, option }
+    }
+}
+
+impl<T> Deref for OccupiedEntry<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { MaybeUninit::assume_init_ref(&*self.option.value.get()) }
+    }
+}
+
+impl<T> DerefMut for OccupiedEntry<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { MaybeUninit::assume_init_mut(&mut *self.option.value.get()) }
+    }
+}
+
+impl<T: Debug> Debug for OccupiedEntry<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("OccupiedEntry").field(&**self).finish()
+    }
+}
+
+/// A vacant entry of a [`ConcurrentOption`] of None variant.
+///
+/// The option is released back as None when the entry is dropped without inserting a value.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+pub struct VacantEntry<'a, T> {
+    handle: Handle<'a>,
+    option: &'a ConcurrentOption<T>,
+}
+
+impl<'a, T> VacantEntry<'a, T> {
+    /// Writes the `value` into the option and returns a [`WriteGuard`] to it, keeping the reservation;
+    /// the option is released as Some variant once the guard is dropped.
+    ///
+    /// [`WriteGuard`]: crate::WriteGuard
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::none();
+    ///
+    /// if let Entry::Vacant(entry) = x.entry() {
+    ///     let mut guard = entry.insert(vec![1]);
+    ///     guard.push(2);
+    /// }
+    ///
+    /// assert_eq!(x.take(), Some(vec![1, 2]));
+    /// ```
+    pub fn insert(self, value: T) -> WriteGuard<'a, T> {
+        let Self { mut handle, option } = self;
+        unsafe { &mut *option.value.get() }.write(value);
+        handle.set_success_state(SOME);
+        WriteGuard { handle, option }
+    }
+}
+
+impl<T> Debug for VacantEntry<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("VacantEntry")
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Thread safe method to reserve the option and obtain an [`Entry`] which is either
+    /// [`Entry::Occupied`] if the option is of Some variant, or [`Entry::Vacant`] otherwise.
+    ///
+    /// The option is reserved while the entry, or the guard obtained from it, is alive;
+    /// hence, the variant and value cannot be concurrently changed in between.
+    ///
+    /// Note that other threads attempting to read or write the option wait until the entry is dropped.
+    /// Therefore, the entry is meant to be short-lived.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen by [`freeze`], or if it is in a custom state set by [`transition_custom`].
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    ///
+    /// match x.entry() {
+    ///     Entry::Occupied(entry) if *entry % 2 == 1 => _ = entry.remove(),
+    ///     Entry::Occupied(mut entry) => *entry += 1,
+    ///     Entry::Vacant(entry) => _ = entry.insert(0),
+    /// }
+    /// assert!(x.is_none());
+    ///
+    /// match x.entry() {
+    ///     Entry::Occupied(entry) => _ = entry.remove(),
+    ///     Entry::Vacant(entry) => _ = entry.insert(0),
+    /// }
+    /// assert_eq!(x.take(), Some(0));
+    /// ```
+    pub fn entry(&self) -> Entry<'_, T> {
+        loop {
+            if let Some(handle) = self.spin_get_write_handle(SOME, SOME) {
+                return Entry::Occupied(OccupiedEntry {
+                    handle,
+                    option: self,
+                });
+            }
+
+            if let Some(handle) = self.spin_get_write_handle(NONE, NONE) {
+                return Entry::Vacant(VacantEntry {
+                    handle,
+                    option: self,
+                });
+            }
+
+            self.assert_not_frozen();
+            self.assert_not_custom();
+        }
+    }
+}
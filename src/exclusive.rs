@@ -1,11 +1,68 @@
-use crate::{states::*, ConcurrentOption};
+use crate::{states::*, stats::ExitReason, ConcurrentOption};
 use core::{
     mem::MaybeUninit,
     ops::{Deref, DerefMut},
-    sync::atomic::Ordering,
+    pin::Pin,
 };
 
 impl<T> ConcurrentOption<T> {
+    /// Returns the state of the option.
+    ///
+    /// Since the option is exclusively borrowed, the state is read by a plain load without any atomic instruction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut x = ConcurrentOption::some(3);
+    /// assert_eq!(x.exclusive_state(), State::Some);
+    ///
+    /// _ = x.exclusive_take();
+    /// assert_eq!(x.exclusive_state(), State::None);
+    /// ```
+    pub fn exclusive_state(&mut self) -> State {
+        State::new(*self.state.get_mut())
+    }
+
+    /// Returns `true` if the option is a Some variant.
+    ///
+    /// Since the option is exclusively borrowed, the state is read by a plain load without any atomic instruction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut options = [ConcurrentOption::some(1), ConcurrentOption::none(), ConcurrentOption::some(3)];
+    /// let num_some = options.iter_mut().map(|x| x.exclusive_is_some()).filter(|x| *x).count();
+    /// assert_eq!(num_some, 2);
+    /// ```
+    #[inline]
+    pub fn exclusive_is_some(&mut self) -> bool {
+        matches!(*self.state.get_mut(), SOME | FROZEN)
+    }
+
+    /// Returns `true` if the option is a None variant.
+    ///
+    /// Since the option is exclusively borrowed, the state is read by a plain load without any atomic instruction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut x = ConcurrentOption::<u32>::none();
+    /// assert!(x.exclusive_is_none());
+    ///
+    /// x.exclusive_insert(3);
+    /// assert!(!x.exclusive_is_none());
+    /// ```
+    #[inline]
+    pub fn exclusive_is_none(&mut self) -> bool {
+        !self.exclusive_is_some()
+    }
+
     /// Converts from `Option<T>` (or `&mut Option<T>`) to `Option<&mut T::Target>`.
     ///
     /// Leaves the original `Option` in-place, creating a new one containing a mutable reference to
@@ -41,15 +98,74 @@ impl<T> ConcurrentOption<T> {
     ///     Some(v) => *v = 42,
     ///     None => {},
     /// }
-    /// assert_eq!(unsafe { x.as_ref() }, Some(&42));
+    /// assert_eq!(x.with_ref(|x| *x), Some(42));
     /// ```
     pub fn exclusive_as_mut(&mut self) -> Option<&mut T> {
-        match self.state.load(Ordering::Relaxed) {
-            SOME => Some(unsafe { (*self.value.get()).assume_init_mut() }),
+        match *self.state.get_mut() {
+            SOME | FROZEN => {
+                self.exclusive_bump_generation();
+                Some(unsafe { (*self.value.get()).assume_init_mut() })
+            }
             _ => None,
         }
     }
 
+    /// Returns a mutable slice of the contained value, if any.
+    /// If this is None, an empty slice is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut x = ConcurrentOption::some(1234);
+    /// x.exclusive_as_mut_slice()[0] += 1;
+    /// assert_eq!(x, ConcurrentOption::some(1235));
+    ///
+    /// let mut x = ConcurrentOption::<i32>::none();
+    /// assert!(x.exclusive_as_mut_slice().is_empty());
+    /// ```
+    pub fn exclusive_as_mut_slice(&mut self) -> &mut [T] {
+        match self.exclusive_as_mut() {
+            Some(x) => core::slice::from_mut(x),
+            None => &mut [],
+        }
+    }
+
+    /// Converts from `Pin<&mut ConcurrentOption<T>>` to `Option<Pin<&mut T>>`.
+    ///
+    /// # Safety
+    ///
+    /// A pinned option still provides the thread safe methods requiring only `&self`, such as `take`, `replace`
+    /// or `set_none`, which move the value out of the option or drop it in place of a new one.
+    /// Therefore, the option cannot guarantee structural pinning of its payload by itself.
+    ///
+    /// The caller must guarantee that, once this method is called, the value of the option is never moved
+    /// until it is dropped; in particular, none of the methods moving the value out through `&self` is called on
+    /// the option while it holds the pinned value. This is trivially satisfied if `T: Unpin`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use core::pin::pin;
+    ///
+    /// let mut x = pin!(ConcurrentOption::some(42));
+    /// if let Some(mut v) = unsafe { x.as_mut().exclusive_as_pin_mut() } {
+    ///     v.set(7);
+    /// }
+    /// assert_eq!(unsafe { x.exclusive_as_pin_mut() }.map(|x| *x), Some(7));
+    ///
+    /// let x = pin!(ConcurrentOption::<u32>::none());
+    /// assert!(unsafe { x.exclusive_as_pin_mut() }.is_none());
+    /// ```
+    pub unsafe fn exclusive_as_pin_mut(self: Pin<&mut Self>) -> Option<Pin<&mut T>> {
+        // SAFETY: the caller guarantees that the value is never moved out of the pinned option.
+        let this = unsafe { self.get_unchecked_mut() };
+        this.exclusive_as_mut()
+            .map(|x| unsafe { Pin::new_unchecked(x) })
+    }
+
     /// Takes the value out of the option, leaving a None in its place.
     ///
     /// # Examples
@@ -68,9 +184,11 @@ impl<T> ConcurrentOption<T> {
     /// assert_eq!(y, None);
     /// ```
     pub fn exclusive_take(&mut self) -> Option<T> {
-        match self.state.load(Ordering::Relaxed) {
-            SOME => {
-                self.state.store(NONE, Ordering::Relaxed);
+        match *self.state.get_mut() {
+            SOME | FROZEN => {
+                self.exclusive_bump_generation();
+                *self.state.get_mut() = NONE;
+                self.record_exit(ExitReason::Taken);
                 let x = unsafe { &mut *self.value.get() };
                 Some(unsafe { x.assume_init_read() })
             }
@@ -78,6 +196,122 @@ impl<T> ConcurrentOption<T> {
         }
     }
 
+    /// Converts the option in place into a plain `Option<T>`, leaving a None in its place.
+    ///
+    /// Since the option is exclusively borrowed, the conversion requires no atomic operations beyond plain stores;
+    /// hence, bulk conversions, such as turning a `Vec<ConcurrentOption<T>>` into a `Vec<Option<T>>` at phase boundaries,
+    /// are a cheap loop.
+    ///
+    /// This is equivalent to [`exclusive_take`].
+    ///
+    /// [`exclusive_take`]: ConcurrentOption::exclusive_take
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut slots = vec![ConcurrentOption::some(1), ConcurrentOption::none()];
+    /// let options: Vec<Option<i32>> = slots.iter_mut().map(|x| x.exclusive_into_option()).collect();
+    /// assert_eq!(options, [Some(1), None]);
+    /// assert!(slots.iter().all(|x| x.is_none()));
+    /// ```
+    #[inline(always)]
+    pub fn exclusive_into_option(&mut self) -> Option<T> {
+        self.exclusive_take()
+    }
+
+    /// Sets the option in place from the plain `Option<T>`; i.e., the option becomes
+    /// * Some(x) if `value` is Some(x),
+    /// * None if `value` is None.
+    ///
+    /// If the option already contains a value, the old value is dropped.
+    ///
+    /// Since the option is exclusively borrowed, the conversion requires no atomic operations beyond plain stores.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut x = ConcurrentOption::some(1);
+    ///
+    /// x.exclusive_from_option(Some(2));
+    /// assert_eq!(x, ConcurrentOption::some(2));
+    ///
+    /// x.exclusive_from_option(None);
+    /// assert_eq!(x, ConcurrentOption::none());
+    ///
+    /// x.exclusive_from_option(Some(3));
+    /// assert_eq!(x, ConcurrentOption::some(3));
+    /// ```
+    pub fn exclusive_from_option(&mut self, value: Option<T>) {
+        match value {
+            Some(value) => _ = self.exclusive_insert(value),
+            None => _ = self.exclusive_take(),
+        }
+    }
+
+    /// Resets the option in place to the plain `Option<T>` regardless of its current state; i.e., the option becomes
+    /// * Some(x) if `value` is Some(x),
+    /// * None if `value` is None.
+    ///
+    /// Any value held by the option is dropped, including the value held in a custom state.
+    ///
+    /// Since the option is exclusively borrowed, the reset requires no atomic operations beyond plain stores.
+    /// This is useful for pools or arenas where the options are recycled wholesale between concurrent phases.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is reserved; i.e., if a handle of the option has been leaked while its value is being written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut pool: Vec<_> = (0..4).map(|i| ConcurrentOption::some(i.to_string())).collect();
+    ///
+    /// // concurrent phase
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| _ = pool[1].take());
+    ///     s.spawn(|| _ = pool[2].replace(42.to_string()));
+    /// });
+    ///
+    /// // recycle all options between the phases
+    /// for (i, x) in pool.iter_mut().enumerate() {
+    ///     x.exclusive_reset_to((i % 2 == 0).then(String::new));
+    /// }
+    ///
+    /// assert_eq!(pool[0].get_cloned(), Some(String::new()));
+    /// assert_eq!(pool[1].get_cloned(), None);
+    /// assert_eq!(pool[2].get_cloned(), Some(String::new()));
+    /// assert_eq!(pool[3].get_cloned(), None);
+    /// ```
+    #[allow(clippy::panic)]
+    pub fn exclusive_reset_to(&mut self, value: Option<T>) {
+        match *self.state.get_mut() {
+            RESERVED => panic!("ConcurrentOption is reset while its value is being written."),
+            x if crate::custom_state::holds_value(x) => {
+                self.record_drop(match value {
+                    Some(_) => ExitReason::Replaced,
+                    None => ExitReason::Cleared,
+                });
+                unsafe { self.value.get_mut().assume_init_drop() };
+            }
+            _ => {}
+        }
+
+        self.exclusive_bump_generation();
+        *self.state.get_mut() = match value {
+            Some(value) => {
+                self.value.get_mut().write(value);
+                SOME
+            }
+            None => NONE,
+        };
+    }
+
     /// Takes the value out of the option, but only if the predicate evaluates to
     /// `true` on a mutable reference to the value.
     ///
@@ -108,7 +342,7 @@ impl<T> ConcurrentOption<T> {
     where
         P: FnOnce(&mut T) -> bool,
     {
-        match self.exclusive_as_mut().map_or(false, predicate) {
+        match self.exclusive_as_mut().is_some_and(predicate) {
             true => self.exclusive_take(),
             false => None,
         }
@@ -159,18 +393,21 @@ impl<T> ConcurrentOption<T> {
     /// ```
     #[allow(clippy::panic, clippy::missing_panics_doc)]
     pub fn exclusive_replace(&mut self, value: T) -> Option<T> {
-        match self.state.load(Ordering::Relaxed) {
-            SOME => {
-                self.state.store(RESERVED, Ordering::Relaxed);
+        match *self.state.get_mut() {
+            SOME | FROZEN => {
+                self.exclusive_bump_generation();
+                *self.state.get_mut() = RESERVED;
                 let x = unsafe { (*self.value.get()).assume_init_mut() };
                 let old = core::mem::replace(x, value);
-                self.state.store(SOME, Ordering::Relaxed);
+                self.record_exit(ExitReason::Replaced);
+                *self.state.get_mut() = SOME;
                 Some(old)
             }
             NONE => {
-                self.state.store(RESERVED, Ordering::Relaxed);
+                self.exclusive_bump_generation();
+                *self.state.get_mut() = RESERVED;
                 self.value = MaybeUninit::new(value).into();
-                self.state.store(SOME, Ordering::Relaxed);
+                *self.state.get_mut() = SOME;
                 None
             }
             _ => panic!("ConcurrentOption value is `replace`d while its value is being written."),
@@ -193,7 +430,7 @@ impl<T> ConcurrentOption<T> {
     ///
     /// let val = opt.exclusive_insert(1);
     /// assert_eq!(*val, 1);
-    /// assert_eq!(unsafe { opt.as_ref() }, Some(&1));
+    /// assert_eq!(opt.with_ref(|x| *x), Some(1));
     ///
     /// let val = opt.exclusive_insert(2);
     /// assert_eq!(*val, 2);
@@ -202,17 +439,20 @@ impl<T> ConcurrentOption<T> {
     /// ```
     #[allow(clippy::panic, clippy::missing_panics_doc)]
     pub fn exclusive_insert(&mut self, value: T) -> &mut T {
-        match self.state.load(Ordering::Relaxed) {
-            SOME => {
-                self.state.store(RESERVED, Ordering::Relaxed);
+        match *self.state.get_mut() {
+            SOME | FROZEN => {
+                self.exclusive_bump_generation();
+                *self.state.get_mut() = RESERVED;
                 let x = unsafe { (*self.value.get()).assume_init_mut() };
                 let _ = core::mem::replace(x, value);
-                self.state.store(SOME, Ordering::Relaxed);
+                self.record_drop(ExitReason::Replaced);
+                *self.state.get_mut() = SOME;
             }
             NONE => {
-                self.state.store(RESERVED, Ordering::Relaxed);
+                self.exclusive_bump_generation();
+                *self.state.get_mut() = RESERVED;
                 self.value = MaybeUninit::new(value).into();
-                self.state.store(SOME, Ordering::Relaxed);
+                *self.state.get_mut() = SOME;
             }
             _ => panic!("ConcurrentOption value is `insert`ed while its value is being written."),
         }
@@ -246,6 +486,32 @@ impl<T> ConcurrentOption<T> {
         self.exclusive_get_or_insert_with(|| value)
     }
 
+    /// Inserts the default value into the option if it is None,
+    /// then returns a mutable reference to the contained value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut x = ConcurrentOption::none();
+    ///
+    /// {
+    ///     let y: &mut u32 = x.exclusive_get_or_insert_default();
+    ///     assert_eq!(y, &0);
+    ///
+    ///     *y = 7;
+    /// }
+    ///
+    /// assert_eq!(x, ConcurrentOption::some(7));
+    /// ```
+    pub fn exclusive_get_or_insert_default(&mut self) -> &mut T
+    where
+        T: Default,
+    {
+        self.exclusive_get_or_insert_with(T::default)
+    }
+
     /// Inserts a value computed from `f` into the option if it is None,
     /// then returns a mutable reference to the contained value.
     ///
@@ -265,22 +531,85 @@ impl<T> ConcurrentOption<T> {
     ///
     /// assert_eq!(x, ConcurrentOption::some(7));
     /// ```
-    #[allow(clippy::panic, clippy::missing_panics_doc)]
     pub fn exclusive_get_or_insert_with<F>(&mut self, f: F) -> &mut T
     where
         F: FnOnce() -> T,
     {
-        match self.state.load(Ordering::Relaxed) {
-            SOME => self.exclusive_as_mut().expect("is guaranteed to be some"),
+        self.exclusive_get_or_insert_with_status(f).0
+    }
+
+    /// Inserts a value computed from `f` into the option if it is None,
+    /// then returns a mutable reference to the contained value together with whether or not
+    /// `f` is called and its value is inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut x = ConcurrentOption::none();
+    ///
+    /// let (y, inserted) = x.exclusive_get_or_insert_with_status(|| 5);
+    /// assert_eq!((*y, inserted), (5, true));
+    ///
+    /// let (y, inserted) = x.exclusive_get_or_insert_with_status(|| 7);
+    /// assert_eq!((*y, inserted), (5, false));
+    /// ```
+    #[allow(clippy::panic, clippy::missing_panics_doc)]
+    pub fn exclusive_get_or_insert_with_status<F>(&mut self, f: F) -> (&mut T, bool)
+    where
+        F: FnOnce() -> T,
+    {
+        match *self.state.get_mut() {
+            SOME | FROZEN => (
+                self.exclusive_as_mut().expect("is guaranteed to be some"),
+                false,
+            ),
             NONE => {
-                self.state.store(RESERVED, Ordering::Relaxed);
+                self.exclusive_bump_generation();
+                *self.state.get_mut() = RESERVED;
                 self.value = MaybeUninit::new(f()).into();
-                self.state.store(SOME, Ordering::Relaxed);
-                self.exclusive_as_mut().expect("is guaranteed to be some")
+                *self.state.get_mut() = SOME;
+                (
+                    self.exclusive_as_mut().expect("is guaranteed to be some"),
+                    true,
+                )
             }
             _ => panic!(
                 "ConcurrentOption `get_or_insert_with` is called while its value is being written."
             ),
         }
     }
+
+    /// Inserts a value computed from `init` into the option if it is None,
+    /// then maps a mutable reference to the contained value with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut x = ConcurrentOption::none();
+    ///
+    /// let len = x.exclusive_map_or_insert_with(Vec::new, |v| {
+    ///     v.push(1);
+    ///     v.len()
+    /// });
+    /// assert_eq!(len, 1);
+    ///
+    /// let len = x.exclusive_map_or_insert_with(Vec::new, |v| {
+    ///     v.push(2);
+    ///     v.len()
+    /// });
+    /// assert_eq!(len, 2);
+    ///
+    /// assert_eq!(x, ConcurrentOption::some(vec![1, 2]));
+    /// ```
+    pub fn exclusive_map_or_insert_with<U, I, F>(&mut self, init: I, f: F) -> U
+    where
+        I: FnOnce() -> T,
+        F: FnOnce(&mut T) -> U,
+    {
+        f(self.exclusive_get_or_insert_with(init))
+    }
 }
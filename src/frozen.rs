@@ -0,0 +1,183 @@
+use crate::{parking::snooze, states::*, ConcurrentOption};
+use core::{mem::MaybeUninit, ops::Deref, sync::atomic::Ordering};
+
+/// A read-only option created by [`ConcurrentOption::into_frozen`], which has no mutation api at all.
+///
+/// It dereferences to an `Option<T>`; hence, all of the safe read accessors of `Option`, such as `as_ref`,
+/// `is_some` or `map` over `as_ref`, are available. It is `Sync` whenever `T` is `Sync`,
+/// which allows handing out the value to read-only subsystems without any synchronization or unsafe code.
+///
+/// [`ConcurrentOption::into_frozen`]: crate::ConcurrentOption::into_frozen
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FrozenOption<T>(Option<T>);
+
+impl<T> FrozenOption<T> {
+    /// Unwraps the underlying option.
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for FrozenOption<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<FrozenOption<T>> for Option<T> {
+    fn from(value: FrozenOption<T>) -> Self {
+        value.into_inner()
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Thread safe method to permanently turn the option of Some variant into a read-only option;
+    /// returns a reference to the value if the option is or already was frozen, None if the option is None.
+    ///
+    /// Once frozen, the value can never be mutated or taken through a shared reference.
+    /// This makes the safe [`get`] method possible, which returns a reference to the value without any reservation.
+    ///
+    /// After freezing:
+    /// * read methods such as [`is_some`], [`map`] or [`get_cloned`] treat the option as of Some variant;
+    /// * mutating methods which can report a failure fail; for instance, [`take`] returns None,
+    ///   [`update_if_some`] returns false and [`try_replace`] returns the value back as [`ReplaceError::Frozen`];
+    /// * mutating methods which must eventually succeed, such as [`replace`] or [`set_some`], panic;
+    /// * read guards such as the ones of [`get_guard`] are available, but cannot be upgraded, while [`get`] is
+    ///   the cheaper alternative; write guards such as the ones of [`lock_mut`] are not available.
+    ///
+    /// Methods requiring `&mut self` can still access the value mutably, since exclusive access proves that
+    /// no reference obtained by [`get`] is alive; the ones which write the state, such as [`exclusive_take`]
+    /// or [`exclusive_replace`], unfreeze the option.
+    ///
+    /// [`get`]: ConcurrentOption::get
+    /// [`is_some`]: ConcurrentOption::is_some
+    /// [`map`]: ConcurrentOption::map
+    /// [`get_cloned`]: ConcurrentOption::get_cloned
+    /// [`take`]: ConcurrentOption::take
+    /// [`update_if_some`]: ConcurrentOption::update_if_some
+    /// [`try_replace`]: ConcurrentOption::try_replace
+    /// [`ReplaceError::Frozen`]: crate::ReplaceError::Frozen
+    /// [`replace`]: ConcurrentOption::replace
+    /// [`set_some`]: ConcurrentOption::set_some
+    /// [`get_guard`]: ConcurrentOption::get_guard
+    /// [`lock_mut`]: ConcurrentOption::lock_mut
+    /// [`exclusive_take`]: ConcurrentOption::exclusive_take
+    /// [`exclusive_replace`]: ConcurrentOption::exclusive_replace
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::<String>::none();
+    /// assert_eq!(x.freeze(), None);
+    ///
+    /// x.initialize_if_none(3.to_string());
+    /// assert_eq!(x.get(), None); // not frozen yet
+    ///
+    /// let value = x.freeze().unwrap();
+    /// assert_eq!(value, "3");
+    ///
+    /// assert_eq!(x.take(), None);
+    /// assert!(!x.update_if_some(|x| x.push('!')));
+    /// assert_eq!(x.get(), Some(&3.to_string()));
+    /// ```
+    pub fn freeze(&self) -> Option<&T> {
+        loop {
+            match self
+                .state
+                .compare_exchange(SOME, FROZEN, Ordering::SeqCst, ORDER_LOAD)
+            {
+                Ok(_) | Err(FROZEN) => {
+                    return Some(unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) })
+                }
+                Err(RESERVED) => snooze(&self.state, RESERVED),
+                Err(_) => return None,
+            }
+        }
+    }
+
+    /// Thread safe method to return a reference to the value if the option is frozen by [`freeze`]; None otherwise.
+    ///
+    /// Note that an option of Some variant which is not frozen yields None.
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(vec![1, 2]);
+    /// assert_eq!(x.get(), None);
+    ///
+    /// _ = x.freeze();
+    ///
+    /// std::thread::scope(|s| {
+    ///     for _ in 0..4 {
+    ///         s.spawn(|| assert_eq!(x.get().map(|x| x.len()), Some(2)));
+    ///     }
+    /// });
+    /// ```
+    pub fn get(&self) -> Option<&T> {
+        match self.state.load(ORDER_LOAD) {
+            FROZEN => Some(unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) }),
+            _ => None,
+        }
+    }
+
+    /// Converts the option into a read-only [`FrozenOption`].
+    ///
+    /// Since the option is consumed, the conversion requires no atomic operations beyond plain loads and stores.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(vec![1, 2, 3]);
+    /// let frozen = x.into_frozen();
+    ///
+    /// std::thread::scope(|s| {
+    ///     for _ in 0..4 {
+    ///         s.spawn(|| assert_eq!(frozen.as_ref().map(|x| x.len()), Some(3)));
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(frozen.into_inner(), Some(vec![1, 2, 3]));
+    /// ```
+    pub fn into_frozen(self) -> FrozenOption<T> {
+        FrozenOption(self.into())
+    }
+
+    /// Returns whether or not the option is frozen by [`freeze`].
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    /// assert!(!x.is_frozen());
+    ///
+    /// _ = x.freeze();
+    /// assert!(x.is_frozen());
+    /// assert!(x.is_some());
+    /// ```
+    #[inline]
+    pub fn is_frozen(&self) -> bool {
+        self.state.load(ORDER_LOAD) == FROZEN
+    }
+
+    /// Panics if the option is frozen; used by mutating methods which cannot report a failure.
+    #[allow(clippy::panic)]
+    pub(crate) fn assert_not_frozen(&self) {
+        if self.is_frozen() {
+            panic!("frozen ConcurrentOption cannot be mutated through a shared reference");
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use crate::atomic::AtomicU32;
+#[cfg(any(miri, feature = "generation", feature = "ref-tracking"))]
+use crate::atomic::AtomicUsize;
+use crate::states::StateU8;
+#[cfg(feature = "tokio")]
+use crate::states::{NONE, SOME};
+use core::sync::atomic::Ordering;
+
+#[cfg(feature = "last-updated")]
+static EPOCH: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+
+/// Bookkeeping of a concurrent option besides its state, which is updated on every mutation.
+///
+/// It counts the active shared readers, such as a `map` call in progress, which read the value
+/// without reserving the option; a writer waits until there exists none before mutating the value.
+/// The count is a 32-bit word so that it fits next to the state of small values; hence, there can be
+/// at most `u32::MAX` readers, including the alive read guards, at the same time.
+///
+/// When the `generation` feature is enabled, it also holds the generation counter which is incremented
+/// on every mutation, and which is observed by [`Watcher`]s.
+///
+/// When the `last-updated` feature is enabled, it also records the instant of the last mutation.
+///
+/// When the `ref-tracking` feature is enabled or under miri, it also counts the alive [`RefToken`]s
+/// and asserts that there exists none whenever the option is about to be mutated.
+///
+/// When the `tokio` feature is enabled, it also holds the notifications of the option becoming Some and None,
+/// which are awaited by [`ConcurrentOption::notified_on_set`] and [`ConcurrentOption::notified_on_take`].
+///
+/// [`Watcher`]: crate::Watcher
+/// [`RefToken`]: crate::RefToken
+/// [`ConcurrentOption::notified_on_set`]: crate::ConcurrentOption::notified_on_set
+/// [`ConcurrentOption::notified_on_take`]: crate::ConcurrentOption::notified_on_take
+pub(crate) struct Generation {
+    #[cfg(feature = "generation")]
+    counter: AtomicUsize,
+    num_readers: AtomicU32,
+    #[cfg(feature = "last-updated")]
+    last_updated: crate::atomic::AtomicU64,
+    #[cfg(any(miri, feature = "ref-tracking"))]
+    pub(crate) num_ref_tokens: AtomicUsize,
+    #[cfg(feature = "tokio")]
+    pub(crate) on_set: tokio::sync::Notify,
+    #[cfg(feature = "tokio")]
+    pub(crate) on_take: tokio::sync::Notify,
+}
+
+impl Generation {
+    pub(crate) const fn new() -> Self {
+        Self {
+            #[cfg(feature = "generation")]
+            counter: AtomicUsize::new(0),
+            num_readers: AtomicU32::new(0),
+            #[cfg(feature = "last-updated")]
+            last_updated: crate::atomic::AtomicU64::new(0),
+            #[cfg(any(miri, feature = "ref-tracking"))]
+            num_ref_tokens: AtomicUsize::new(0),
+            #[cfg(feature = "tokio")]
+            on_set: tokio::sync::Notify::const_new(),
+            #[cfg(feature = "tokio")]
+            on_take: tokio::sync::Notify::const_new(),
+        }
+    }
+
+    #[cfg(feature = "generation")]
+    #[inline(always)]
+    pub(crate) fn load(&self, order: Ordering) -> usize {
+        self.counter.load(order)
+    }
+
+    /// Asserts that there exists no alive [`RefToken`] on the option; must be called before the option is mutated.
+    ///
+    /// Compiles to nothing unless the `ref-tracking` feature is enabled or the code runs under miri.
+    ///
+    /// [`RefToken`]: crate::RefToken
+    #[inline(always)]
+    pub(crate) fn assert_no_ref_tokens(&self) {
+        #[cfg(any(miri, feature = "ref-tracking"))]
+        assert_eq!(
+            self.num_ref_tokens.load(Ordering::Acquire),
+            0,
+            "ConcurrentOption is mutated while a RefToken to its value is alive"
+        );
+    }
+
+    /// Registers a shared reader; the reader must check that the state is SOME afterwards,
+    /// and call [`exit_read`] right away if it is not.
+    ///
+    /// Together with the sequentially consistent reservation of the writers, this guarantees that either
+    /// the reader observes the reservation, or the writer observes the reader in [`wait_for_readers`].
+    ///
+    /// [`exit_read`]: Generation::exit_read
+    /// [`wait_for_readers`]: Generation::wait_for_readers
+    #[inline(always)]
+    pub(crate) fn enter_read(&self) {
+        self.num_readers.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Deregisters a shared reader registered by [`enter_read`].
+    ///
+    /// [`enter_read`]: Generation::enter_read
+    #[inline(always)]
+    pub(crate) fn exit_read(&self) {
+        self.num_readers.fetch_sub(1, Ordering::Release);
+    }
+
+    /// Returns whether or not there exists an active shared reader.
+    #[inline(always)]
+    pub(crate) fn has_readers(&self) -> bool {
+        self.num_readers.load(Ordering::SeqCst) > 0
+    }
+
+    /// Returns whether or not there exists an active shared reader other than the caller,
+    /// which must be a registered reader itself.
+    #[inline(always)]
+    pub(crate) fn has_other_readers(&self) -> bool {
+        self.num_readers.load(Ordering::SeqCst) > 1
+    }
+
+    /// Spins until there exists no active shared reader; must be called by a writer after reserving
+    /// the option from the SOME state and before mutating the value.
+    #[inline(always)]
+    pub(crate) fn wait_for_readers(&self) {
+        while self.has_readers() {
+            core::hint::spin_loop();
+        }
+    }
+
+    /// Increments the generation; must be called before the state is released.
+    #[inline(always)]
+    pub(crate) fn bump(&self) {
+        #[cfg(feature = "generation")]
+        self.counter.fetch_add(1, Ordering::Relaxed);
+        #[cfg(feature = "last-updated")]
+        self.last_updated.store(now(), Ordering::Relaxed);
+    }
+
+    /// Notifies the tasks awaiting the option to become Some or None; must be called right after a mutation
+    /// releases the option, which was in the `from` state, into the `to` state.
+    ///
+    /// Compiles to nothing unless the `tokio` feature is enabled.
+    #[inline(always)]
+    pub(crate) fn notify_transition(&self, from: StateU8, to: StateU8) {
+        #[cfg(feature = "tokio")]
+        match (from, to) {
+            (SOME, SOME) => {}
+            (_, SOME) => self.on_set.notify_waiters(),
+            (SOME, NONE) => self.on_take.notify_waiters(),
+            _ => {}
+        }
+
+        #[cfg(not(feature = "tokio"))]
+        let _ = (from, to);
+    }
+
+    /// Increments the generation through an exclusive reference.
+    #[inline(always)]
+    pub(crate) fn bump_mut(&mut self) {
+        #[cfg(feature = "generation")]
+        {
+            let counter = self.counter.get_mut();
+            *counter = counter.wrapping_add(1);
+        }
+        #[cfg(feature = "last-updated")]
+        {
+            *self.last_updated.get_mut() = now();
+        }
+    }
+
+    #[cfg(feature = "last-updated")]
+    pub(crate) fn last_updated(&self, order: Ordering) -> Option<std::time::Instant> {
+        match self.last_updated.load(order) {
+            0 => None,
+            nanos => EPOCH
+                .get()
+                .map(|epoch| *epoch + core::time::Duration::from_nanos(nanos - 1)),
+        }
+    }
+}
+
+/// Nanoseconds elapsed since the epoch, shifted by one so that zero represents never.
+#[cfg(feature = "last-updated")]
+fn now() -> u64 {
+    let epoch = EPOCH.get_or_init(std::time::Instant::now);
+    let nanos = u64::try_from(epoch.elapsed().as_nanos()).unwrap_or(u64::MAX - 1);
+    nanos + 1
+}
@@ -1,9 +1,18 @@
+use crate::atomic::AtomicU8;
+use crate::contention::Contended;
+use crate::generation::Generation;
+use crate::parking::{notify, snooze};
 use crate::states::*;
-use core::sync::atomic::{AtomicU8, Ordering};
+use core::sync::atomic::Ordering;
 
 pub(crate) struct Handle<'a> {
     state: &'a AtomicU8,
+    initial_state: StateU8,
     success_state: StateU8,
+    generation: Option<&'a Generation>,
+    /// Generation of the option if the handle has been a write handle at any point,
+    /// which is notified about the transition once the option is released.
+    written: Option<&'a Generation>,
 }
 
 impl<'a> Handle<'a> {
@@ -11,20 +20,25 @@ impl<'a> Handle<'a> {
         state: &'a AtomicU8,
         initial_state: StateU8,
         success_state: StateU8,
+        generation: Option<&'a Generation>,
     ) -> Option<Self> {
+        if let Some(generation) = generation {
+            generation.assert_no_ref_tokens();
+        }
         match state
-            .compare_exchange(
-                initial_state,
-                RESERVED,
-                Ordering::Acquire,
-                Ordering::Relaxed,
-            )
+            .compare_exchange(initial_state, RESERVED, Ordering::SeqCst, Ordering::Relaxed)
             .is_ok()
         {
-            true => Some(Self {
-                state,
-                success_state,
-            }),
+            true => {
+                wait_for_readers(initial_state, generation);
+                Some(Self {
+                    state,
+                    initial_state,
+                    success_state,
+                    generation,
+                    written: generation,
+                })
+            }
             false => None,
         }
     }
@@ -33,38 +47,215 @@ impl<'a> Handle<'a> {
         state: &'a AtomicU8,
         initial_state: StateU8,
         success_state: StateU8,
+        generation: Option<&'a Generation>,
     ) -> Option<Self> {
+        if let Some(generation) = generation {
+            generation.assert_no_ref_tokens();
+        }
+        #[cfg(feature = "deadlock-detection")]
+        let mut watch = crate::deadlock::SpinWatch::new();
         loop {
             match state.compare_exchange(
                 initial_state,
                 RESERVED,
-                Ordering::Acquire,
+                Ordering::SeqCst,
                 Ordering::Relaxed,
             ) {
                 Ok(_) => {
+                    wait_for_readers(initial_state, generation);
                     return Some(Self {
                         state,
+                        initial_state,
                         success_state,
-                    })
+                        generation,
+                        written: generation,
+                    });
                 }
                 Err(previous_state) => match previous_state {
-                    RESERVED => continue,
+                    RESERVED => {
+                        #[cfg(feature = "deadlock-detection")]
+                        watch.on_spin();
+                        snooze(state, RESERVED);
+                    }
                     _ => return None,
                 },
             }
         }
     }
+
+    pub fn spin_get_bounded(
+        state: &'a AtomicU8,
+        initial_state: StateU8,
+        success_state: StateU8,
+        generation: Option<&'a Generation>,
+        spins_left: &mut usize,
+    ) -> Result<Option<Self>, Contended> {
+        Self::spin_get_while(state, initial_state, success_state, generation, || {
+            match *spins_left > 0 {
+                true => {
+                    *spins_left -= 1;
+                    true
+                }
+                false => false,
+            }
+        })
+    }
+
+    /// Spins while the state is reserved by another thread as long as `keep_spinning` returns true.
+    pub fn spin_get_while<F>(
+        state: &'a AtomicU8,
+        initial_state: StateU8,
+        success_state: StateU8,
+        generation: Option<&'a Generation>,
+        mut keep_spinning: F,
+    ) -> Result<Option<Self>, Contended>
+    where
+        F: FnMut() -> bool,
+    {
+        if let Some(generation) = generation {
+            generation.assert_no_ref_tokens();
+        }
+        #[cfg(feature = "deadlock-detection")]
+        let mut watch = crate::deadlock::SpinWatch::new();
+        loop {
+            match state.compare_exchange(
+                initial_state,
+                RESERVED,
+                Ordering::SeqCst,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => {
+                    if let Some(generation) = generation.filter(|_| initial_state == SOME) {
+                        while generation.has_readers() {
+                            match keep_spinning() {
+                                true => core::hint::spin_loop(),
+                                false => {
+                                    state.store(initial_state, ORDER_STORE);
+                                    notify(state);
+                                    return Err(Contended(()));
+                                }
+                            }
+                        }
+                    }
+                    return Ok(Some(Self {
+                        state,
+                        initial_state,
+                        success_state,
+                        generation,
+                        written: generation,
+                    }));
+                }
+                Err(previous_state) => match previous_state {
+                    RESERVED if keep_spinning() => {
+                        #[cfg(feature = "deadlock-detection")]
+                        watch.on_spin();
+                        core::hint::spin_loop();
+                    }
+                    RESERVED => return Err(Contended(())),
+                    _ => return Ok(None),
+                },
+            }
+        }
+    }
+
+    /// Sets the state the option will be released into once the handle is dropped.
+    pub fn set_success_state(&mut self, success_state: StateU8) {
+        self.success_state = success_state;
+    }
+
+    /// Turns the handle into a write handle which bumps the `generation` once dropped, keeping the reservation.
+    pub fn make_write(&mut self, generation: &'a Generation) {
+        generation.assert_no_ref_tokens();
+        generation.wait_for_readers();
+        self.generation = Some(generation);
+        self.written = Some(generation);
+    }
 }
 
-impl<'a> Drop for Handle<'a> {
+/// Waits for the shared readers to leave if a write handle is acquired from the SOME state.
+#[inline(always)]
+fn wait_for_readers(initial_state: StateU8, generation: Option<&Generation>) {
+    if let Some(generation) = generation.filter(|_| initial_state == SOME) {
+        generation.wait_for_readers();
+    }
+}
+
+impl Drop for Handle<'_> {
     fn drop(&mut self) {
+        if let Some(generation) = self.generation {
+            generation.bump();
+        }
+
         self.state
-            .compare_exchange(
-                RESERVED,
-                self.success_state,
-                Ordering::Release,
-                Ordering::Relaxed,
-            )
+            .compare_exchange(RESERVED, self.success_state, ORDER_STORE, Ordering::Relaxed)
             .expect("Failed to update the concurrent state after concurrent state mutation");
+        notify(self.state);
+
+        if let Some(generation) = self.written {
+            generation.notify_transition(self.initial_state, self.success_state);
+        }
+    }
+}
+
+/// Handle of a shared reader of an option of Some variant.
+///
+/// Unlike a [`Handle`], it does not reserve the option; hence, any number of readers can read the value concurrently.
+/// Writers, on the other hand, wait until all readers drop their handles before mutating the value.
+pub(crate) struct ReadHandle<'a> {
+    generation: &'a Generation,
+}
+
+impl<'a> ReadHandle<'a> {
+    /// Creates the handle of a reader which is already registered by [`Generation::enter_read`]
+    /// while the option is known to be of Some variant.
+    pub fn entered(generation: &'a Generation) -> Self {
+        Self { generation }
+    }
+
+    /// Returns a read handle if the option is of Some variant, including a frozen option, None otherwise;
+    /// returns a [`Contended`] error without waiting if the option is reserved by another thread.
+    pub fn get(state: &'a AtomicU8, generation: &'a Generation) -> Result<Option<Self>, Contended> {
+        generation.enter_read();
+        match state.load(Ordering::SeqCst) {
+            SOME | FROZEN => Ok(Some(Self { generation })),
+            x => {
+                generation.exit_read();
+                match x {
+                    RESERVED => Err(Contended(())),
+                    _ => Ok(None),
+                }
+            }
+        }
+    }
+
+    /// Spins while the option is reserved by another thread;
+    /// returns a read handle if the option is of Some variant, including a frozen option, None otherwise.
+    pub fn spin_get(state: &'a AtomicU8, generation: &'a Generation) -> Option<Self> {
+        #[cfg(feature = "deadlock-detection")]
+        let mut watch = crate::deadlock::SpinWatch::new();
+        loop {
+            generation.enter_read();
+            match state.load(Ordering::SeqCst) {
+                SOME | FROZEN => return Some(Self { generation }),
+                RESERVED => {
+                    generation.exit_read();
+                    while state.load(Ordering::Relaxed) == RESERVED {
+                        #[cfg(feature = "deadlock-detection")]
+                        watch.on_spin();
+                        snooze(state, RESERVED);
+                    }
+                }
+                _ => {
+                    generation.exit_read();
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+impl Drop for ReadHandle<'_> {
+    fn drop(&mut self) {
+        self.generation.exit_read();
     }
 }
@@ -0,0 +1,119 @@
+use crate::{states::*, ConcurrentOption};
+use core::sync::atomic::Ordering;
+
+/// The exclusive right to initialize a [`ConcurrentOption`], created by [`ConcurrentOption::begin_once`].
+///
+/// The right can be claimed only once throughout the lifetime of the option; once a token is created,
+/// all subsequent `begin_once` calls return None, even after the token is completed or dropped.
+/// Only the holder of the token can complete the initialization by [`ConcurrentOption::complete`].
+///
+/// Unlike [`ConcurrentOption::try_claim`], the token does not reserve the option;
+/// hence, the value can be computed while holding the token without blocking the readers.
+///
+/// Available with the `init-once` feature, which adds the once flag to every option.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`ConcurrentOption::begin_once`]: crate::ConcurrentOption::begin_once
+/// [`ConcurrentOption::complete`]: crate::ConcurrentOption::complete
+/// [`ConcurrentOption::try_claim`]: crate::ConcurrentOption::try_claim
+#[derive(Debug)]
+#[must_use = "the right to initialize is lost for good once the token is dropped"]
+pub struct InitOnceToken<'a, T> {
+    option: &'a ConcurrentOption<T>,
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Thread safe method to claim the exclusive right to initialize the option, which can succeed only once
+    /// throughout the lifetime of the option.
+    ///
+    /// Returns an [`InitOnceToken`] on the first call; returns None on all subsequent calls,
+    /// regardless of whether the token is completed, dropped or still alive.
+    ///
+    /// This gives a safe alternative to [`initialize_unchecked`] for the single-initializer pattern:
+    /// rather than the caller promising that there exists one initializer, the token statically proves it.
+    ///
+    /// [`initialize_unchecked`]: ConcurrentOption::initialize_unchecked
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::none();
+    ///
+    /// let token = x.begin_once().unwrap();
+    /// assert!(x.begin_once().is_none());
+    ///
+    /// assert_eq!(x.complete(token, 3.to_string()), Ok(()));
+    /// assert_eq!(x.get_cloned(), Some(3.to_string()));
+    ///
+    /// // the right is never claimed again, even if the option is emptied
+    /// _ = x.take();
+    /// assert!(x.begin_once().is_none());
+    /// ```
+    pub fn begin_once(&self) -> Option<InitOnceToken<'_, T>> {
+        self.once_begun
+            .compare_exchange(0, 1, Ordering::AcqRel, Ordering::Relaxed)
+            .ok()
+            .map(|_| InitOnceToken { option: self })
+    }
+
+    /// Returns whether or not the right to initialize the option is already claimed by [`begin_once`].
+    ///
+    /// [`begin_once`]: ConcurrentOption::begin_once
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::<String>::none();
+    /// assert!(!x.is_once_begun());
+    ///
+    /// let token = x.begin_once();
+    /// drop(token);
+    /// assert!(x.is_once_begun());
+    /// ```
+    pub fn is_once_begun(&self) -> bool {
+        self.once_begun.load(ORDER_LOAD) == 1
+    }
+
+    /// Thread safe method to initialize the option with the `value` by consuming the `token` created by [`begin_once`].
+    ///
+    /// Returns back the `value` as the error, leaving the option untouched, if
+    /// * the `token` is created by another option, or
+    /// * the option is not of None variant, such as when it is initialized by `initialize_if_none` in the meantime.
+    ///
+    /// [`begin_once`]: ConcurrentOption::begin_once
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::none();
+    /// let y = ConcurrentOption::none();
+    ///
+    /// let token = y.begin_once().unwrap();
+    /// assert_eq!(x.complete(token, 42), Err(42));
+    /// assert!(x.is_none() && y.is_none());
+    ///
+    /// let token = x.begin_once().unwrap();
+    /// x.initialize_if_none(7);
+    /// assert_eq!(x.complete(token, 42), Err(42));
+    /// assert_eq!(x.get_cloned(), Some(7));
+    /// ```
+    pub fn complete(&self, token: InitOnceToken<'_, T>, value: T) -> Result<(), T> {
+        if !core::ptr::eq(token.option, self) {
+            return Err(value);
+        }
+
+        match self.get_write_handle(NONE, SOME) {
+            Some(_handle) => {
+                unsafe { &mut *self.value.get() }.write(value);
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{handle::ReadHandle, states::*, ConcurrentOption};
+use core::{fmt::Debug, mem::MaybeUninit, ops::Deref};
+
+/// A proof that a [`ConcurrentOption`] is initialized, providing safe shared access to its value;
+/// created by [`ConcurrentOption::initialized`] or [`ConcurrentOption::get_or_initialize`].
+///
+/// The token registers as a shared reader of the option; it does not reserve the option.
+/// Therefore, any number of tokens and reads can coexist, while writers such as `take` or `replace`
+/// wait until all tokens are dropped. This makes the safe [`get`] method possible,
+/// which is the common use case of the init-once pattern where the option is never mutated once initialized.
+///
+/// Note that the thread holding a token must not mutate the same option, which would wait for the token forever.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`ConcurrentOption::initialized`]: crate::ConcurrentOption::initialized
+/// [`ConcurrentOption::get_or_initialize`]: crate::ConcurrentOption::get_or_initialize
+/// [`get`]: Initialized::get
+pub struct Initialized<'a, T> {
+    _handle: ReadHandle<'a>,
+    option: &'a ConcurrentOption<T>,
+}
+
+impl<'a, T> Initialized<'a, T> {
+    /// Creates the token of a reader which is already registered by `enter_read`
+    /// while the option is known to be of Some variant.
+    pub(crate) fn entered(option: &'a ConcurrentOption<T>) -> Self {
+        Self {
+            _handle: ReadHandle::entered(&option.generation),
+            option,
+        }
+    }
+
+    /// Returns a reference to the value of the initialized option.
+    pub fn get(&self) -> &T {
+        unsafe { MaybeUninit::assume_init_ref(&*self.option.value.get()) }
+    }
+}
+
+impl<T> Deref for Initialized<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+impl<T: Debug> Debug for Initialized<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Initialized").field(self.get()).finish()
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Thread safe method to obtain an [`Initialized`] token proving that the option is of Some variant,
+    /// which provides safe access to the value; returns None if the option is None.
+    ///
+    /// See [`Initialized`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::none();
+    /// assert!(x.initialized().is_none());
+    ///
+    /// x.initialize_if_none(3.to_string());
+    ///
+    /// let first = x.initialized().unwrap();
+    /// let second = x.initialized().unwrap(); // tokens do not block each other
+    /// assert_eq!(first.get(), "3");
+    /// assert_eq!(second.len(), 1);
+    /// ```
+    pub fn initialized(&self) -> Option<Initialized<'_, T>> {
+        self.spin_get_read_handle().map(|handle| Initialized {
+            _handle: handle,
+            option: self,
+        })
+    }
+
+    /// Thread safe method to initialize the option with the given `value` if it is None,
+    /// and then to obtain an [`Initialized`] token providing safe access to the value.
+    ///
+    /// The `value` is dropped if the option is already of Some variant.
+    ///
+    /// See [`Initialized`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let config = ConcurrentOption::none();
+    ///
+    /// std::thread::scope(|s| {
+    ///     for i in 0..4 {
+    ///         let config = &config;
+    ///         s.spawn(move || {
+    ///             let config = config.get_or_initialize(format!("config-{}", i));
+    ///             assert!(config.get().starts_with("config-"));
+    ///         });
+    ///     }
+    /// });
+    ///
+    /// assert!(config.is_some());
+    /// ```
+    pub fn get_or_initialize(&self, value: T) -> Initialized<'_, T> {
+        loop {
+            if let Some(initialized) = self.initialized() {
+                return initialized;
+            }
+
+            if let Some(handle) = self.spin_get_write_handle(NONE, SOME) {
+                unsafe { &mut *self.value.get() }.write(value);
+                // registered as a reader before the option is released as Some
+                self.generation.enter_read();
+                drop(handle);
+                return Initialized::entered(self);
+            }
+        }
+    }
+}
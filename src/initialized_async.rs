@@ -0,0 +1,125 @@
+use crate::{states::*, waiters, ConcurrentOption};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+/// Future which resolves once the [`ConcurrentOption`] is observed to be Some,
+/// created by [`ConcurrentOption::initialized_async`].
+///
+/// While pending, the future registers the waker of its task on the option; the task is woken by the next state
+/// transition of the option, such as `initialize_if_none`, rather than by polling.
+/// The registration is removed when the future is dropped before it resolves.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`ConcurrentOption::initialized_async`]: crate::ConcurrentOption::initialized_async
+#[must_use = "futures do nothing unless awaited or polled"]
+pub struct InitializedAsync<'a, T> {
+    option: &'a ConcurrentOption<T>,
+    waker: Option<Waker>,
+}
+
+impl<T> InitializedAsync<'_, T> {
+    fn is_some(&self) -> bool {
+        matches!(self.option.state.load(ORDER_LOAD), SOME | FROZEN)
+    }
+}
+
+impl<T> Future for InitializedAsync<'_, T> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if !self.is_some() {
+            waiters::register_waker(&self.option.state, cx.waker());
+            self.waker = Some(cx.waker().clone());
+
+            // the option might have been initialized before the registration
+            if !self.is_some() {
+                return Poll::Pending;
+            }
+        }
+
+        // the registration is consumed by the release which initialized the option, unless the release happened
+        // before the registration; then, it must be removed here since no release might ever consume it
+        if let Some(waker) = self.waker.take() {
+            waiters::deregister_waker(&self.option.state, &waker);
+        }
+        Poll::Ready(())
+    }
+}
+
+impl<T> Drop for InitializedAsync<'_, T> {
+    fn drop(&mut self) {
+        if let Some(waker) = &self.waker {
+            waiters::deregister_waker(&self.option.state, waker);
+        }
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Returns a future which resolves once the option is observed to be Some.
+    ///
+    /// This is the async form of [`wait_for_some`]. Rather than asking the executor to poll it repeatedly,
+    /// the pending future is woken by the state transitions of the option.
+    ///
+    /// Note that the option might be taken right after the future resolves, if there are other writers.
+    /// [`get_cloned_async`] can be used to wait for and read the value at once.
+    ///
+    /// [`wait_for_some`]: ConcurrentOption::wait_for_some
+    /// [`get_cloned_async`]: ConcurrentOption::get_cloned_async
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use std::{future::Future, pin::pin, task::*};
+    ///
+    /// let x = ConcurrentOption::none();
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let mut initialized = pin!(x.initialized_async());
+    /// assert_eq!(initialized.as_mut().poll(&mut cx), Poll::Pending);
+    ///
+    /// x.initialize_if_none(42);
+    /// assert_eq!(initialized.as_mut().poll(&mut cx), Poll::Ready(()));
+    /// ```
+    pub fn initialized_async(&self) -> InitializedAsync<'_, T> {
+        InitializedAsync {
+            option: self,
+            waker: None,
+        }
+    }
+
+    /// Waits until the option is observed to be Some and returns a clone of its value.
+    ///
+    /// If the option is taken by another thread between the initialization and the clone,
+    /// the future keeps waiting for the next value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use std::{future::Future, pin::pin, task::*};
+    ///
+    /// let x = ConcurrentOption::none();
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let mut value = pin!(x.get_cloned_async());
+    /// assert_eq!(value.as_mut().poll(&mut cx), Poll::Pending);
+    ///
+    /// x.initialize_if_none(3.to_string());
+    /// assert_eq!(value.as_mut().poll(&mut cx), Poll::Ready(3.to_string()));
+    /// ```
+    pub async fn get_cloned_async(&self) -> T
+    where
+        T: Clone,
+    {
+        loop {
+            self.initialized_async().await;
+            if let Some(value) = self.get_cloned() {
+                return value;
+            }
+        }
+    }
+}
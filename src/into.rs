@@ -1,5 +1,4 @@
-use crate::{concurrent_option::ConcurrentOption, states::*};
-use core::sync::atomic::Ordering;
+use crate::{concurrent_option::ConcurrentOption, states::*, IntoOption};
 
 impl<T> ConcurrentOption<T> {
     /// Returns the contained Some value, consuming the `self` value.
@@ -118,6 +117,116 @@ impl<T> ConcurrentOption<T> {
         self.exclusive_take().unwrap_or_else(f)
     }
 
+    /// Returns the option if it contains a value, otherwise returns `other`.
+    ///
+    /// Arguments passed to `or` are eagerly evaluated; if you are passing the
+    /// result of a function call, it is recommended to use [`ConcurrentOption::or_else`],
+    /// which is lazily evaluated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// assert_eq!(ConcurrentOption::some(2).or(None), Some(2));
+    /// assert_eq!(ConcurrentOption::none().or(Some(100)), Some(100));
+    /// assert_eq!(ConcurrentOption::some(2).or(ConcurrentOption::some(100)), Some(2));
+    /// assert_eq!(ConcurrentOption::<u32>::none().or(None), None);
+    /// ```
+    pub fn or(mut self, other: impl IntoOption<T>) -> Option<T> {
+        self.exclusive_take().or_else(|| other.into_option())
+    }
+
+    /// Returns the option if it contains a value, otherwise calls `f` and returns the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// fn nobody() -> Option<&'static str> { None }
+    /// fn vikings() -> Option<&'static str> { Some("vikings") }
+    ///
+    /// assert_eq!(ConcurrentOption::some("barbarians").or_else(vikings), Some("barbarians"));
+    /// assert_eq!(ConcurrentOption::none().or_else(vikings), Some("vikings"));
+    /// assert_eq!(ConcurrentOption::none().or_else(nobody), None);
+    /// ```
+    pub fn or_else<V, F>(mut self, f: F) -> Option<T>
+    where
+        V: IntoOption<T>,
+        F: FnOnce() -> V,
+    {
+        self.exclusive_take().or_else(|| f().into_option())
+    }
+
+    /// Returns Some if exactly one of the option and `other` is Some, otherwise returns None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// assert_eq!(ConcurrentOption::some(2).xor(None), Some(2));
+    /// assert_eq!(ConcurrentOption::none().xor(Some(2)), Some(2));
+    /// assert_eq!(ConcurrentOption::some(2).xor(ConcurrentOption::some(2)), None);
+    /// assert_eq!(ConcurrentOption::<u32>::none().xor(None), None);
+    /// ```
+    pub fn xor(mut self, other: impl IntoOption<T>) -> Option<T> {
+        self.exclusive_take().xor(other.into_option())
+    }
+
+    /// Transforms the option into a [`Result`], mapping Some(v) to Ok(v) and None to Err(err).
+    ///
+    /// Arguments passed to `ok_or` are eagerly evaluated; if you are passing the
+    /// result of a function call, it is recommended to use [`ConcurrentOption::ok_or_else`],
+    /// which is lazily evaluated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// assert_eq!(ConcurrentOption::some("foo").ok_or(0), Ok("foo"));
+    /// assert_eq!(ConcurrentOption::<&str>::none().ok_or(0), Err(0));
+    /// ```
+    pub fn ok_or<E>(mut self, err: E) -> Result<T, E> {
+        self.exclusive_take().ok_or(err)
+    }
+
+    /// Transforms the option into a [`Result`], mapping Some(v) to Ok(v) and None to Err(err()).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// assert_eq!(ConcurrentOption::some("foo").ok_or_else(|| 0), Ok("foo"));
+    /// assert_eq!(ConcurrentOption::<&str>::none().ok_or_else(|| 0), Err(0));
+    /// ```
+    pub fn ok_or_else<E, F>(mut self, err: F) -> Result<T, E>
+    where
+        F: FnOnce() -> E,
+    {
+        self.exclusive_take().ok_or_else(err)
+    }
+
+    /// Zips the option with `other`.
+    ///
+    /// Returns Some((s, o)) if both the option and `other` are Some, otherwise returns None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// assert_eq!(ConcurrentOption::some(1).zip(Some("hi")), Some((1, "hi")));
+    /// assert_eq!(ConcurrentOption::some(1).zip(ConcurrentOption::<u8>::none()), None);
+    /// assert_eq!(ConcurrentOption::<u8>::none().zip(Some("hi")), None);
+    /// ```
+    pub fn zip<U>(mut self, other: impl IntoOption<U>) -> Option<(T, U)> {
+        self.exclusive_take().zip(other.into_option())
+    }
+
     /// Returns the contained Some value, consuming the `self` value,
     /// without checking that the value is not None.
     ///
@@ -142,9 +251,46 @@ impl<T> ConcurrentOption<T> {
     /// let x: ConcurrentOption<&str> = ConcurrentOption::none();
     /// assert_eq!(unsafe { x.unwrap_unchecked() }, "air"); // Undefined behavior!
     /// ```
-    pub unsafe fn unwrap_unchecked(self) -> T {
-        self.state.store(NONE, Ordering::Relaxed);
+    pub unsafe fn unwrap_unchecked(mut self) -> T {
+        *self.state.get_mut() = NONE;
         let x = &mut *self.value.get();
         x.assume_init_read()
     }
 }
+
+impl<T, U> ConcurrentOption<(T, U)> {
+    /// Unzips an option containing a tuple into a tuple of options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// assert_eq!(ConcurrentOption::some((1, "hi")).unzip(), (Some(1), Some("hi")));
+    /// assert_eq!(ConcurrentOption::<(u8, u32)>::none().unzip(), (None, None));
+    /// ```
+    pub fn unzip(mut self) -> (Option<T>, Option<U>) {
+        self.exclusive_take().unzip()
+    }
+}
+
+impl<T, E> ConcurrentOption<Result<T, E>> {
+    /// Transposes an option of a [`Result`] into a [`Result`] of an option.
+    ///
+    /// None is mapped to Ok(None); Some(Ok(x)) and Some(Err(e)) are mapped to Ok(Some(x)) and Err(e), respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x: ConcurrentOption<Result<i32, String>> = ConcurrentOption::some(Ok(5));
+    /// assert_eq!(x.transpose(), Ok(Some(5)));
+    ///
+    /// let x: ConcurrentOption<Result<i32, String>> = ConcurrentOption::none();
+    /// assert_eq!(x.transpose(), Ok(None));
+    /// ```
+    pub fn transpose(mut self) -> Result<Option<T>, E> {
+        self.exclusive_take().transpose()
+    }
+}
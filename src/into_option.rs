@@ -1,4 +1,4 @@
-use crate::ConcurrentOption;
+use crate::{ConcurrentOption, LoadOrdering};
 
 /// Trait representing types that can be converted into a standard Option.
 ///
@@ -28,16 +28,93 @@ pub trait IntoOption<T> {
     /// assert_eq!(option.into_option(), Some(42));
     /// ```
     fn into_option(self) -> Option<T>;
+
+    /// Converts self into Option where any shared state is loaded with the desired `order`.
+    ///
+    /// Owned values have no shared state; hence, the default implementation simply calls [`into_option`].
+    /// On the other hand, converting a borrowed `&ConcurrentOption<T>` clones the value with the given ordering.
+    ///
+    /// [`into_option`]: IntoOption::into_option
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// fn read<O: IntoOption<i32>>(x: O) -> Option<i32> {
+    ///     x.into_option_with_order(LoadOrdering::Relaxed)
+    /// }
+    ///
+    /// let con_option = ConcurrentOption::some(42);
+    /// assert_eq!(read(&con_option), Some(42));
+    /// assert_eq!(read(con_option), Some(42));
+    /// assert_eq!(read(Some(42)), Some(42));
+    /// ```
+    fn into_option_with_order(self, order: LoadOrdering) -> Option<T>
+    where
+        Self: Sized,
+    {
+        let _ = order;
+        self.into_option()
+    }
+
+    /// Returns a clone of the value as an Option without consuming self.
+    ///
+    /// This is useful when a snapshot suffices, avoiding to consume the concurrent value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let con_option = ConcurrentOption::some(42.to_string());
+    /// assert_eq!(con_option.to_option(), Some(42.to_string()));
+    /// assert_eq!(con_option.take(), Some(42.to_string()));
+    ///
+    /// let option = Some(42.to_string());
+    /// assert_eq!(option.to_option(), Some(42.to_string()));
+    /// ```
+    fn to_option(&self) -> Option<T>
+    where
+        T: Clone;
 }
 
 impl<T> IntoOption<T> for Option<T> {
     fn into_option(self) -> Option<T> {
         self
     }
+
+    fn to_option(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.clone()
+    }
 }
 
 impl<T> IntoOption<T> for ConcurrentOption<T> {
     fn into_option(mut self) -> Option<T> {
         self.exclusive_take()
     }
+
+    fn to_option(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.clone_into_option()
+    }
+}
+
+impl<T: Clone> IntoOption<T> for &ConcurrentOption<T> {
+    fn into_option(self) -> Option<T> {
+        self.clone_into_option()
+    }
+
+    fn into_option_with_order(self, order: LoadOrdering) -> Option<T> {
+        self.get_cloned_with_order(order)
+    }
+
+    fn to_option(&self) -> Option<T> {
+        self.clone_into_option()
+    }
 }
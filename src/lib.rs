@@ -12,23 +12,146 @@
 )]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod aggregate;
+mod aligned;
+#[cfg(feature = "alloc")]
+mod arc;
+#[cfg(feature = "arc-swap")]
+mod arc_swap_interop;
+mod atomic;
+mod bitmap;
+#[cfg(feature = "alloc")]
+mod bulk;
+#[cfg(feature = "std")]
+mod cancel;
+mod claim;
 mod common_traits;
 mod concurrent;
+mod concurrent_init;
 mod concurrent_option;
+mod contention;
+mod custom_state;
+#[cfg(feature = "std")]
+mod deadline;
+#[cfg(feature = "deadlock-detection")]
+pub mod deadlock;
+#[cfg(feature = "dot")]
+pub mod dot;
 mod drop;
+mod dyn_slot;
+pub mod encoding;
+mod entry;
 mod exclusive;
+mod frozen;
+mod generation;
 mod handle;
+#[cfg(feature = "init-once")]
+mod init_once;
+mod initialized;
+#[cfg(feature = "async")]
+mod initialized_async;
 mod into;
 mod into_option;
+mod macros;
+mod mapped_guard;
 mod mut_handle;
 mod new;
+#[cfg(feature = "tokio")]
+mod notified;
 mod option;
+mod ordering;
+#[cfg(feature = "std")]
+mod padded;
+mod parking;
 mod raw;
+mod read_guard;
+mod ref_token;
+mod replace_scoped;
+mod reservation;
+#[cfg(all(feature = "std", not(orx_concurrent_option_single_thread)))]
+mod scoped_init;
+#[cfg(feature = "alloc")]
+mod small;
+pub mod spsc;
 mod states;
+mod stats;
+#[cfg(all(feature = "stress", not(orx_concurrent_option_single_thread)))]
+pub mod stress;
+#[cfg(feature = "generation")]
+mod take_token;
+pub mod testing;
+mod wait;
+#[cfg(any(
+    all(feature = "std", not(feature = "futex"), not(feature = "parking_lot")),
+    feature = "async"
+))]
+mod waiters;
+#[cfg(feature = "generation")]
+mod watcher;
+mod with_metadata;
 mod with_order;
+mod with_raw_order;
+mod write_guard;
+#[cfg(feature = "write-intent")]
+mod write_intent;
 
+pub use aligned::{
+    Align128, Align16, Align32, Align4096, Align64, Aligned, AlignedConcurrentOption, Alignment,
+};
+#[cfg(feature = "alloc")]
+pub use arc::SharedOption;
+#[cfg(feature = "arc-swap")]
+pub use arc_swap_interop::ArcSwapAdapter;
+pub use bitmap::some_bitmap;
+#[cfg(feature = "alloc")]
+pub use bulk::{
+    into_concurrent_options, into_concurrent_options_boxed, into_options, into_options_boxed,
+};
+#[cfg(feature = "std")]
+pub use cancel::{CancelToken, Cancelled};
+pub use claim::SlotClaim;
+pub use common_traits::debug::DisplaySnapshot;
 pub use common_traits::iter;
+pub use concurrent_init::{ConcurrentInit, InitTargets};
 pub use concurrent_option::ConcurrentOption;
+pub use contention::{Contended, ReplaceError, DEFAULT_MAX_SPINS};
+pub use custom_state::{CustomState, MAX_NUM_CUSTOM_STATES};
+pub use dyn_slot::DynSlot;
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+pub use frozen::FrozenOption;
+#[cfg(feature = "init-once")]
+pub use init_once::InitOnceToken;
+pub use initialized::Initialized;
+#[cfg(feature = "async")]
+pub use initialized_async::InitializedAsync;
 pub use into_option::IntoOption;
-pub use mut_handle::MutHandle;
-pub use states::{State, StateU8, NONE, RESERVED, SOME};
+pub use mapped_guard::{MappedReadGuard, MappedWriteGuard};
+pub use mut_handle::{InvalidTransition, MutHandle, SomeMutHandle};
+pub use ordering::LoadOrdering;
+#[cfg(feature = "std")]
+pub use padded::{cache_line_size, PaddedSlots, DEFAULT_CACHE_LINE_SIZE};
+pub use read_guard::ReadGuard;
+pub use ref_token::RefToken;
+pub use reservation::ReservationGuard;
+#[cfg(all(feature = "std", not(orx_concurrent_option_single_thread)))]
+pub use scoped_init::ScopedInit;
+#[cfg(feature = "alloc")]
+pub use small::{SmallBox, SmallConcurrentOption};
+pub use states::{State, StateU8, FROZEN, NONE, RESERVED, SOME};
+#[cfg(feature = "stats")]
+pub use stats::ExitReason;
+#[cfg(feature = "generation")]
+pub use take_token::TakeToken;
+#[cfg(feature = "async")]
+pub use wait::WaitForNone;
+#[cfg(feature = "generation")]
+pub use watcher::Watcher;
+pub use with_metadata::{AtomicMetadata, ConcurrentOptionWith};
+pub use write_guard::WriteGuard;
+#[cfg(feature = "write-intent")]
+pub use write_intent::WriteIntent;
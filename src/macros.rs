@@ -0,0 +1,130 @@
+/// Defines a global late-initialized value backed by a static [`ConcurrentOption`].
+///
+/// `concurrent_static!(static NAME: T;)` expands to a unit type `NAME` with the following accessors:
+/// * `NAME::init(value: T) -> bool` initializes the global value if it is not yet initialized;
+///   returns whether or not the value is initialized by this call,
+/// * `NAME::get() -> Option<&'static T>` returns a reference to the global value if it is initialized,
+/// * `NAME::is_initialized() -> bool` returns whether or not the global value is initialized.
+///
+/// The underlying static is defined within an anonymous constant block; hence, it cannot be named by any code,
+/// including the module defining the global value. Therefore, the value can only be written once and is never
+/// mutated afterwards. This is what makes it safe to hand out `&'static T` references after initialization.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Config {
+///     num_threads: usize,
+/// }
+///
+/// concurrent_static!(static CONFIG: Config;);
+///
+/// assert!(!CONFIG::is_initialized());
+/// assert_eq!(CONFIG::get(), None);
+///
+/// std::thread::scope(|s| {
+///     for i in 0..4 {
+///         s.spawn(move || _ = CONFIG::init(Config { num_threads: 4 + i }));
+///     }
+/// });
+///
+/// assert!(CONFIG::is_initialized());
+/// assert!(CONFIG::get().is_some_and(|x| (4..8).contains(&x.num_threads)));
+///
+/// // subsequent initializations are ignored
+/// let num_threads = CONFIG::get().map(|x| x.num_threads);
+/// assert!(!CONFIG::init(Config { num_threads: 42 }));
+/// assert_eq!(CONFIG::get().map(|x| x.num_threads), num_threads);
+/// ```
+///
+/// The underlying option cannot be reached to take or replace the value, even within the defining module.
+///
+/// ```compile_fail
+/// use orx_concurrent_option::*;
+///
+/// concurrent_static!(static NAME: String;);
+///
+/// assert!(NAME::init(3.to_string()));
+/// _ = NAME::cell().take();
+/// ```
+#[macro_export]
+macro_rules! concurrent_static {
+    ($(#[$attr:meta])* $vis:vis static $name:ident : $t:ty;) => {
+        $(#[$attr])*
+        #[allow(non_camel_case_types)]
+        $vis struct $name;
+
+        const _: () = {
+            // cannot be named outside of this block; hence, it is only accessed through the methods below
+            static CELL: $crate::ConcurrentOption<$t> = $crate::ConcurrentOption::none();
+
+            impl $name {
+                /// Initializes the global value if it is not yet initialized;
+                /// returns whether or not the value is initialized by this call.
+                #[allow(dead_code)]
+                $vis fn init(value: $t) -> bool {
+                    CELL.initialize_if_none(value)
+                }
+
+                /// Returns a reference to the global value if it is initialized; None otherwise.
+                #[allow(dead_code)]
+                $vis fn get() -> Option<&'static $t> {
+                    // the value is written at most once and never mutated afterwards
+                    unsafe { CELL.as_ref() }
+                }
+
+                /// Returns whether or not the global value is initialized.
+                #[allow(dead_code)]
+                $vis fn is_initialized() -> bool {
+                    CELL.is_some_with_order($crate::LoadOrdering::Acquire)
+                }
+            }
+        };
+    };
+}
+
+/// Creates a [`ConcurrentOption`] mirroring the way `Some(x)` and `None` literals are written.
+///
+/// * `copt!(x)` creates `ConcurrentOption::some(x)`,
+/// * `copt!(None)` creates `ConcurrentOption::none()`,
+/// * `copt![None; N]` creates an array of `N` concurrent options of None variant;
+///   this does not require `T: Clone`.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// let x = copt!(3.to_string());
+/// assert_eq!(x, ConcurrentOption::some(3.to_string()));
+///
+/// let x: ConcurrentOption<String> = copt!(None);
+/// assert_eq!(x, ConcurrentOption::none());
+///
+/// struct NotClone(usize);
+///
+/// let slots: [ConcurrentOption<NotClone>; 4] = copt![None; 4];
+/// assert!(slots.iter().all(|x| x.is_none()));
+///
+/// assert!(slots[2].initialize_if_none(NotClone(2)));
+/// assert_eq!(slots[2].map(|x| x.0), Some(2));
+/// ```
+#[macro_export]
+macro_rules! copt {
+    (None) => {
+        $crate::ConcurrentOption::none()
+    };
+    (None; $n:expr) => {
+        ::core::array::from_fn::<_, $n, _>(|_| $crate::ConcurrentOption::none())
+    };
+    ($value:expr) => {
+        $crate::ConcurrentOption::some($value)
+    };
+}
@@ -0,0 +1,127 @@
+use crate::handle::{Handle, ReadHandle};
+use core::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+
+/// A guard providing shared access to a part of the value of a [`ConcurrentOption`],
+/// created by [`ReadGuard::map`] or [`ReadGuard::try_map`].
+///
+/// The guard remains registered as a reader of the option while it is alive, exactly as the [`ReadGuard`] it is created from.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`ReadGuard`]: crate::ReadGuard
+/// [`ReadGuard::map`]: crate::ReadGuard::map
+/// [`ReadGuard::try_map`]: crate::ReadGuard::try_map
+pub struct MappedReadGuard<'a, U: ?Sized> {
+    pub(crate) _handle: ReadHandle<'a>,
+    pub(crate) value: &'a U,
+}
+
+impl<'a, U: ?Sized> MappedReadGuard<'a, U> {
+    /// Further projects the guard into a part of its value by the function `f`, keeping the registration as a reader.
+    pub fn map<V: ?Sized, F>(self, f: F) -> MappedReadGuard<'a, V>
+    where
+        F: FnOnce(&U) -> &V,
+    {
+        MappedReadGuard {
+            value: f(self.value),
+            _handle: self._handle,
+        }
+    }
+
+    /// Further projects the guard into a part of its value by the function `f`, keeping the registration as a reader;
+    /// returns back the guard as the error if `f` returns None.
+    pub fn try_map<V: ?Sized, F>(self, f: F) -> Result<MappedReadGuard<'a, V>, Self>
+    where
+        F: FnOnce(&U) -> Option<&V>,
+    {
+        match f(self.value) {
+            Some(value) => Ok(MappedReadGuard {
+                value,
+                _handle: self._handle,
+            }),
+            None => Err(self),
+        }
+    }
+}
+
+impl<U: ?Sized> Deref for MappedReadGuard<'_, U> {
+    type Target = U;
+
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<U: ?Sized + Debug> Debug for MappedReadGuard<'_, U> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("MappedReadGuard").field(&self.value).finish()
+    }
+}
+
+/// A guard providing exclusive mutable access to a part of the value of a [`ConcurrentOption`],
+/// created by [`WriteGuard::map`] or [`WriteGuard::try_map`].
+///
+/// The option remains reserved while the guard is alive, exactly as it is by the [`WriteGuard`] it is created from.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`WriteGuard`]: crate::WriteGuard
+/// [`WriteGuard::map`]: crate::WriteGuard::map
+/// [`WriteGuard::try_map`]: crate::WriteGuard::try_map
+pub struct MappedWriteGuard<'a, U: ?Sized> {
+    pub(crate) _handle: Handle<'a>,
+    pub(crate) value: &'a mut U,
+}
+
+impl<'a, U: ?Sized> MappedWriteGuard<'a, U> {
+    /// Further projects the guard into a part of its value by the function `f`, keeping the reservation.
+    pub fn map<V: ?Sized, F>(self, f: F) -> MappedWriteGuard<'a, V>
+    where
+        F: FnOnce(&mut U) -> &mut V,
+    {
+        MappedWriteGuard {
+            value: f(self.value),
+            _handle: self._handle,
+        }
+    }
+
+    /// Further projects the guard into a part of its value by the function `f`, keeping the reservation;
+    /// returns back the guard as the error if `f` returns None.
+    pub fn try_map<V: ?Sized, F>(self, f: F) -> Result<MappedWriteGuard<'a, V>, Self>
+    where
+        F: FnOnce(&mut U) -> Option<&mut V>,
+    {
+        let value: *mut U = self.value;
+        // SAFETY: the reborrow is released before the guard is returned back in the error case
+        match f(unsafe { &mut *value }) {
+            Some(value) => Ok(MappedWriteGuard {
+                value,
+                _handle: self._handle,
+            }),
+            None => Err(self),
+        }
+    }
+}
+
+impl<U: ?Sized> Deref for MappedWriteGuard<'_, U> {
+    type Target = U;
+
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<U: ?Sized> DerefMut for MappedWriteGuard<'_, U> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.value
+    }
+}
+
+impl<U: ?Sized + Debug> Debug for MappedWriteGuard<'_, U> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("MappedWriteGuard")
+            .field(&self.value)
+            .finish()
+    }
+}
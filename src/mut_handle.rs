@@ -1,13 +1,22 @@
-use crate::{states::*, ConcurrentOption};
+use crate::{
+    custom_state::{holds_value, is_custom},
+    parking::{notify, snooze},
+    states::*,
+    stats::ExitReason,
+    ConcurrentOption,
+};
 use core::{
     cell::UnsafeCell,
+    fmt::Debug,
     mem::MaybeUninit,
-    sync::atomic::{AtomicU8, Ordering},
+    ops::{Deref, DerefMut},
+    sync::atomic::Ordering,
 };
 
 /// Provides a mut-handle on the optional.
 pub struct MutHandle<'a, T> {
-    state: &'a AtomicU8,
+    option: &'a ConcurrentOption<T>,
+    initial_state: StateU8,
     success_state: StateU8,
     /// Provides direct access to the cell holding the data of the optional.
     pub value: &'a UnsafeCell<MaybeUninit<T>>,
@@ -19,22 +28,33 @@ impl<'a, T> MutHandle<'a, T> {
         initial_state: StateU8,
         success_state: StateU8,
     ) -> Option<Self> {
+        option.generation.assert_no_ref_tokens();
+        #[cfg(feature = "deadlock-detection")]
+        let mut watch = crate::deadlock::SpinWatch::new();
         loop {
             match option.state.compare_exchange(
                 initial_state,
                 RESERVED,
-                Ordering::Acquire,
+                Ordering::SeqCst,
                 Ordering::Relaxed,
             ) {
                 Ok(_) => {
+                    if initial_state == SOME {
+                        option.generation.wait_for_readers();
+                    }
                     return Some(Self {
-                        state: &option.state,
+                        option,
+                        initial_state,
                         success_state,
                         value: &option.value,
                     });
                 }
                 Err(previous_state) => match previous_state {
-                    RESERVED => continue,
+                    RESERVED => {
+                        #[cfg(feature = "deadlock-detection")]
+                        watch.on_spin();
+                        snooze(&option.state, RESERVED);
+                    }
                     _ => return None,
                 },
             }
@@ -48,21 +68,256 @@ impl<'a, T> MutHandle<'a, T> {
     /// This operation might lead to undefined behavior:
     /// * if we use it while other threads are accessing the data, or
     /// * if the optional `is_none` when we access the value.
+    #[allow(clippy::mut_from_ref)]
     pub unsafe fn get_mut(&self) -> &mut T {
         let x = unsafe { &mut *self.value.get() };
         unsafe { MaybeUninit::assume_init_mut(x) }
     }
-}
 
-impl<'a, T> Drop for MutHandle<'a, T> {
-    fn drop(&mut self) {
-        self.state
+    /// Sets the state the optional will be brought to once the handle is dropped,
+    /// replacing the `success_state` the handle is created with.
+    ///
+    /// This allows deciding on the final transition after inspecting the value, such as a conditional take.
+    ///
+    /// Further, the `success_state` must be consistent with the value; i.e., it can be `SOME` only if the value is initialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `success_state` is `Reserved`, `Frozen` or a `Custom` state which is not a valid custom state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3.to_string());
+    ///
+    /// // take the value only if it is "3"
+    /// let mut handle = unsafe { x.mut_handle(SOME, SOME) }.unwrap();
+    /// let taken = match unsafe { handle.get_mut() } == "3" {
+    ///     true => {
+    ///         handle.set_success_state(State::None);
+    ///         Some(unsafe { (*handle.value.get()).assume_init_read() })
+    ///     }
+    ///     false => None,
+    /// };
+    /// drop(handle);
+    ///
+    /// assert_eq!(taken, Some(3.to_string()));
+    /// assert!(x.is_none());
+    /// ```
+    #[allow(clippy::panic)]
+    pub fn set_success_state(&mut self, success_state: State) {
+        let error = match success_state {
+            State::Reserved => Some(InvalidTransition::ReservedSuccessState),
+            State::Frozen => Some(InvalidTransition::FrozenSuccessState),
+            State::Custom(x) if !is_custom(x) => Some(InvalidTransition::InvalidCustomState(x)),
+            _ => None,
+        };
+        if let Some(error) = error {
+            panic!("{}", error);
+        }
+        self.success_state = success_state.to_u8();
+    }
+
+    /// Releases the handle by restoring the optional back to the `initial_state` the handle is created with,
+    /// rather than bringing it to the success state.
+    ///
+    /// The generation of the optional is not incremented; hence, watchers do not observe a change.
+    /// Therefore, the caller must not have mutated the value through the handle, or must have reverted the mutation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state of the optional has been changed while the handle is alive, which is not possible through safe code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    ///
+    /// let handle = unsafe { x.mut_handle(SOME, NONE) }.unwrap();
+    /// assert_eq!(x.state(LoadOrdering::Acquire), State::Reserved);
+    /// handle.abort();
+    ///
+    /// assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+    /// assert_eq!(x.take(), Some(3));
+    /// ```
+    pub fn abort(self) {
+        let handle = core::mem::ManuallyDrop::new(self);
+        handle
+            .option
+            .state
             .compare_exchange(
                 RESERVED,
-                self.success_state,
-                Ordering::Release,
+                handle.initial_state,
+                ORDER_STORE,
                 Ordering::Relaxed,
             )
+            .expect("Failed to restore the concurrent state after aborting the mutation");
+        notify(&handle.option.state);
+    }
+
+    /// Converts the handle into a [`SomeMutHandle`] which provides safe access to the value through `Deref` and `DerefMut`;
+    /// returns back the handle as the error if the handle is not created with the `SOME` initial state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(vec![1]);
+    ///
+    /// let handle = unsafe { x.mut_handle(SOME, SOME) }.unwrap();
+    /// let mut handle = handle.into_some().unwrap();
+    /// handle.push(2);
+    /// drop(handle);
+    ///
+    /// assert_eq!(x.take(), Some(vec![1, 2]));
+    ///
+    /// let handle = unsafe { x.mut_handle(NONE, NONE) }.unwrap();
+    /// assert!(handle.into_some().is_err());
+    /// ```
+    pub fn into_some(self) -> Result<SomeMutHandle<'a, T>, Self> {
+        match self.initial_state {
+            SOME => Ok(SomeMutHandle { handle: self }),
+            _ => Err(self),
+        }
+    }
+}
+
+impl<T> Debug for MutHandle<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MutHandle")
+            .field("initial_state", &State::new(self.initial_state))
+            .field("success_state", &State::new(self.success_state))
+            .finish()
+    }
+}
+
+impl<'a, T> Drop for MutHandle<'a, T> {
+    fn drop(&mut self) {
+        let option = self.option;
+        if holds_value(self.initial_state) && !holds_value(self.success_state) {
+            option.record_exit(ExitReason::Taken);
+        }
+
+        option.generation.bump();
+        option
+            .state
+            .compare_exchange(RESERVED, self.success_state, ORDER_STORE, Ordering::Relaxed)
             .expect("Failed to update the concurrent state after concurrent state mutation");
+        notify(&option.state);
+        option
+            .generation
+            .notify_transition(self.initial_state, self.success_state);
+    }
+}
+
+/// Error of [`ConcurrentOption::mut_handle_typed`] describing why the requested transition is invalid.
+///
+/// [`ConcurrentOption::mut_handle_typed`]: crate::ConcurrentOption::mut_handle_typed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidTransition {
+    /// The initial state is RESERVED, which is owned by the handle holding the reservation.
+    ReservedInitialState,
+    /// The initial state is FROZEN, whose value can never be mutated through a shared reference.
+    FrozenInitialState,
+    /// The success state is RESERVED, which would leave the optional reserved forever.
+    ReservedSuccessState,
+    /// The success state is FROZEN; options are frozen only by [`ConcurrentOption::freeze`].
+    ///
+    /// [`ConcurrentOption::freeze`]: crate::ConcurrentOption::freeze
+    FrozenSuccessState,
+    /// The raw value of a [`State::Custom`] state is not a custom state; see [`CustomState`].
+    ///
+    /// [`CustomState`]: crate::CustomState
+    InvalidCustomState(StateU8),
+}
+
+impl core::fmt::Display for InvalidTransition {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ReservedInitialState => {
+                write!(f, "a mut handle cannot be created from the RESERVED state")
+            }
+            Self::FrozenInitialState => {
+                write!(f, "a mut handle cannot be created from the FROZEN state")
+            }
+            Self::ReservedSuccessState => {
+                write!(f, "a mut handle cannot release the optional as RESERVED")
+            }
+            Self::FrozenSuccessState => {
+                write!(f, "a mut handle cannot release the optional as FROZEN")
+            }
+            Self::InvalidCustomState(x) => write!(f, "{} is not a custom state", x),
+        }
+    }
+}
+
+impl InvalidTransition {
+    pub(crate) fn validate(
+        initial_state: State,
+        success_state: State,
+    ) -> Result<(StateU8, StateU8), Self> {
+        for state in [initial_state, success_state] {
+            if let State::Custom(x) = state {
+                if !is_custom(x) {
+                    return Err(Self::InvalidCustomState(x));
+                }
+            }
+        }
+        match (initial_state, success_state) {
+            (State::Reserved, _) => Err(Self::ReservedInitialState),
+            (State::Frozen, _) => Err(Self::FrozenInitialState),
+            (_, State::Reserved) => Err(Self::ReservedSuccessState),
+            (_, State::Frozen) => Err(Self::FrozenSuccessState),
+            (initial, success) => Ok((initial.to_u8(), success.to_u8())),
+        }
+    }
+}
+
+/// A mut-handle on an optional of Some variant; created by [`ConcurrentOption::some_mut_handle`] or [`MutHandle::into_some`].
+///
+/// Since the handle proves that the value is initialized, it provides safe access to the value through `Deref` and `DerefMut`,
+/// rather than the raw cell of the [`MutHandle`].
+///
+/// [`ConcurrentOption::some_mut_handle`]: crate::ConcurrentOption::some_mut_handle
+pub struct SomeMutHandle<'a, T> {
+    pub(crate) handle: MutHandle<'a, T>,
+}
+
+impl<'a, T> SomeMutHandle<'a, T> {
+    /// Releases the handle by restoring the optional back to the `SOME` state, rather than bringing it to the success state.
+    ///
+    /// See [`MutHandle::abort`] for details.
+    pub fn abort(self) {
+        self.handle.abort()
+    }
+
+    /// Converts back into the underlying [`MutHandle`], keeping the reservation.
+    pub fn into_handle(self) -> MutHandle<'a, T> {
+        self.handle
+    }
+}
+
+impl<T> Deref for SomeMutHandle<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { MaybeUninit::assume_init_ref(&*self.handle.value.get()) }
+    }
+}
+
+impl<T> DerefMut for SomeMutHandle<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { MaybeUninit::assume_init_mut(&mut *self.handle.value.get()) }
+    }
+}
+
+impl<T: Debug> Debug for SomeMutHandle<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SomeMutHandle").field(&**self).finish()
     }
 }
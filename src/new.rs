@@ -1,6 +1,8 @@
+use crate::atomic::AtomicU8;
 use crate::concurrent_option::ConcurrentOption;
+use crate::generation::Generation;
 use crate::states::*;
-use core::mem::MaybeUninit;
+use core::{cell::UnsafeCell, mem::MaybeUninit};
 
 impl<T> ConcurrentOption<T> {
     /// Creates a concurrent option of the Some variant with an existing value.
@@ -17,13 +19,38 @@ impl<T> ConcurrentOption<T> {
     /// assert!(x.is_some());
     /// assert!(!x.is_none());
     /// ```
-    pub fn some(value: T) -> Self {
+    pub const fn some(value: T) -> Self {
         Self {
-            value: MaybeUninit::new(value).into(),
-            state: SOME.into(),
+            value: UnsafeCell::new(MaybeUninit::new(value)),
+            state: AtomicU8::new(SOME),
+            generation: Generation::new(),
+            #[cfg(feature = "write-intent")]
+            num_pending_writers: crate::atomic::AtomicU16::new(0),
+            #[cfg(feature = "init-once")]
+            once_begun: AtomicU8::new(0),
+            #[cfg(feature = "stats")]
+            stats: crate::stats::Stats::new(),
         }
     }
 
+    /// Creates a concurrent option of the Some variant with a clone of the given `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let value = 3.to_string();
+    /// let x = ConcurrentOption::some_cloned(&value);
+    /// assert_eq!(x, ConcurrentOption::some(3.to_string()));
+    /// ```
+    pub fn some_cloned(value: &T) -> Self
+    where
+        T: Clone,
+    {
+        Self::some(value.clone())
+    }
+
     /// Creates a concurrent option of the None variant with a missing value.
     ///
     /// # Examples
@@ -43,12 +70,29 @@ impl<T> ConcurrentOption<T> {
     /// assert!(!x.is_some());
     /// assert!(x.is_none());
     /// ```
-    pub fn none() -> Self {
-        let value = MaybeUninit::uninit();
-        let value = unsafe { value.assume_init() };
+    ///
+    /// Since the constructor is `const`, it can be used to define statics.
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// static NAME: ConcurrentOption<String> = ConcurrentOption::none();
+    ///
+    /// assert!(NAME.is_none());
+    /// assert!(NAME.initialize_if_none(3.to_string()));
+    /// assert_eq!(NAME.map(|x| x.len()), Some(1));
+    /// ```
+    pub const fn none() -> Self {
         Self {
-            value,
-            state: NONE.into(),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+            state: AtomicU8::new(NONE),
+            generation: Generation::new(),
+            #[cfg(feature = "write-intent")]
+            num_pending_writers: crate::atomic::AtomicU16::new(0),
+            #[cfg(feature = "init-once")]
+            once_begun: AtomicU8::new(0),
+            #[cfg(feature = "stats")]
+            stats: crate::stats::Stats::new(),
         }
     }
 }
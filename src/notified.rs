@@ -0,0 +1,64 @@
+use crate::ConcurrentOption;
+use tokio::sync::futures::Notified;
+
+impl<T> ConcurrentOption<T> {
+    /// Returns a future which resolves once the option becomes Some, such as by `initialize_if_none` or `insert`.
+    ///
+    /// The future is registered as soon as it is created, rather than when it is first polled.
+    /// Therefore, a transition happening between creating the future and awaiting it is not missed;
+    /// which allows the check-then-wait pattern below without a race.
+    ///
+    /// Note that mutations keeping the option Some, such as `replace` on a Some variant, do not notify.
+    /// Further, the option might already be taken again once the future resolves, if there are other writers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use std::{future::Future, pin::pin, task::*};
+    ///
+    /// let x = ConcurrentOption::none();
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// // create the future before checking the option
+    /// let mut notified = pin!(x.notified_on_set());
+    /// assert!(x.is_none());
+    /// assert_eq!(notified.as_mut().poll(&mut cx), Poll::Pending);
+    ///
+    /// x.initialize_if_none(42);
+    /// assert_eq!(notified.as_mut().poll(&mut cx), Poll::Ready(()));
+    /// ```
+    pub fn notified_on_set(&self) -> Notified<'_> {
+        self.generation.on_set.notified()
+    }
+
+    /// Returns a future which resolves once the value of the option is taken out, such as by `take` or `take_if`,
+    /// leaving a None in its place.
+    ///
+    /// The future is registered as soon as it is created, rather than when it is first polled.
+    /// Therefore, a transition happening between creating the future and awaiting it is not missed;
+    /// which allows the check-then-wait pattern below without a race.
+    ///
+    /// Note that the option might already be Some again once the future resolves, if there are other writers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use std::{future::Future, pin::pin, task::*};
+    ///
+    /// let x = ConcurrentOption::some(3);
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// // create the future before checking the option
+    /// let mut notified = pin!(x.notified_on_take());
+    /// assert!(x.is_some());
+    /// assert_eq!(notified.as_mut().poll(&mut cx), Poll::Pending);
+    ///
+    /// assert_eq!(x.take(), Some(3));
+    /// assert_eq!(notified.as_mut().poll(&mut cx), Poll::Ready(()));
+    /// ```
+    pub fn notified_on_take(&self) -> Notified<'_> {
+        self.generation.on_take.notified()
+    }
+}
@@ -1,6 +1,9 @@
 use crate::{concurrent_option::ConcurrentOption, states::*, IntoOption};
 use core::sync::atomic::Ordering;
-use core::{mem::MaybeUninit, ops::Deref};
+use core::{
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+};
 
 impl<T> ConcurrentOption<T> {
     // &self
@@ -21,7 +24,7 @@ impl<T> ConcurrentOption<T> {
     /// ```
     #[inline]
     pub fn is_some(&self) -> bool {
-        self.state.load(Ordering::Relaxed) == SOME
+        matches!(self.state.load(Ordering::Relaxed), SOME | FROZEN)
     }
 
     /// Returns `true` if the option is a None variant.
@@ -39,11 +42,80 @@ impl<T> ConcurrentOption<T> {
     /// ```
     #[inline]
     pub fn is_none(&self) -> bool {
-        self.state.load(Ordering::Relaxed) != SOME
+        !matches!(self.state.load(Ordering::Relaxed), SOME | FROZEN)
+    }
+
+    /// Returns whether or not the option is a Some variant, waiting for an ongoing mutation to complete
+    /// for at most `max_spins` retries.
+    ///
+    /// Returns
+    /// * `Some(true)` if the option is observed to be of Some variant;
+    /// * `Some(false)` if the option is observed to be of None variant;
+    /// * `None` if the option is still reserved for a mutation after `max_spins` retries,
+    ///   in which case the state is indeterminate.
+    ///
+    /// Note that [`is_some`] reports a reserved option as not some immediately,
+    /// while this method gives the ongoing mutation a bounded chance to complete.
+    ///
+    /// [`is_some`]: ConcurrentOption::is_some
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x: ConcurrentOption<u32> = ConcurrentOption::some(2);
+    /// assert_eq!(x.is_some_eventually(16), Some(true));
+    ///
+    /// let x: ConcurrentOption<u32> = ConcurrentOption::none();
+    /// assert_eq!(x.is_some_eventually(16), Some(false));
+    /// ```
+    pub fn is_some_eventually(&self, max_spins: usize) -> Option<bool> {
+        for _ in 0..=max_spins {
+            match self.state.load(ORDER_LOAD) {
+                SOME | FROZEN => return Some(true),
+                RESERVED => core::hint::spin_loop(),
+                _ => return Some(false),
+            }
+        }
+        None
+    }
+
+    /// Returns an approximate answer to whether or not the option is a Some variant.
+    ///
+    /// The state is read by a single relaxed load without any fence or reservation, the same as [`is_some`].
+    /// The result might already be outdated once it is returned; and it does not synchronize with the writer of the value.
+    /// Therefore, it must only be used for heuristics where a stale answer is acceptable,
+    /// such as selecting a victim in work stealing; and never to decide whether the value can be accessed.
+    ///
+    /// [`is_some`]: ConcurrentOption::is_some
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let queues = [ConcurrentOption::none(), ConcurrentOption::some(42)];
+    ///
+    /// let victim = queues.iter().position(|q| q.is_probably_some());
+    /// assert_eq!(victim, Some(1));
+    ///
+    /// // the victim might have been emptied in the meantime; hence, the actual access handles None
+    /// assert_eq!(queues[1].take(), Some(42));
+    /// ```
+    #[inline(always)]
+    pub fn is_probably_some(&self) -> bool {
+        matches!(self.state.load(Ordering::Relaxed), SOME | FROZEN)
     }
 
     /// Partially thread safe method to convert from `&Option<T>` to `Option<&T>`.
     ///
+    /// Whenever the reference is only required within a scope, the safe [`with_ref`] method must be preferred.
+    /// Since `as_ref` is `unsafe`, its call sites can be flagged in reviews, for instance,
+    /// by the `clippy::undocumented_unsafe_blocks` lint.
+    ///
+    /// [`with_ref`]: ConcurrentOption::with_ref
+    ///
     /// # Safety
     ///
     /// Note that creating a valid reference part of this method is thread safe.
@@ -52,10 +124,10 @@ impl<T> ConcurrentOption<T> {
     ///
     /// * It is safe to use this method if the returned reference is discarded (miri would still complain).
     /// * It is also safe to use this method if the caller is able to guarantee that there exist
-    /// no concurrent writes while holding onto this reference.
+    ///   no concurrent writes while holding onto this reference.
     ///   * One such case is using `as_ref` together with `initialize_when_none` method.
-    /// This is perfectly safe since the value will be written only once,
-    /// and `as_ref` returns a valid reference only after the value is initialized.
+    ///     This is perfectly safe since the value will be written only once,
+    ///     and `as_ref` returns a valid reference only after the value is initialized.
     /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
     ///
     /// # Examples
@@ -70,7 +142,7 @@ impl<T> ConcurrentOption<T> {
     /// assert_eq!(unsafe { x.as_ref() }, None);
     /// ```
     pub unsafe fn as_ref(&self) -> Option<&T> {
-        match self.spin_get_handle(SOME, SOME) {
+        match self.spin_get_read_handle() {
             Some(_handle) => {
                 let x = &*self.value.get();
                 Some(x.assume_init_ref())
@@ -79,6 +151,36 @@ impl<T> ConcurrentOption<T> {
         }
     }
 
+    /// Thread safe method which calls `f` with a reference to the value (if any) and returns its result.
+    ///
+    /// This is the safe replacement of [`as_ref`] whenever the reference is only required within a scope.
+    /// The option is reserved while `f` is executed; and the reference cannot escape the closure.
+    /// Therefore, the value cannot be concurrently mutated while it is being accessed.
+    ///
+    /// `with_ref` behaves exactly like [`map`].
+    ///
+    /// [`as_ref`]: ConcurrentOption::as_ref
+    /// [`map`]: ConcurrentOption::map
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3.to_string());
+    /// assert_eq!(x.with_ref(|x| x.len()), Some(1));
+    /// assert_eq!(x.with_ref(|x| x == "3"), Some(true));
+    ///
+    /// _ = x.take();
+    /// assert_eq!(x.with_ref(|x| x.len()), None);
+    /// ```
+    pub fn with_ref<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.map(f)
+    }
+
     /// Partially thread safe method to convert from `Option<T>` (or `&Option<T>`) to `Option<&T::Target>`.
     ///
     /// Leaves the original Option in-place, creating a new one with a reference
@@ -92,10 +194,10 @@ impl<T> ConcurrentOption<T> {
     ///
     /// * It is safe to use this method if the returned reference is discarded (miri would still complain).
     /// * It is also safe to use this method if the caller is able to guarantee that there exist
-    /// no concurrent writes while holding onto this reference.
+    ///   no concurrent writes while holding onto this reference.
     ///   * One such case is using `as_ref` together with `initialize_when_none` method.
-    /// This is perfectly safe since the value will be written only once,
-    /// and `as_ref` returns a valid reference only after the value is initialized.
+    ///     This is perfectly safe since the value will be written only once,
+    ///     and `as_ref` returns a valid reference only after the value is initialized.
     /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
     ///
     /// # Examples
@@ -113,7 +215,7 @@ impl<T> ConcurrentOption<T> {
     where
         T: Deref,
     {
-        match self.spin_get_handle(SOME, SOME) {
+        match self.spin_get_read_handle() {
             Some(_handle) => {
                 let x = &*self.value.get();
                 Some(x.assume_init_ref())
@@ -122,6 +224,54 @@ impl<T> ConcurrentOption<T> {
         }
     }
 
+    /// Partially thread safe method to convert from `Option<T>` (or `&Option<T>`) to `Option<&mut T::Target>`.
+    ///
+    /// Leaves the original Option in-place, creating a new one containing a mutable reference to
+    /// the inner type's [`Deref::Target`] type.
+    ///
+    /// See [`exclusive_as_deref_mut`] for the safe counterpart requiring a `&mut self` reference.
+    ///
+    /// [`exclusive_as_deref_mut`]: ConcurrentOption::exclusive_as_deref_mut
+    ///
+    /// # Safety
+    ///
+    /// Note that creating a valid reference part of this method is thread safe.
+    ///
+    /// The method is `unsafe` due to the returned mutable reference to the underlying value.
+    ///
+    /// * It is safe to use this method if the returned mutable reference is discarded (miri would still complain).
+    /// * It is also safe to use this method if the caller is able to guarantee that there exist
+    ///   no concurrent reads or writes while holding onto this reference.
+    /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x: ConcurrentOption<String> = ConcurrentOption::some("hey".to_owned());
+    /// if let Some(x) = unsafe { x.as_deref_mut() } {
+    ///     x.make_ascii_uppercase();
+    /// }
+    /// assert_eq!(unsafe { x.as_deref() }, Some("HEY"));
+    ///
+    /// let x: ConcurrentOption<String> = ConcurrentOption::none();
+    /// assert_eq!(unsafe { x.as_deref_mut() }, None);
+    /// ```
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn as_deref_mut(&self) -> Option<&mut <T as Deref>::Target>
+    where
+        T: DerefMut,
+    {
+        match self.spin_get_write_handle(SOME, SOME) {
+            Some(_handle) => {
+                let x = &mut *self.value.get();
+                Some(x.assume_init_mut())
+            }
+            None => None,
+        }
+    }
+
     /// Partially thread safe method to return an iterator over the possibly contained value; yields
     /// * the single element if the option is of Some variant;
     /// * no elements otherwise.
@@ -134,10 +284,10 @@ impl<T> ConcurrentOption<T> {
     ///
     /// * It is safe to use this method if the returned reference is discarded (miri would still complain).
     /// * It is also safe to use this method if the caller is able to guarantee that there exist
-    /// no concurrent writes while holding onto this reference.
+    ///   no concurrent writes while holding onto this reference.
     ///   * One such case is using `as_ref` together with `initialize_when_none` method.
-    /// This is perfectly safe since the value will be written only once,
-    /// and `as_ref` returns a valid reference only after the value is initialized.
+    ///     This is perfectly safe since the value will be written only once,
+    ///     and `as_ref` returns a valid reference only after the value is initialized.
     /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
     ///
     /// # Examples
@@ -180,7 +330,7 @@ impl<T> ConcurrentOption<T> {
     where
         T: Clone,
     {
-        match self.spin_get_handle(SOME, SOME) {
+        match self.spin_get_read_handle() {
             Some(_handle) => {
                 let x = unsafe { &*self.value.get() };
                 Some(unsafe { x.assume_init_ref().clone() })
@@ -189,6 +339,56 @@ impl<T> ConcurrentOption<T> {
         }
     }
 
+    /// Thread safe method which returns a clone of the value if the option is of Some variant; None otherwise.
+    ///
+    /// This is equivalent to [`clone_into_option`].
+    ///
+    /// [`clone_into_option`]: ConcurrentOption::clone_into_option
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(12.to_string());
+    /// assert_eq!(x.get_cloned(), Some(12.to_string()));
+    ///
+    /// let x = ConcurrentOption::<String>::none();
+    /// assert_eq!(x.get_cloned(), None);
+    /// ```
+    pub fn get_cloned(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.clone_into_option()
+    }
+
+    /// Thread safe method which returns a copy of the value if the option is of Some variant; None otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(12);
+    /// assert_eq!(x.get_copied(), Some(12));
+    ///
+    /// let x = ConcurrentOption::<i32>::none();
+    /// assert_eq!(x.get_copied(), None);
+    /// ```
+    pub fn get_copied(&self) -> Option<T>
+    where
+        T: Copy,
+    {
+        match self.spin_get_read_handle() {
+            Some(_handle) => {
+                let x = unsafe { &*self.value.get() };
+                Some(unsafe { *x.assume_init_ref() })
+            }
+            None => None,
+        }
+    }
+
     /// Thread safe method to map the reference of the underlying value with the given function `f`.
     ///
     /// Returns
@@ -204,9 +404,9 @@ impl<T> ConcurrentOption<T> {
     /// Therefore, `map` must be preferred in a concurrent program:
     /// * the map operation via `map` guarantees that the underlying value will not be updated before the operation; while
     /// * the alternative approach with `as_ref` is subject to data race if the state of the optional is concurrently being
-    /// updated by methods such as `take`.
+    ///   updated by methods such as `take`.
     ///   * an exception to this is the `initialize_if_none` method which fits very well the initialize-once scenarios;
-    /// here, `as_ref` and `initialize_if_none` can safely be called concurrently from multiple threads.
+    ///     here, `as_ref` and `initialize_if_none` can safely be called concurrently from multiple threads.
     ///
     /// # Examples
     ///
@@ -225,7 +425,7 @@ impl<T> ConcurrentOption<T> {
     where
         F: FnOnce(&T) -> U,
     {
-        match self.spin_get_handle(SOME, SOME) {
+        match self.spin_get_read_handle() {
             Some(_handle) => {
                 let x = unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) };
                 Some(f(x))
@@ -258,7 +458,7 @@ impl<T> ConcurrentOption<T> {
     where
         F: FnOnce(&T) -> U,
     {
-        match self.spin_get_handle(SOME, SOME) {
+        match self.spin_get_read_handle() {
             Some(_handle) => {
                 let x = unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) };
                 f(x)
@@ -288,7 +488,131 @@ impl<T> ConcurrentOption<T> {
         D: FnOnce() -> U,
         F: FnOnce(&T) -> U,
     {
-        match self.spin_get_handle(SOME, SOME) {
+        match self.spin_get_read_handle() {
+            Some(_handle) => {
+                let x = unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) };
+                f(x)
+            }
+            None => default(),
+        }
+    }
+
+    /// Maps the contained value with `f` (if any), or returns the [default value]
+    /// of the result type `U` (if none).
+    ///
+    /// The function `f` is evaluated while holding a read handle on the option;
+    /// hence, the value cannot be mutated concurrently during the computation, while other readers can proceed.
+    ///
+    /// [default value]: Default::default
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some("foo");
+    /// assert_eq!(x.map_or_default(|v| v.len()), 3);
+    ///
+    /// let x: ConcurrentOption<&str> = ConcurrentOption::none();
+    /// assert_eq!(x.map_or_default(|v| v.len()), 0);
+    /// ```
+    pub fn map_or_default<U, F>(&self, f: F) -> U
+    where
+        U: Default,
+        F: FnOnce(&T) -> U,
+    {
+        match self.spin_get_read_handle() {
+            Some(_handle) => {
+                let x = unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) };
+                f(x)
+            }
+            None => U::default(),
+        }
+    }
+
+    /// Returns the provided `default` reference (if none),
+    /// or applies a function to the contained value which returns a reference (if any).
+    ///
+    /// Neither the value nor the default is cloned; hence, this method allows for zero-copy read paths.
+    ///
+    /// # Safety
+    ///
+    /// Note that creating a valid reference part of this method is thread safe.
+    ///
+    /// The method is `unsafe` due to the returned reference which might point to the underlying value.
+    ///
+    /// * It is safe to use this method if the returned reference is discarded (miri would still complain).
+    /// * It is also safe to use this method if the caller is able to guarantee that there exist
+    ///   no concurrent writes while holding onto this reference.
+    ///   * One such case is using `map_ref_or` together with `initialize_when_none` method.
+    ///     This is perfectly safe since the value will be written only once,
+    ///     and `map_ref_or` returns a reference into the value only after the value is initialized.
+    /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some((1, "foo".to_string()));
+    /// assert_eq!(unsafe { x.map_ref_or("bar", |v| v.1.as_str()) }, "foo");
+    ///
+    /// let x = ConcurrentOption::<(i32, String)>::none();
+    /// assert_eq!(unsafe { x.map_ref_or("bar", |v| v.1.as_str()) }, "bar");
+    /// ```
+    pub unsafe fn map_ref_or<'a, U, F>(&'a self, default: &'a U, f: F) -> &'a U
+    where
+        U: ?Sized,
+        F: FnOnce(&'a T) -> &'a U,
+    {
+        match self.spin_get_read_handle() {
+            Some(_handle) => {
+                let x = unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) };
+                f(x)
+            }
+            None => default,
+        }
+    }
+
+    /// Computes a default function returning a reference (if none),
+    /// or applies a different function to the contained value which returns a reference (if any).
+    ///
+    /// Neither the value nor the default is cloned; hence, this method allows for zero-copy read paths.
+    ///
+    /// # Safety
+    ///
+    /// Note that creating a valid reference part of this method is thread safe.
+    ///
+    /// The method is `unsafe` due to the returned reference which might point to the underlying value.
+    ///
+    /// * It is safe to use this method if the returned reference is discarded (miri would still complain).
+    /// * It is also safe to use this method if the caller is able to guarantee that there exist
+    ///   no concurrent writes while holding onto this reference.
+    ///   * One such case is using `map_ref_or_else` together with `initialize_when_none` method.
+    ///     This is perfectly safe since the value will be written only once,
+    ///     and `map_ref_or_else` returns a reference into the value only after the value is initialized.
+    /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let fallback = vec![7, 8];
+    ///
+    /// let x = ConcurrentOption::some(vec![1, 2, 3]);
+    /// assert_eq!(unsafe { x.map_ref_or_else(|| &fallback[..], |v| &v[1..]) }, &[2, 3]);
+    ///
+    /// let x = ConcurrentOption::<Vec<i32>>::none();
+    /// assert_eq!(unsafe { x.map_ref_or_else(|| &fallback[..], |v| &v[1..]) }, &[7, 8]);
+    /// ```
+    pub unsafe fn map_ref_or_else<'a, U, D, F>(&'a self, default: D, f: F) -> &'a U
+    where
+        U: ?Sized,
+        D: FnOnce() -> &'a U,
+        F: FnOnce(&'a T) -> &'a U,
+    {
+        match self.spin_get_read_handle() {
             Some(_handle) => {
                 let x = unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) };
                 f(x)
@@ -315,7 +639,7 @@ impl<T> ConcurrentOption<T> {
     /// ```
     #[inline]
     pub fn is_some_and(&self, f: impl FnOnce(&T) -> bool) -> bool {
-        match self.spin_get_handle(SOME, SOME) {
+        match self.spin_get_read_handle() {
             Some(_handle) => {
                 let x = unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) };
                 f(x)
@@ -324,6 +648,93 @@ impl<T> ConcurrentOption<T> {
         }
     }
 
+    /// Returns `true` if the option is None or the value inside of it matches a predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(2);
+    /// assert_eq!(x.is_none_or(|x| *x > 1), true);
+    ///
+    /// let x = ConcurrentOption::some(0);
+    /// assert_eq!(x.is_none_or(|x| *x > 1), false);
+    ///
+    /// let x: ConcurrentOption<i32> = ConcurrentOption::none();
+    /// assert_eq!(x.is_none_or(|x| *x > 1), true);
+    /// ```
+    #[inline]
+    pub fn is_none_or(&self, f: impl FnOnce(&T) -> bool) -> bool {
+        match self.spin_get_read_handle() {
+            Some(_handle) => {
+                let x = unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) };
+                f(x)
+            }
+            None => true,
+        }
+    }
+
+    /// Calls `f` with a reference to the value if the option is of Some variant; and returns back the option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut visited = vec![];
+    ///
+    /// let x = ConcurrentOption::some(4);
+    /// assert!(x.inspect(|x| visited.push(*x)).is_some());
+    ///
+    /// let x: ConcurrentOption<i32> = ConcurrentOption::none();
+    /// assert!(x.inspect(|x| visited.push(*x)).is_none());
+    ///
+    /// assert_eq!(visited, [4]);
+    /// ```
+    pub fn inspect<F>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&T),
+    {
+        if let Some(_handle) = self.spin_get_read_handle() {
+            let x = unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) };
+            f(x);
+        }
+        self
+    }
+
+    /// Partially thread safe method to return a slice of the contained value, if any.
+    /// If this is None, an empty slice is returned.
+    ///
+    /// # Safety
+    ///
+    /// Note that creating a valid slice part of this method is thread safe.
+    ///
+    /// The method is `unsafe` due to the returned slice referencing the underlying value.
+    ///
+    /// * It is safe to use this method if the returned slice is discarded (miri would still complain).
+    /// * It is also safe to use this method if the caller is able to guarantee that there exist
+    ///   no concurrent writes while holding onto this slice.
+    /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(1234);
+    /// assert_eq!(unsafe { x.as_slice() }, &[1234]);
+    ///
+    /// let x = ConcurrentOption::<i32>::none();
+    /// assert_eq!(unsafe { x.as_slice() }, &[]);
+    /// ```
+    pub unsafe fn as_slice(&self) -> &[T] {
+        match unsafe { self.as_ref() } {
+            Some(x) => core::slice::from_ref(x),
+            None => &[],
+        }
+    }
+
     /// Returns None if the option is None, otherwise returns `other`.
     ///
     /// Arguments passed to `and` are eagerly evaluated; if you are passing the
@@ -398,7 +809,7 @@ impl<T> ConcurrentOption<T> {
         V: IntoOption<U>,
         F: FnOnce(&T) -> V,
     {
-        match self.spin_get_handle(SOME, SOME) {
+        match self.spin_get_read_handle() {
             Some(_handle) => {
                 let x = unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) };
                 f(x).into_option()
@@ -426,10 +837,10 @@ impl<T> ConcurrentOption<T> {
     ///
     /// * It is safe to use this method if the returned reference is discarded (miri would still complain).
     /// * It is also safe to use this method if the caller is able to guarantee that there exist
-    /// no concurrent writes while holding onto this reference.
+    ///   no concurrent writes while holding onto this reference.
     ///   * One such case is using `as_ref` together with `initialize_when_none` method.
-    /// This is perfectly safe since the value will be written only once,
-    /// and `as_ref` returns a valid reference only after the value is initialized.
+    ///     This is perfectly safe since the value will be written only once,
+    ///     and `as_ref` returns a valid reference only after the value is initialized.
     /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
     ///
     /// # Examples
@@ -451,7 +862,7 @@ impl<T> ConcurrentOption<T> {
     where
         P: FnOnce(&T) -> bool,
     {
-        match self.spin_get_handle(SOME, SOME) {
+        match self.spin_get_read_handle() {
             Some(_handle) => {
                 let x = unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) };
                 match predicate(x) {
@@ -509,6 +920,157 @@ impl<T> ConcurrentOption<&T> {
     {
         self.exclusive_take().copied()
     }
+    /// Thread safe method to map a `&ConcurrentOption<&T>` to an `Option<T>` by cloning the contents of the
+    /// option.
+    ///
+    /// Unlike [`cloned`], this method does not consume the option;
+    /// the value is cloned while holding a read handle on the option.
+    ///
+    /// [`cloned`]: ConcurrentOption::cloned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = 12;
+    /// let opt_x = ConcurrentOption::some(&x);
+    ///
+    /// let cloned = opt_x.cloned_ref();
+    /// assert_eq!(cloned, Some(12));
+    /// assert_eq!(unsafe { opt_x.as_ref() }, Some(&&12));
+    /// ```
+    pub fn cloned_ref(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.map(|x| (*x).clone())
+    }
+
+    /// Thread safe method to map a `&ConcurrentOption<&T>` to an `Option<T>` by copying the contents of the
+    /// option.
+    ///
+    /// Unlike [`copied`], this method does not consume the option;
+    /// the value is copied while holding a read handle on the option.
+    ///
+    /// [`copied`]: ConcurrentOption::copied
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = 12;
+    /// let opt_x = ConcurrentOption::some(&x);
+    ///
+    /// let copied = opt_x.copied_ref();
+    /// assert_eq!(copied, Some(12));
+    /// assert_eq!(unsafe { opt_x.as_ref() }, Some(&&12));
+    /// ```
+    pub fn copied_ref(&self) -> Option<T>
+    where
+        T: Copy,
+    {
+        self.map(|x| **x)
+    }
+}
+
+impl<T> ConcurrentOption<&mut T> {
+    /// Maps an `ConcurrentOption<&mut T>` to an `Option<T>` by cloning the contents of the
+    /// option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut x = 12;
+    /// let opt_x = ConcurrentOption::some(&mut x);
+    ///
+    /// let cloned = opt_x.cloned();
+    /// assert_eq!(cloned, Some(12));
+    /// ```
+    pub fn cloned(mut self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.exclusive_take().cloned()
+    }
+
+    /// Maps an `ConcurrentOption<&mut T>` to an `Option<T>` by copying the contents of the
+    /// option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut x = 12;
+    /// let opt_x = ConcurrentOption::some(&mut x);
+    ///
+    /// let copied = opt_x.copied();
+    /// assert_eq!(copied, Some(12));
+    /// ```
+    pub fn copied(mut self) -> Option<T>
+    where
+        T: Copy,
+    {
+        self.exclusive_take().copied()
+    }
+
+    /// Thread safe method to map a `&ConcurrentOption<&mut T>` to an `Option<T>` by cloning the contents of the
+    /// option.
+    ///
+    /// Unlike [`cloned`], this method does not consume the option;
+    /// the value is cloned while holding a read handle on the option.
+    ///
+    /// [`cloned`]: ConcurrentOption::cloned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut x = 12;
+    /// let opt_x = ConcurrentOption::some(&mut x);
+    ///
+    /// let cloned = opt_x.cloned_ref();
+    /// assert_eq!(cloned, Some(12));
+    /// assert!(opt_x.is_some());
+    /// ```
+    pub fn cloned_ref(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.map(|x| (**x).clone())
+    }
+
+    /// Thread safe method to map a `&ConcurrentOption<&mut T>` to an `Option<T>` by copying the contents of the
+    /// option.
+    ///
+    /// Unlike [`copied`], this method does not consume the option;
+    /// the value is copied while holding a read handle on the option.
+    ///
+    /// [`copied`]: ConcurrentOption::copied
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_option::*;
+    ///
+    /// let mut x = 12;
+    /// let opt_x = ConcurrentOption::some(&mut x);
+    ///
+    /// let copied = opt_x.copied_ref();
+    /// assert_eq!(copied, Some(12));
+    /// assert!(opt_x.is_some());
+    /// ```
+    pub fn copied_ref(&self) -> Option<T>
+    where
+        T: Copy,
+    {
+        self.map(|x| **x)
+    }
 }
 
 impl<T> ConcurrentOption<ConcurrentOption<T>> {
@@ -0,0 +1,82 @@
+use core::sync::atomic::Ordering;
+
+/// Memory ordering which is valid for loading the state of a [`ConcurrentOption`];
+/// i.e., one of `Relaxed`, `Acquire` or `SeqCst`.
+///
+/// The **with_order** methods of the concurrent option accept a `LoadOrdering` rather than a raw `Ordering`,
+/// which turns the invalid orderings for loads, `Release` and `AcqRel`, into compile-time errors.
+///
+/// A raw `Ordering` can be converted by `try_from`, or by the unsafe [`from_raw_unchecked`] escape hatch.
+/// Further, each **with_order** method has an unsafe **with_raw_order** counterpart accepting a raw `Ordering`,
+/// such as `is_some_with_raw_order`.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`from_raw_unchecked`]: LoadOrdering::from_raw_unchecked
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+/// use core::sync::atomic::Ordering;
+///
+/// let x = ConcurrentOption::some(3);
+/// assert!(x.is_some_with_order(LoadOrdering::Acquire));
+///
+/// let order = LoadOrdering::try_from(Ordering::SeqCst).unwrap();
+/// assert!(x.is_some_with_order(order));
+///
+/// assert!(LoadOrdering::try_from(Ordering::Release).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LoadOrdering(Ordering);
+
+#[allow(non_upper_case_globals)]
+impl LoadOrdering {
+    /// `Relaxed` ordering for loads.
+    pub const Relaxed: Self = Self(Ordering::Relaxed);
+
+    /// `Acquire` ordering for loads.
+    pub const Acquire: Self = Self(Ordering::Acquire);
+
+    /// `SeqCst` ordering for loads.
+    pub const SeqCst: Self = Self(Ordering::SeqCst);
+
+    /// Creates a load ordering from the raw `order` without checking whether or not it is valid for loads.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `order` is one of `Relaxed`, `Acquire` or `SeqCst`.
+    /// Otherwise, the **with_order** methods receiving the ordering will panic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    /// let order = unsafe { LoadOrdering::from_raw_unchecked(Ordering::Acquire) };
+    /// assert!(x.is_some_with_order(order));
+    /// ```
+    pub const unsafe fn from_raw_unchecked(order: Ordering) -> Self {
+        Self(order)
+    }
+}
+
+impl From<LoadOrdering> for Ordering {
+    fn from(value: LoadOrdering) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<Ordering> for LoadOrdering {
+    type Error = Ordering;
+
+    /// Converts the raw `order` into a load ordering; returns back the `order` as the error if it is not valid for loads.
+    fn try_from(order: Ordering) -> Result<Self, Self::Error> {
+        match order {
+            Ordering::Relaxed | Ordering::Acquire | Ordering::SeqCst => Ok(Self(order)),
+            _ => Err(order),
+        }
+    }
+}
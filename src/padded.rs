@@ -0,0 +1,228 @@
+use crate::ConcurrentOption;
+use core::{
+    fmt::Debug,
+    marker::PhantomData,
+    mem::{align_of, size_of},
+    ops::Index,
+    ptr::NonNull,
+};
+use std::{
+    alloc::{alloc, dealloc, handle_alloc_error, Layout},
+    sync::OnceLock,
+};
+
+/// Size of the destructive interference, i.e., the cache line size, assumed when it cannot be detected at runtime.
+///
+/// It follows the prefetching granularity of the target architecture rather than its nominal cache line size;
+/// for instance, 128 bytes on x86_64 where adjacent cache lines are prefetched in pairs, and on aarch64.
+pub const DEFAULT_CACHE_LINE_SIZE: usize = if cfg!(any(
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "powerpc64",
+)) {
+    128
+} else if cfg!(any(
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "riscv32",
+    target_arch = "riscv64",
+)) {
+    32
+} else if cfg!(target_arch = "s390x") {
+    256
+} else {
+    64
+};
+
+static CACHE_LINE_SIZE: OnceLock<usize> = OnceLock::new();
+
+/// Returns the size of the destructive interference, i.e., the cache line size, of the machine in bytes.
+///
+/// The size is detected once at runtime where the platform exposes it, such as through sysfs on Linux;
+/// and [`DEFAULT_CACHE_LINE_SIZE`] of the target architecture is used otherwise.
+/// The result is always a power of two.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// let line = cache_line_size();
+/// assert!(line.is_power_of_two());
+/// ```
+pub fn cache_line_size() -> usize {
+    *CACHE_LINE_SIZE.get_or_init(|| detect_cache_line_size().unwrap_or(DEFAULT_CACHE_LINE_SIZE))
+}
+
+fn detect_cache_line_size() -> Option<usize> {
+    #[cfg(target_os = "linux")]
+    {
+        let path = "/sys/devices/system/cpu/cpu0/cache/index0/coherency_line_size";
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|x| x.trim().parse::<usize>().ok())
+            .filter(|x| x.is_power_of_two())
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    None
+}
+
+/// A fixed-length sequence of [`ConcurrentOption`]s each of which is padded to the [`cache_line_size`] detected at runtime;
+/// created by [`ConcurrentOption::padded_vec`].
+///
+/// Each option starts at a cache line boundary and no two options share a cache line, which prevents false sharing
+/// between threads working on neighboring slots. Unlike [`Aligned`] payloads which fix the alignment at compile time,
+/// the padding adapts to the machine the program runs on.
+///
+/// Since the stride between the options is not known at compile time, the options cannot be viewed as a slice;
+/// they are accessed by indexing or by [`iter`] instead.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`ConcurrentOption::padded_vec`]: crate::ConcurrentOption::padded_vec
+/// [`Aligned`]: crate::Aligned
+/// [`iter`]: PaddedSlots::iter
+pub struct PaddedSlots<T> {
+    ptr: NonNull<u8>,
+    len: usize,
+    stride: usize,
+    align: usize,
+    phantom: PhantomData<ConcurrentOption<T>>,
+}
+
+unsafe impl<T: Send> Send for PaddedSlots<T> {}
+
+#[cfg(not(orx_concurrent_option_single_thread))]
+unsafe impl<T: Sync> Sync for PaddedSlots<T> {}
+
+impl<T> PaddedSlots<T> {
+    fn new(len: usize) -> Self {
+        let line = cache_line_size();
+        let align = line.max(align_of::<ConcurrentOption<T>>());
+        let stride = size_of::<ConcurrentOption<T>>().next_multiple_of(align);
+
+        let ptr = match len {
+            0 => NonNull::<u8>::dangling(),
+            _ => {
+                let layout = Self::layout(len, stride, align);
+                let ptr = unsafe { alloc(layout) };
+                let ptr = NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout));
+                for i in 0..len {
+                    let slot = unsafe { ptr.as_ptr().add(i * stride) } as *mut ConcurrentOption<T>;
+                    unsafe { slot.write(ConcurrentOption::none()) };
+                }
+                ptr
+            }
+        };
+
+        Self {
+            ptr,
+            len,
+            stride,
+            align,
+            phantom: PhantomData,
+        }
+    }
+
+    #[allow(clippy::expect_used, clippy::missing_panics_doc)]
+    fn layout(len: usize, stride: usize, align: usize) -> Layout {
+        let size = stride.checked_mul(len).expect("capacity overflow");
+        Layout::from_size_align(size, align).expect("cache line size is a power of two")
+    }
+
+    /// Returns the number of options.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no options.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the distance in bytes between the starts of two consecutive options;
+    /// which is a multiple of the [`cache_line_size`].
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Returns a reference to the option at the given `index`; None if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&ConcurrentOption<T>> {
+        match index < self.len {
+            true => {
+                let slot = unsafe { self.ptr.as_ptr().add(index * self.stride) };
+                Some(unsafe { &*(slot as *const ConcurrentOption<T>) })
+            }
+            false => None,
+        }
+    }
+
+    /// Returns an iterator over the options.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &ConcurrentOption<T>> {
+        (0..self.len).map(|i| &self[i])
+    }
+}
+
+impl<T> Index<usize> for PaddedSlots<T> {
+    type Output = ConcurrentOption<T>;
+
+    #[allow(clippy::panic)]
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(x) => x,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len, index
+            ),
+        }
+    }
+}
+
+impl<T> Drop for PaddedSlots<T> {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            for i in 0..self.len {
+                let slot = unsafe { self.ptr.as_ptr().add(i * self.stride) };
+                unsafe { (slot as *mut ConcurrentOption<T>).drop_in_place() };
+            }
+            let layout = Self::layout(self.len, self.stride, self.align);
+            unsafe { dealloc(self.ptr.as_ptr(), layout) };
+        }
+    }
+}
+
+impl<T: Debug> Debug for PaddedSlots<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Creates `len` options of None variant, each of which is padded to the [`cache_line_size`] detected at runtime.
+    ///
+    /// See [`PaddedSlots`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let slots = ConcurrentOption::<u64>::padded_vec(4);
+    /// assert_eq!(slots.len(), 4);
+    /// assert_eq!(slots.stride() % cache_line_size(), 0);
+    ///
+    /// std::thread::scope(|s| {
+    ///     for i in 0..4 {
+    ///         let slots = &slots;
+    ///         s.spawn(move || _ = slots[i].initialize_if_none(i as u64));
+    ///     }
+    /// });
+    ///
+    /// let values: Vec<_> = slots.iter().map(|x| x.get_cloned()).collect();
+    /// assert_eq!(values, [Some(0), Some(1), Some(2), Some(3)]);
+    /// ```
+    pub fn padded_vec(len: usize) -> PaddedSlots<T> {
+        PaddedSlots::new(len)
+    }
+}
@@ -0,0 +1,257 @@
+//! Waiting on the state of a concurrent option.
+//!
+//! By default, threads waiting for a reservation to be released spin on the state.
+//!
+//! When the `futex` feature is enabled, they spin for a short while and then sleep in the kernel by the
+//! futex, ulock or WaitOnAddress primitive of the platform until the state is changed. Since the state is
+//! a single byte while these primitives wait on 32-bit words, the states are mapped to a fixed table of
+//! sequence numbers by their addresses. Every release of a state increments the sequence number of its
+//! bucket and wakes the waiters of the bucket, if any. Options sharing a bucket might cause spurious wake-ups,
+//! which are harmless since the waiters re-check the state. Waiting never allocates and the options carry no
+//! waiter queues; hence, this is the backend of choice for programs embedding very many options.
+//!
+//! When the `parking_lot` feature is enabled instead, they spin for a short while and then park in the global
+//! parking lot of `parking_lot_core`, keyed by the address of the state, until the state is released.
+//! A fixed table of waiter counters, again indexed by the addresses, lets releases skip the parking lot
+//! when nobody waits. If both features are enabled, the `futex` feature takes precedence.
+//!
+//! Without either of these features, threads blocked in the waiting methods, such as `wait_for_some`, park after
+//! spinning and yielding for a while, and are unparked precisely by the release of the state they wait on;
+//! see the `waiters` module for details. With the `async` feature, the same lists hold the wakers of the
+//! pending futures waiting on the states.
+//!
+//! Writers waiting for the shared readers of the value to leave, on the other hand, always spin.
+//! Further, threads waiting for a reservation keep spinning when the `deadlock-detection` feature is enabled,
+//! since stuck waits are detected by observing the spins.
+
+use crate::{atomic::AtomicU8, states::StateU8};
+
+/// Waits for the state to be changed from `current`; might return spuriously.
+///
+/// This is the backoff step of the loops which retry while the option is reserved by another thread.
+#[inline(always)]
+pub(crate) fn snooze(state: &AtomicU8, current: StateU8) {
+    #[cfg(all(feature = "futex", not(feature = "deadlock-detection")))]
+    futex::snooze(state, current);
+
+    #[cfg(all(
+        feature = "parking_lot",
+        not(feature = "futex"),
+        not(feature = "deadlock-detection")
+    ))]
+    lot::snooze(state, current);
+
+    #[cfg(not(all(
+        any(feature = "futex", feature = "parking_lot"),
+        not(feature = "deadlock-detection")
+    )))]
+    {
+        let _ = (state, current);
+        core::hint::spin_loop();
+    }
+}
+
+/// Waits for the state to be changed from `current` without spinning first; might return spuriously.
+///
+/// The thread does not wait if `done` returns true once it is announced as a waiter; hence, a condition which
+/// is signaled by a [`notify`] call on the `state`, such as a cancellation, is never missed.
+#[cfg(feature = "std")]
+#[inline(always)]
+pub(crate) fn park<F>(state: &AtomicU8, current: StateU8, done: &mut F)
+where
+    F: FnMut() -> bool,
+{
+    #[cfg(feature = "futex")]
+    futex::park(state, current, done);
+
+    #[cfg(all(feature = "parking_lot", not(feature = "futex")))]
+    lot::park(state, current, done);
+
+    #[cfg(not(any(feature = "futex", feature = "parking_lot")))]
+    crate::waiters::park(state, current, done);
+}
+
+/// Returns the number of threads parked on the `state`, and with the `async` feature, the number of tasks
+/// registered on the `state`.
+///
+/// With the `futex` or `parking_lot` feature, the threads are counted per bucket of states; hence, the count
+/// might include the threads waiting on other states sharing the bucket.
+#[cfg(feature = "std")]
+pub(crate) fn waiter_count(state: &AtomicU8) -> usize {
+    #[allow(unused_mut)]
+    let mut num_waiters = 0;
+
+    #[cfg(feature = "futex")]
+    {
+        num_waiters += futex::waiter_count(state);
+    }
+
+    #[cfg(all(feature = "parking_lot", not(feature = "futex")))]
+    {
+        num_waiters += lot::waiter_count(state);
+    }
+
+    #[cfg(any(
+        all(feature = "std", not(feature = "futex"), not(feature = "parking_lot")),
+        feature = "async"
+    ))]
+    {
+        num_waiters += crate::waiters::waiter_count(state);
+    }
+
+    num_waiters
+}
+
+/// Wakes up the threads waiting on the `state`; must be called right after the state is released or changed
+/// by a sequentially consistent operation.
+///
+/// The waiters announce themselves before they check the state and park; hence, the release only loads
+/// the announcement of its bucket when nobody waits, and the sequentially consistent release guarantees that
+/// either the waiter observes the new state or the release observes the waiter.
+#[inline(always)]
+pub(crate) fn notify(state: &AtomicU8) {
+    #[cfg(feature = "futex")]
+    futex::notify(state);
+
+    #[cfg(all(feature = "parking_lot", not(feature = "futex")))]
+    lot::notify(state);
+
+    #[cfg(any(
+        all(feature = "std", not(feature = "futex"), not(feature = "parking_lot")),
+        feature = "async"
+    ))]
+    crate::waiters::notify(state);
+
+    #[cfg(not(feature = "std"))]
+    let _ = state;
+}
+
+#[cfg(feature = "futex")]
+mod futex {
+    use crate::{atomic::AtomicU8, states::StateU8};
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    #[cfg(not(feature = "deadlock-detection"))]
+    const NUM_SPINS: usize = 128;
+    const NUM_BUCKETS: usize = 64;
+
+    struct Bucket {
+        sequence: AtomicU32,
+        num_waiters: AtomicU32,
+    }
+
+    #[allow(clippy::declare_interior_mutable_const)]
+    const EMPTY_BUCKET: Bucket = Bucket {
+        sequence: AtomicU32::new(0),
+        num_waiters: AtomicU32::new(0),
+    };
+
+    static BUCKETS: [Bucket; NUM_BUCKETS] = [EMPTY_BUCKET; NUM_BUCKETS];
+
+    fn bucket(state: &AtomicU8) -> &'static Bucket {
+        let address = state as *const AtomicU8 as usize;
+        let hash = address.wrapping_mul(0x9E37_79B9) >> 16;
+        &BUCKETS[hash % NUM_BUCKETS]
+    }
+
+    #[cfg(not(feature = "deadlock-detection"))]
+    pub(super) fn snooze(state: &AtomicU8, current: StateU8) {
+        for _ in 0..NUM_SPINS {
+            match state.load(Ordering::Relaxed) == current {
+                true => core::hint::spin_loop(),
+                false => return,
+            }
+        }
+        park(state, current, &mut || false);
+    }
+
+    pub(super) fn park<F>(state: &AtomicU8, current: StateU8, done: &mut F)
+    where
+        F: FnMut() -> bool,
+    {
+        let bucket = bucket(state);
+        bucket.num_waiters.fetch_add(1, Ordering::SeqCst);
+        let sequence = bucket.sequence.load(Ordering::SeqCst);
+        // either the release is observed here, or it increments the sequence after it is loaded
+        if state.load(Ordering::SeqCst) == current && !done() {
+            atomic_wait::wait(&bucket.sequence, sequence);
+        }
+        bucket.num_waiters.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn waiter_count(state: &AtomicU8) -> usize {
+        bucket(state).num_waiters.load(Ordering::SeqCst) as usize
+    }
+
+    pub(super) fn notify(state: &AtomicU8) {
+        let bucket = bucket(state);
+        // a waiter which is not observed here observes the release when it checks the state
+        if bucket.num_waiters.load(Ordering::SeqCst) > 0 {
+            bucket.sequence.fetch_add(1, Ordering::SeqCst);
+            atomic_wait::wake_all(&bucket.sequence);
+        }
+    }
+}
+
+#[cfg(all(feature = "parking_lot", not(feature = "futex")))]
+mod lot {
+    use crate::{atomic::AtomicU8, states::StateU8};
+    use core::sync::atomic::{AtomicU32, Ordering};
+    use parking_lot_core::{DEFAULT_PARK_TOKEN, DEFAULT_UNPARK_TOKEN};
+
+    #[cfg(not(feature = "deadlock-detection"))]
+    const NUM_SPINS: usize = 128;
+    const NUM_BUCKETS: usize = 64;
+
+    #[allow(clippy::declare_interior_mutable_const)]
+    const NO_WAITERS: AtomicU32 = AtomicU32::new(0);
+
+    static NUM_WAITERS: [AtomicU32; NUM_BUCKETS] = [NO_WAITERS; NUM_BUCKETS];
+
+    fn key(state: &AtomicU8) -> usize {
+        state as *const AtomicU8 as usize
+    }
+
+    fn num_waiters(key: usize) -> &'static AtomicU32 {
+        let hash = key.wrapping_mul(0x9E37_79B9) >> 16;
+        &NUM_WAITERS[hash % NUM_BUCKETS]
+    }
+
+    #[cfg(not(feature = "deadlock-detection"))]
+    pub(super) fn snooze(state: &AtomicU8, current: StateU8) {
+        for _ in 0..NUM_SPINS {
+            match state.load(Ordering::Relaxed) == current {
+                true => core::hint::spin_loop(),
+                false => return,
+            }
+        }
+        park(state, current, &mut || false);
+    }
+
+    pub(super) fn park<F>(state: &AtomicU8, current: StateU8, done: &mut F)
+    where
+        F: FnMut() -> bool,
+    {
+        let key = key(state);
+        let num_waiters = num_waiters(key);
+        num_waiters.fetch_add(1, Ordering::SeqCst);
+        // the state is validated while the queue of the key is locked; hence, either the release is observed
+        // here, or the releasing thread observes the waiter and unparks it after it is queued
+        let validate = || state.load(Ordering::SeqCst) == current && !done();
+        _ = unsafe {
+            parking_lot_core::park(key, validate, || {}, |_, _| {}, DEFAULT_PARK_TOKEN, None)
+        };
+        num_waiters.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn waiter_count(state: &AtomicU8) -> usize {
+        num_waiters(key(state)).load(Ordering::SeqCst) as usize
+    }
+
+    pub(super) fn notify(state: &AtomicU8) {
+        let key = key(state);
+        if num_waiters(key).load(Ordering::SeqCst) > 0 {
+            _ = unsafe { parking_lot_core::unpark_all(key, DEFAULT_UNPARK_TOKEN) };
+        }
+    }
+}
@@ -1,5 +1,4 @@
-use crate::{states::*, ConcurrentOption};
-use core::sync::atomic::Ordering;
+use crate::{states::*, ConcurrentOption, LoadOrdering};
 
 impl<T> ConcurrentOption<T> {
     // raw
@@ -27,7 +26,7 @@ impl<T> ConcurrentOption<T> {
     /// assert_eq!(unsafe { p.unwrap().as_ref() }, Some(&3.to_string()));
     /// ```
     pub fn get_raw(&self) -> Option<*const T> {
-        match self.spin_get_handle(SOME, SOME) {
+        match self.spin_get_read_handle() {
             Some(_handle) => {
                 let x = unsafe { &*self.value.get() };
                 Some(x.as_ptr())
@@ -65,41 +64,119 @@ impl<T> ConcurrentOption<T> {
     /// assert_eq!(unsafe { x.as_ref() }, Some(&7.to_string()));
     /// ```
     pub fn get_raw_mut(&self) -> Option<*mut T> {
-        match self.spin_get_handle(SOME, SOME) {
-            Some(_handle) => {
+        match self.spin_get_read_handle() {
+            Some(_handle) if self.state.load(ORDER_LOAD) != FROZEN => {
                 let x = unsafe { &mut *self.value.get() };
                 Some(x.as_mut_ptr())
             }
+            _ => None,
+        }
+    }
+
+    /// Returns:
+    /// * a raw `*const T` pointer to the underlying data together with the current version of the option
+    ///   when the option is of Some variant;
+    /// * `None` otherwise.
+    ///
+    /// The version is the generation counter of the option which is incremented by every mutation.
+    /// Together with [`is_still`], it allows external lock-free protocols to validate that the option
+    /// has not been mutated, such as taken and re-initialized, between the pointer acquisition and its use.
+    ///
+    /// The counter is a `usize` which wraps around on overflow; hence, on 32-bit targets, a version observed
+    /// exactly 2^32 mutations earlier compares equal to the current one.
+    ///
+    /// Available with the `generation` feature, which adds the generation counter to every option.
+    ///
+    /// [`is_still`]: ConcurrentOption::is_still
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::<String>::none();
+    /// assert!(x.get_raw_versioned().is_none());
+    ///
+    /// let x = ConcurrentOption::some(3.to_string());
+    /// let (p, version) = x.get_raw_versioned().unwrap();
+    /// assert!(x.is_still(version));
+    /// assert_eq!(unsafe { p.as_ref() }, Some(&3.to_string()));
+    ///
+    /// _ = x.take();
+    /// _ = x.initialize_if_none(7.to_string());
+    /// assert!(!x.is_still(version)); // pointer cannot be trusted anymore
+    /// ```
+    #[cfg(feature = "generation")]
+    pub fn get_raw_versioned(&self) -> Option<(*const T, u64)> {
+        match self.spin_get_read_handle() {
+            Some(_handle) => {
+                let x = unsafe { &*self.value.get() };
+                let version = self.generation.load(core::sync::atomic::Ordering::Relaxed) as u64;
+                Some((x.as_ptr(), version))
+            }
             None => None,
         }
     }
 
+    /// Returns whether or not the option is still at the given `version`; i.e., it has not been mutated
+    /// since the version is observed by [`get_raw_versioned`].
+    ///
+    /// Since the version is incremented only when a mutation completes, the option must also be of Some variant;
+    /// hence, false is returned while a writer holds the option reserved in the middle of a mutation.
+    /// Note that the result is a snapshot; the option might be mutated right after this method returns.
+    /// Further, the version wraps around as described in [`get_raw_versioned`].
+    ///
+    /// [`get_raw_versioned`]: ConcurrentOption::get_raw_versioned
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    /// let (_, version) = x.get_raw_versioned().unwrap();
+    /// assert!(x.is_still(version));
+    ///
+    /// _ = x.map(|x| x + 1);
+    /// assert!(x.is_still(version));
+    ///
+    /// x.update_if_some(|x| *x += 1);
+    /// assert!(!x.is_still(version));
+    /// ```
+    #[cfg(feature = "generation")]
+    pub fn is_still(&self, version: u64) -> bool {
+        // the state is loaded first: a mutation released before it is observed has already bumped the generation
+        match self.state.load(ORDER_LOAD) {
+            SOME | FROZEN => self.generation.load(ORDER_LOAD) as u64 == version,
+            _ => false,
+        }
+    }
+
     // raw with-order
 
     /// Returns:
     /// * a raw `*const T` pointer to the underlying data when the option is of Some variant;
     /// * `None` otherwise.
     ///
-    /// Depending on requirement of the use case, `Relaxed`, `Acquire` or `SeqCst` can be used as the `order`.
+    /// Depending on requirement of the use case, `Relaxed`, `Acquire` or `SeqCst` can be used as the [`LoadOrdering`].
     ///
     /// # Example
     ///
     /// ```rust
     /// use orx_concurrent_option::*;
-    /// use core::sync::atomic::Ordering;
     ///
     /// let x = ConcurrentOption::<String>::none();
-    /// let p = x.get_raw_with_order(Ordering::SeqCst);
+    /// let p = x.get_raw_with_order(LoadOrdering::SeqCst);
     /// assert!(p.is_none());
     ///
     /// let x = ConcurrentOption::some(3.to_string());
-    /// let p = x.get_raw_with_order(Ordering::Acquire);
+    /// let p = x.get_raw_with_order(LoadOrdering::Acquire);
     /// assert!(p.is_some());
     /// assert_eq!(unsafe { p.unwrap().as_ref() }, Some(&3.to_string()));
     /// ```
-    pub fn get_raw_with_order(&self, order: Ordering) -> Option<*const T> {
-        match self.state.load(order) {
-            SOME => {
+    pub fn get_raw_with_order(&self, order: LoadOrdering) -> Option<*const T> {
+        match self.state.load(order.into()) {
+            SOME | FROZEN => {
                 let x = unsafe { &*self.value.get() };
                 Some(x.as_ptr())
             }
@@ -111,31 +188,30 @@ impl<T> ConcurrentOption<T> {
     /// * a raw `*mut T` pointer to the underlying data when the option is of Some variant;
     /// * `None` otherwise.
     ///
-    /// Depending on requirement of the use case, `Relaxed`, `Acquire` or `SeqCst` can be used as the `order`.
+    /// Depending on requirement of the use case, `Relaxed`, `Acquire` or `SeqCst` can be used as the [`LoadOrdering`].
     ///
     /// ```rust
     /// use orx_concurrent_option::*;
-    /// use core::sync::atomic::Ordering;
     ///
     /// let x = ConcurrentOption::<String>::none();
-    /// let p = x.get_raw_mut_with_order(Ordering::SeqCst);
+    /// let p = x.get_raw_mut_with_order(LoadOrdering::SeqCst);
     /// assert!(p.is_none());
     ///
     /// let x = ConcurrentOption::some(3.to_string());
-    /// let p = x.get_raw_mut_with_order(Ordering::Acquire);
+    /// let p = x.get_raw_mut_with_order(LoadOrdering::Acquire);
     /// assert!(p.is_some());
     /// assert_eq!(unsafe { p.unwrap().as_ref() }, Some(&3.to_string()));
     ///
-    /// let p = x.get_raw_mut_with_order(Ordering::Relaxed).unwrap();
+    /// let p = x.get_raw_mut_with_order(LoadOrdering::Relaxed).unwrap();
     /// assert_eq!(unsafe { p.as_ref() }, Some(&3.to_string()));
     ///
-    /// let p = x.get_raw_mut_with_order(Ordering::Relaxed);
+    /// let p = x.get_raw_mut_with_order(LoadOrdering::Relaxed);
     /// let p = p.unwrap();
     /// let _ = unsafe { p.replace(7.to_string()) }; // only write leads to memory leak
     /// assert_eq!(unsafe { x.as_ref() }, Some(&7.to_string()));
     /// ```
-    pub fn get_raw_mut_with_order(&self, order: Ordering) -> Option<*mut T> {
-        match self.state.load(order) {
+    pub fn get_raw_mut_with_order(&self, order: LoadOrdering) -> Option<*mut T> {
+        match self.state.load(order.into()) {
             SOME => {
                 let x = unsafe { &mut *self.value.get() };
                 Some(x.as_mut_ptr())
@@ -0,0 +1,257 @@
+use crate::{
+    handle::{Handle, ReadHandle},
+    states::*,
+    ConcurrentOption, MappedReadGuard, WriteGuard,
+};
+use core::{fmt::Debug, mem::MaybeUninit, ops::Deref};
+
+/// A guard providing shared access to the value of a [`ConcurrentOption`] of Some variant.
+///
+/// The guard registers as a shared reader of the option while it is alive; it does not reserve the option.
+/// Therefore, any number of guards and reads can coexist, while writers such as `take` or `replace`
+/// wait until all guards are dropped; and hence, the value can safely be accessed through the guard
+/// without any possibility of a data race.
+///
+/// Note that writers attempting to mutate the option wait until the guard is dropped.
+/// Therefore, guards are meant to be short-lived. Further, the thread holding a guard must not mutate the same option,
+/// which would wait for the guard forever; [`try_upgrade`] is to be used instead.
+///
+/// [`try_upgrade`]: ReadGuard::try_upgrade
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+pub struct ReadGuard<'a, T> {
+    pub(crate) handle: ReadHandle<'a>,
+    pub(crate) option: &'a ConcurrentOption<T>,
+}
+
+impl<'a, T> ReadGuard<'a, T> {
+    pub(crate) fn new(option: &'a ConcurrentOption<T>) -> Option<Self> {
+        option
+            .spin_get_read_handle()
+            .map(|handle| Self { handle, option })
+    }
+
+    /// Attempts to upgrade the read guard into a [`WriteGuard`] without waiting; the option is reserved
+    /// while the guard is still registered as a reader, hence, no other thread can take or replace the value in between.
+    ///
+    /// Returns back the read guard as the error if the upgrade is not possible at the moment, which is the case
+    /// * if there exist other readers of the option, such as other read guards,
+    /// * if the option is reserved by another thread, or
+    /// * if the option is frozen.
+    ///
+    /// [`WriteGuard`]: crate::WriteGuard
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let slots = [ConcurrentOption::some(3)];
+    ///
+    /// let (_, guard) = ConcurrentOption::iter_guards(&slots).next().unwrap();
+    /// if *guard == 3 {
+    ///     let mut guard = guard.try_upgrade().unwrap();
+    ///     *guard += 1;
+    /// }
+    ///
+    /// assert_eq!(slots[0].map(|x| *x), Some(4));
+    /// ```
+    pub fn try_upgrade(self) -> Result<WriteGuard<'a, T>, Self> {
+        let Some(mut handle) = Handle::get(&self.option.state, SOME, SOME, None) else {
+            return Err(self);
+        };
+
+        match self.option.generation.has_other_readers() {
+            // new readers cannot enter while the option is reserved; dropping the handle releases it back as Some
+            true => Err(self),
+            false => {
+                let Self {
+                    handle: read_handle,
+                    option,
+                } = self;
+                drop(read_handle);
+                handle.make_write(&option.generation);
+                Ok(WriteGuard { handle, option })
+            }
+        }
+    }
+
+    /// Projects the guard into a part of the value by the function `f`, such as a field of the value,
+    /// while keeping the registration as a reader.
+    ///
+    /// Note that this method shadows a `map` method of the value, if any;
+    /// such a method can still be called by explicitly dereferencing the guard as in `(*guard).map(..)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// struct Config {
+    ///     endpoint: String,
+    ///     retries: usize,
+    /// }
+    ///
+    /// let slots = [ConcurrentOption::some(Config {
+    ///     endpoint: "localhost".to_string(),
+    ///     retries: 3,
+    /// })];
+    ///
+    /// let (_, guard) = ConcurrentOption::iter_guards(&slots).next().unwrap();
+    /// assert_eq!(guard.retries, 3);
+    ///
+    /// let endpoint = guard.map(|cfg| cfg.endpoint.as_str());
+    /// assert_eq!(&*endpoint, "localhost");
+    /// ```
+    pub fn map<U: ?Sized, F>(self, f: F) -> MappedReadGuard<'a, U>
+    where
+        F: FnOnce(&T) -> &U,
+    {
+        let value = unsafe { MaybeUninit::assume_init_ref(&*self.option.value.get()) };
+        MappedReadGuard {
+            value: f(value),
+            _handle: self.handle,
+        }
+    }
+
+    /// Projects the guard into a part of the value by the function `f` while keeping the registration as a reader;
+    /// returns back the guard as the error if `f` returns None.
+    ///
+    /// Note that this method shadows a `try_map` method of the value, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let slots = [ConcurrentOption::some(vec![1, 2, 3])];
+    ///
+    /// let (_, guard) = ConcurrentOption::iter_guards(&slots).next().unwrap();
+    /// let guard = guard.try_map(|x| x.get(7)).unwrap_err();
+    /// let last = guard.try_map(|x| x.last()).unwrap();
+    /// assert_eq!(*last, 3);
+    /// ```
+    pub fn try_map<U: ?Sized, F>(self, f: F) -> Result<MappedReadGuard<'a, U>, Self>
+    where
+        F: FnOnce(&T) -> Option<&U>,
+    {
+        let value = unsafe { MaybeUninit::assume_init_ref(&*self.option.value.get()) };
+        match f(value) {
+            Some(value) => Ok(MappedReadGuard {
+                value,
+                _handle: self.handle,
+            }),
+            None => Err(self),
+        }
+    }
+}
+
+impl<T> Deref for ReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { MaybeUninit::assume_init_ref(&*self.option.value.get()) }
+    }
+}
+
+impl<T: Debug> Debug for ReadGuard<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ReadGuard").field(&**self).finish()
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Thread safe method to obtain a [`ReadGuard`] to the value of the option if it is of Some variant;
+    /// returns None if the option is None.
+    ///
+    /// The guard registers as a shared reader of the option while it is alive, and hence, the option cannot be mutated
+    /// or taken by other threads until the guard is dropped. This provides a fully safe alternative to the unsafe `as_ref`.
+    ///
+    /// Note that other readers proceed concurrently, while other threads attempting to write the option
+    /// wait until the guard is dropped. Therefore, the guard is meant to be short-lived.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3.to_string());
+    ///
+    /// {
+    ///     let guard = x.get_guard().unwrap();
+    ///     let other = x.get_guard().unwrap(); // guards do not block each other
+    ///     assert_eq!(guard.as_str(), "3");
+    ///     assert_eq!(other.len(), 1);
+    /// } // writers can proceed from here on
+    ///
+    /// assert_eq!(x.take(), Some(3.to_string()));
+    /// assert!(x.get_guard().is_none());
+    /// ```
+    pub fn get_guard(&self) -> Option<ReadGuard<'_, T>> {
+        ReadGuard::new(self)
+    }
+
+    /// Returns a safe iterator over the option yielding at most one element, which is a [`ReadGuard`] to the value
+    /// if the option is of Some variant.
+    ///
+    /// Unlike the unsafe [`iter`], the yielded value cannot be invalidated by a concurrent `take` or `replace`
+    /// since the iterator, and then the yielded guard, is registered as a reader of the option until the guard is dropped.
+    ///
+    /// Note that other threads attempting to write the option wait until the iterator and the guard are dropped.
+    ///
+    /// [`iter`]: ConcurrentOption::iter
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3.to_string());
+    ///
+    /// let iter = x.iter_guarded();
+    /// assert_eq!(iter.len(), 1);
+    /// let total_len: usize = iter.map(|value| value.len()).sum();
+    /// assert_eq!(total_len, 1);
+    ///
+    /// _ = x.take();
+    /// assert_eq!(x.iter_guarded().count(), 0);
+    /// ```
+    pub fn iter_guarded(&self) -> crate::iter::GuardedIter<'_, T> {
+        crate::iter::GuardedIter {
+            maybe: ReadGuard::new(self),
+        }
+    }
+
+    /// Returns an iterator over the `options` yielding the index and a [`ReadGuard`] of each option which is of Some variant.
+    ///
+    /// The guards register as readers of the options one at a time while iterating, and each registration is released
+    /// as soon as the corresponding guard is dropped. Options which are None at the time they are visited are skipped.
+    ///
+    /// This provides a safe way to visit the live slots of a slot table without cloning the values or using
+    /// the unsafe `as_ref`.
+    ///
+    /// Note that each guard blocks the writers of the corresponding option while it is alive.
+    /// Therefore, it is recommended to drop each guard before moving on to the next one,
+    /// rather than collecting the guards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let slots = [
+    ///     ConcurrentOption::some(1.to_string()),
+    ///     ConcurrentOption::none(),
+    ///     ConcurrentOption::some(33.to_string()),
+    /// ];
+    ///
+    /// let mut total_len = 0;
+    /// for (idx, value) in ConcurrentOption::iter_guards(&slots) {
+    ///     assert!(idx == 0 || idx == 2);
+    ///     total_len += value.len();
+    /// }
+    /// assert_eq!(total_len, 3);
+    /// ```
+    pub fn iter_guards(options: &[Self]) -> crate::iter::IterGuards<'_, T> {
+        crate::iter::IterGuards { options, idx: 0 }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::ConcurrentOption;
+use core::{fmt::Debug, mem::MaybeUninit, ops::Deref};
+
+/// A reference to the value of a [`ConcurrentOption`] of Some variant, created by [`ConcurrentOption::as_ref_token`].
+///
+/// Unlike the [`ReadGuard`], the token does not register as a reader of the option; hence, it does not block writers.
+/// Therefore, it is subject to the same contract as the reference returned by `as_ref`:
+/// there must not be any concurrent writes to the option while the token is alive.
+///
+/// This contract becomes mechanically checkable when the `ref-tracking` feature is enabled or the code runs under miri:
+/// * every token registers itself on the option when created and deregisters when dropped,
+/// * every mutation of the option asserts that there exists no alive token.
+///
+/// Otherwise, the token is a transparent wrapper around `&T` with no additional cost.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`ConcurrentOption::as_ref_token`]: crate::ConcurrentOption::as_ref_token
+/// [`ReadGuard`]: crate::ReadGuard
+#[cfg_attr(not(any(miri, feature = "ref-tracking")), repr(transparent))]
+pub struct RefToken<'a, T> {
+    value: &'a T,
+    #[cfg(any(miri, feature = "ref-tracking"))]
+    num_ref_tokens: &'a crate::atomic::AtomicUsize,
+}
+
+#[cfg(any(miri, feature = "ref-tracking"))]
+impl<T> Drop for RefToken<'_, T> {
+    fn drop(&mut self) {
+        self.num_ref_tokens
+            .fetch_sub(1, core::sync::atomic::Ordering::Release);
+    }
+}
+
+impl<T> Deref for RefToken<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<T: Debug> Debug for RefToken<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("RefToken").field(self.value).finish()
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Partially thread safe method to obtain a [`RefToken`] to the value of the option if it is of Some variant.
+    ///
+    /// The token behaves like the reference returned by `as_ref`;
+    /// however, its contract is validated when the `ref-tracking` feature is enabled or the code runs under miri.
+    /// See [`RefToken`] for details.
+    ///
+    /// # Safety
+    ///
+    /// Note that creating a valid token part of this method is thread safe.
+    ///
+    /// The method is `unsafe` due to the token referencing the underlying value.
+    ///
+    /// * It is safe to use this method if the returned token is dropped before the option is mutated.
+    /// * It is also safe to use this method if the caller is able to guarantee that there exist
+    ///   no concurrent writes while holding onto this token.
+    ///   * One such case is using `as_ref_token` together with `initialize_when_none` method.
+    /// * Otherwise, it will lead to an **Undefined Behavior** due to data race;
+    ///   or to a panic when the contract is validated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3.to_string());
+    ///
+    /// let token = unsafe { x.as_ref_token() }.unwrap();
+    /// assert_eq!(token.as_str(), "3");
+    /// drop(token);
+    ///
+    /// _ = x.take(); // fine, since the token is dropped
+    /// assert!(unsafe { x.as_ref_token() }.is_none());
+    /// ```
+    pub unsafe fn as_ref_token(&self) -> Option<RefToken<'_, T>> {
+        match self.spin_get_read_handle() {
+            Some(_handle) => {
+                #[cfg(any(miri, feature = "ref-tracking"))]
+                self.generation
+                    .num_ref_tokens
+                    .fetch_add(1, core::sync::atomic::Ordering::Acquire);
+
+                Some(RefToken {
+                    value: unsafe { MaybeUninit::assume_init_ref(&*self.value.get()) },
+                    #[cfg(any(miri, feature = "ref-tracking"))]
+                    num_ref_tokens: &self.generation.num_ref_tokens,
+                })
+            }
+            None => None,
+        }
+    }
+}
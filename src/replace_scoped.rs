@@ -0,0 +1,63 @@
+use crate::ConcurrentOption;
+
+/// Restores the original value of the option once dropped, including during unwinding.
+struct Restore<'a, T> {
+    option: &'a ConcurrentOption<T>,
+    original: Option<T>,
+}
+
+impl<T> Drop for Restore<'_, T> {
+    fn drop(&mut self) {
+        match self.original.take() {
+            Some(original) => _ = self.option.replace(original),
+            None => _ = self.option.take(),
+        }
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Thread safe method which temporarily replaces the value of the option with `temp` while running `f`,
+    /// and restores the original value afterwards; returns the result of `f`.
+    ///
+    /// The original value, or the None variant if the option was None, is restored even if `f` panics.
+    /// The value of the option at the time of restoration, which is `temp` unless mutated by `f` or another thread,
+    /// is dropped.
+    ///
+    /// Note that the replacement and the restoration are separate thread safe operations; other threads observe
+    /// the temporary value while `f` is running, and their mutations in the meantime are overwritten by the restoration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen by [`freeze`], or if it is in a custom state set by [`transition_custom`].
+    ///
+    /// [`freeze`]: ConcurrentOption::freeze
+    /// [`transition_custom`]: ConcurrentOption::transition_custom
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let flag = ConcurrentOption::some("production");
+    ///
+    /// let observed = flag.replace_scoped("testing", || flag.get_cloned());
+    /// assert_eq!(observed, Some("testing"));
+    /// assert_eq!(flag.get_cloned(), Some("production"));
+    ///
+    /// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    ///     flag.replace_scoped("testing", || panic!("test failed"))
+    /// }));
+    /// assert!(result.is_err());
+    /// assert_eq!(flag.get_cloned(), Some("production"));
+    /// ```
+    pub fn replace_scoped<R, F>(&self, temp: T, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        let _restore = Restore {
+            option: self,
+            original: self.replace(temp),
+        };
+        f()
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{
+    custom_state::{holds_value, is_custom},
+    handle::Handle,
+    states::*,
+    ConcurrentOption,
+};
+use core::{fmt::Debug, mem::MaybeUninit};
+
+/// A guard holding the reservation of a [`ConcurrentOption`], created by [`ConcurrentOption::reserve`] or
+/// [`ConcurrentOption::try_reserve`]; this is the building block of all mutations of the option.
+///
+/// State transitions:
+/// * The option is atomically moved from the `initial_state` to the RESERVED state when the guard is created.
+///   If the initial state is SOME, the guard further waits until shared readers of the value leave.
+/// * While the guard is alive, the option is RESERVED; other threads can neither read nor mutate its value.
+///   They either wait for the reservation to be released or report the option as reserved, depending on the method.
+/// * Once the guard is dropped, the option is released into the `success_state`,
+///   and the mutation is published to the generation of the option, such as observed by [`Watcher`]s.
+///
+/// Since the reservation is exclusive, the guard provides access to the storage of the value through [`value`].
+/// Both the access to the storage and changing the state the option is released into are unsafe,
+/// since the state must agree with whether or not the value is initialized; see [`set_success_state`].
+///
+/// This allows composing multi-step atomic operations on top of `ConcurrentOption` without going through
+/// [`MutHandle`] and raw `UnsafeCell` access.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`ConcurrentOption::reserve`]: crate::ConcurrentOption::reserve
+/// [`ConcurrentOption::try_reserve`]: crate::ConcurrentOption::try_reserve
+/// [`Watcher`]: crate::Watcher
+/// [`value`]: ReservationGuard::value
+/// [`set_success_state`]: ReservationGuard::set_success_state
+/// [`MutHandle`]: crate::MutHandle
+///
+/// # Examples
+///
+/// A swap of the values of two options which leaves both options untouched unless both are of Some variant.
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// fn swap_if_both_some<T>(a: &ConcurrentOption<T>, b: &ConcurrentOption<T>) -> bool {
+///     let Some(mut a) = a.try_reserve(SOME, SOME) else {
+///         return false;
+///     };
+///     let Some(mut b) = b.try_reserve(SOME, SOME) else {
+///         return false; // `a` is released back as Some
+///     };
+///     // both storages are initialized and remain so after the swap
+///     core::mem::swap(unsafe { a.value() }, unsafe { b.value() });
+///     true
+/// }
+///
+/// let a = ConcurrentOption::some(1);
+/// let b = ConcurrentOption::some(2);
+/// assert!(swap_if_both_some(&a, &b));
+/// assert_eq!((a.get_cloned(), b.get_cloned()), (Some(2), Some(1)));
+///
+/// _ = b.take();
+/// assert!(!swap_if_both_some(&a, &b));
+/// assert_eq!((a.get_cloned(), b.get_cloned()), (Some(2), None));
+/// ```
+#[must_use = "the reservation is released as soon as the guard is dropped"]
+pub struct ReservationGuard<'a, T> {
+    handle: Handle<'a>,
+    option: &'a ConcurrentOption<T>,
+    initial_state: StateU8,
+    success_state: StateU8,
+}
+
+impl<T> ReservationGuard<'_, T> {
+    /// Returns the state the option was in before it was reserved.
+    pub fn initial_state(&self) -> State {
+        State::new(self.initial_state)
+    }
+
+    /// Returns the state the option will be released into once the guard is dropped.
+    pub fn success_state(&self) -> State {
+        State::new(self.success_state)
+    }
+
+    /// Returns the storage of the value of the option.
+    ///
+    /// The storage is initialized if and only if the initial state holds a value, such as SOME,
+    /// unless it is mutated through this method.
+    ///
+    /// # Safety
+    ///
+    /// The storage can be written or read out freely while the guard is alive. However, once the guard is dropped,
+    /// the value must be initialized if and only if the success state holds a value, such as SOME or a custom state
+    /// holding a value. For instance, uninitializing the storage of an option reserved from SOME to SOME, or
+    /// reading out its value without calling [`set_success_state`], leads to an **Undefined Behavior** on the
+    /// subsequent reads of the option.
+    ///
+    /// [`set_success_state`]: ReservationGuard::set_success_state
+    pub unsafe fn value(&mut self) -> &mut MaybeUninit<T> {
+        unsafe { &mut *self.option.value.get() }
+    }
+
+    /// Sets the state the option will be released into once the guard is dropped,
+    /// replacing the `success_state` the guard is created with.
+    ///
+    /// This is required for the transitions between a state holding a value and one holding no value,
+    /// such as publishing a value written into an option reserved from NONE.
+    ///
+    /// # Safety
+    ///
+    /// The value must be initialized once the guard is dropped if and only if the `success_state` holds a value,
+    /// such as SOME or a custom state holding a value. Otherwise, subsequent reads of the option lead to
+    /// an **Undefined Behavior**, or the value is leaked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `success_state` is not one of NONE, SOME or a custom state.
+    pub unsafe fn set_success_state(&mut self, success_state: StateU8) {
+        assert_success_state(success_state);
+        self.success_state = success_state;
+        self.handle.set_success_state(success_state);
+    }
+}
+
+impl<T> Debug for ReservationGuard<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReservationGuard")
+            .field("initial_state", &self.initial_state())
+            .field("success_state", &self.success_state())
+            .finish()
+    }
+}
+
+#[allow(clippy::panic)]
+fn assert_states(initial_state: StateU8, success_state: StateU8) {
+    if matches!(initial_state, RESERVED | FROZEN) {
+        panic!("an option cannot be reserved from the RESERVED or FROZEN states");
+    }
+    assert_success_state(success_state);
+    if holds_value(initial_state) != holds_value(success_state) {
+        panic!(
+            "the initial and success states must either both hold a value or both hold no value"
+        );
+    }
+}
+
+#[allow(clippy::panic)]
+fn assert_success_state(success_state: StateU8) {
+    if !matches!(success_state, NONE | SOME) && !is_custom(success_state) {
+        panic!("an option can only be released into the NONE, SOME or a custom state");
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Thread safe method to reserve the option if it is in the `initial_state`, spinning while it is reserved
+    /// by another thread; returns None if the option is in any other state.
+    ///
+    /// The option is released into the `success_state` once the returned guard is dropped.
+    /// Both states must either hold a value, such as SOME, or hold no value, such as NONE;
+    /// transitions between the two kinds are made by [`set_success_state`] after initializing or
+    /// taking out the value.
+    ///
+    /// See [`ReservationGuard`] for details.
+    ///
+    /// [`set_success_state`]: ReservationGuard::set_success_state
+    ///
+    /// # Panics
+    ///
+    /// Panics if
+    /// * the `initial_state` is RESERVED or FROZEN;
+    /// * the `success_state` is not one of NONE, SOME or a custom state; or
+    /// * only one of the `initial_state` and `success_state` holds a value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::none();
+    ///
+    /// let mut guard = x.reserve(NONE, NONE).unwrap();
+    /// assert_eq!(x.state(LoadOrdering::Acquire), State::Reserved);
+    /// unsafe { guard.value() }.write(42);
+    /// unsafe { guard.set_success_state(SOME) };
+    /// drop(guard);
+    ///
+    /// assert_eq!(x.get_cloned(), Some(42));
+    /// ```
+    pub fn reserve(
+        &self,
+        initial_state: StateU8,
+        success_state: StateU8,
+    ) -> Option<ReservationGuard<'_, T>> {
+        assert_states(initial_state, success_state);
+        self.spin_get_write_handle(initial_state, success_state)
+            .map(|handle| ReservationGuard {
+                handle,
+                option: self,
+                initial_state,
+                success_state,
+            })
+    }
+
+    /// Thread safe method to reserve the option if it is in the `initial_state`, without waiting;
+    /// returns None if the option is in any other state, including being reserved by another thread.
+    ///
+    /// The reservation is released into the `success_state` once the returned guard is dropped.
+    /// This is the non-waiting counterpart of [`reserve`]; see [`ReservationGuard`] for details.
+    ///
+    /// [`reserve`]: ConcurrentOption::reserve
+    ///
+    /// # Panics
+    ///
+    /// Panics if
+    /// * the `initial_state` is RESERVED or FROZEN;
+    /// * the `success_state` is not one of NONE, SOME or a custom state; or
+    /// * only one of the `initial_state` and `success_state` holds a value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(vec![1]);
+    ///
+    /// let mut guard = x.try_reserve(SOME, SOME).unwrap();
+    /// assert!(x.try_reserve(SOME, SOME).is_none()); // does not wait
+    /// unsafe { guard.value().assume_init_mut() }.push(2);
+    /// drop(guard);
+    ///
+    /// assert_eq!(x.get_cloned(), Some(vec![1, 2]));
+    /// ```
+    pub fn try_reserve(
+        &self,
+        initial_state: StateU8,
+        success_state: StateU8,
+    ) -> Option<ReservationGuard<'_, T>> {
+        assert_states(initial_state, success_state);
+        self.get_write_handle(initial_state, success_state)
+            .map(|handle| ReservationGuard {
+                handle,
+                option: self,
+                initial_state,
+                success_state,
+            })
+    }
+}
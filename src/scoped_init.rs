@@ -0,0 +1,100 @@
+use crate::ConcurrentOption;
+use std::thread::{Scope, ScopedJoinHandle};
+
+/// Handle of an initializer running in a background thread of a [`Scope`], created by [`ConcurrentOption::scoped_init`].
+///
+/// [`ConcurrentOption::scoped_init`]: crate::ConcurrentOption::scoped_init
+pub struct ScopedInit<'scope, T> {
+    option: &'scope ConcurrentOption<T>,
+    handle: ScopedJoinHandle<'scope, bool>,
+}
+
+impl<'scope, T> ScopedInit<'scope, T> {
+    /// Returns whether or not the initializer thread has finished.
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+
+    /// Waits for the initializer thread to finish and returns whether or not it initialized the option;
+    /// false if the option was already of Some variant when the initial value was computed.
+    ///
+    /// # Panics
+    ///
+    /// Resumes the panic of the initializer thread, if it has panicked.
+    pub fn join(self) -> bool {
+        match self.handle.join() {
+            Ok(initialized) => initialized,
+            Err(payload) => std::panic::resume_unwind(payload),
+        }
+    }
+
+    /// Waits for the initializer thread to finish and returns a reference to the value of the option;
+    /// None only if the value has been taken out of the option in the meantime.
+    ///
+    /// # Safety
+    ///
+    /// The method is `unsafe` due to the returned reference to the underlying value,
+    /// and has the same safety contract as [`as_ref`]:
+    ///
+    /// * It is safe to use this method if the caller is able to guarantee that there exist
+    ///   no concurrent writes while holding onto this reference,
+    ///   such as in the init-once pattern where the option is only initialized by `initialize_if_none`.
+    /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
+    ///
+    /// [`as_ref`]: crate::ConcurrentOption::as_ref
+    ///
+    /// # Panics
+    ///
+    /// Resumes the panic of the initializer thread, if it has panicked.
+    pub unsafe fn join_get(self) -> Option<&'scope T> {
+        let option = self.option;
+        self.join();
+        unsafe { option.as_ref() }
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Spawns a thread in the given `scope` which computes the value by `f` and initializes the option with it
+    /// if it is still None; and returns a [`ScopedInit`] handle of the initializer.
+    ///
+    /// The handle can be used to wait for the initialization, for instance by [`ScopedInit::join_get`],
+    /// while any panic of the initializer is propagated to the waiting thread.
+    /// Meanwhile, other threads can read the option with its thread safe methods.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::<String>::none();
+    ///
+    /// std::thread::scope(|s| {
+    ///     let init = x.scoped_init(s, || 7.to_string());
+    ///
+    ///     s.spawn(|| {
+    ///         while x.is_none() {
+    ///             std::thread::yield_now();
+    ///         }
+    ///         assert_eq!(x.get_cloned(), Some(7.to_string()));
+    ///     });
+    ///
+    ///     let value = unsafe { init.join_get() };
+    ///     assert_eq!(value, Some(&7.to_string()));
+    /// });
+    /// ```
+    pub fn scoped_init<'scope, 'env, F>(
+        &'scope self,
+        scope: &'scope Scope<'scope, 'env>,
+        f: F,
+    ) -> ScopedInit<'scope, T>
+    where
+        T: Send + Sync,
+        F: FnOnce() -> T + Send + 'scope,
+    {
+        let handle = scope.spawn(move || self.initialize_if_none(f()));
+        ScopedInit {
+            option: self,
+            handle,
+        }
+    }
+}
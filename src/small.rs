@@ -0,0 +1,170 @@
+use crate::ConcurrentOption;
+use alloc::boxed::Box;
+use core::{
+    fmt::Debug,
+    marker::PhantomData,
+    mem::{align_of, size_of, ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut},
+};
+
+/// A payload which stores its value inline if it fits in `MAX_INLINE` bytes, and boxes it on the heap otherwise.
+///
+/// The choice is made at compile time for each `T`: the value is stored inline if its size is at most `MAX_INLINE`
+/// and its alignment is at most the alignment of a pointer; see [`SmallBox::IS_INLINE`].
+/// The size of the payload is the maximum of `MAX_INLINE` and the size of a pointer, rounded up to the alignment of a pointer,
+/// regardless of `T`. This bounds the size of structs holding the payload when `T` varies across generic instantiations,
+/// at the cost of a branch on access.
+///
+/// A [`ConcurrentOption`] of a small-box payload is aliased as [`SmallConcurrentOption`].
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+///
+/// let x: SmallConcurrentOption<u32, 16> = ConcurrentOption::some(SmallBox::new(3));
+/// let y: SmallConcurrentOption<[u64; 100], 16> = ConcurrentOption::some(SmallBox::new([7; 100]));
+/// assert_eq!(core::mem::size_of_val(&x), core::mem::size_of_val(&y));
+///
+/// assert!(SmallBox::<u32, 16>::IS_INLINE);
+/// assert!(!SmallBox::<[u64; 100], 16>::IS_INLINE);
+///
+/// y.update_if_some(|y| y[0] = 42);
+/// assert_eq!(y.map(|y| y[0] + y[1]), Some(49));
+/// assert_eq!(x.take().map(SmallBox::into_inner), Some(3));
+/// ```
+pub struct SmallBox<T, const MAX_INLINE: usize> {
+    storage: Storage<T, MAX_INLINE>,
+    phantom: PhantomData<T>,
+}
+
+#[repr(C)]
+union Storage<T, const MAX_INLINE: usize> {
+    inline: [MaybeUninit<u8>; MAX_INLINE],
+    boxed: *mut T,
+}
+
+/// A [`ConcurrentOption`] whose payload is stored inline up to `MAX_INLINE` bytes and boxed otherwise; see [`SmallBox`].
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+pub type SmallConcurrentOption<T, const MAX_INLINE: usize> =
+    ConcurrentOption<SmallBox<T, MAX_INLINE>>;
+
+unsafe impl<T: Send, const MAX_INLINE: usize> Send for SmallBox<T, MAX_INLINE> {}
+
+unsafe impl<T: Sync, const MAX_INLINE: usize> Sync for SmallBox<T, MAX_INLINE> {}
+
+impl<T, const MAX_INLINE: usize> SmallBox<T, MAX_INLINE> {
+    /// Whether or not values of `T` are stored inline, rather than being boxed on the heap.
+    pub const IS_INLINE: bool =
+        size_of::<T>() <= MAX_INLINE && align_of::<T>() <= align_of::<Storage<T, MAX_INLINE>>();
+
+    /// Wraps the `value` as a small-box payload, boxing it if it does not fit inline.
+    pub fn new(value: T) -> Self {
+        let storage = match Self::IS_INLINE {
+            true => {
+                let mut storage = Storage {
+                    inline: [MaybeUninit::uninit(); MAX_INLINE],
+                };
+                unsafe { (&mut storage.inline as *mut _ as *mut T).write(value) };
+                storage
+            }
+            false => Storage {
+                boxed: Box::into_raw(Box::new(value)),
+            },
+        };
+        Self {
+            storage,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Unwraps the payload, moving the value out of the heap if it is boxed.
+    pub fn into_inner(self) -> T {
+        let this = ManuallyDrop::new(self);
+        match Self::IS_INLINE {
+            true => unsafe { this.as_ptr().read() },
+            false => *unsafe { Box::from_raw(this.storage.boxed) },
+        }
+    }
+
+    fn as_ptr(&self) -> *const T {
+        match Self::IS_INLINE {
+            true => unsafe { &self.storage.inline as *const _ as *const T },
+            false => unsafe { self.storage.boxed },
+        }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        match Self::IS_INLINE {
+            true => unsafe { &mut self.storage.inline as *mut _ as *mut T },
+            false => unsafe { self.storage.boxed },
+        }
+    }
+}
+
+impl<T, const MAX_INLINE: usize> Drop for SmallBox<T, MAX_INLINE> {
+    fn drop(&mut self) {
+        match Self::IS_INLINE {
+            true => unsafe { self.as_mut_ptr().drop_in_place() },
+            false => drop(unsafe { Box::from_raw(self.storage.boxed) }),
+        }
+    }
+}
+
+impl<T, const MAX_INLINE: usize> From<T> for SmallBox<T, MAX_INLINE> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, const MAX_INLINE: usize> Deref for SmallBox<T, MAX_INLINE> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.as_ptr() }
+    }
+}
+
+impl<T, const MAX_INLINE: usize> DerefMut for SmallBox<T, MAX_INLINE> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.as_mut_ptr() }
+    }
+}
+
+impl<T: Clone, const MAX_INLINE: usize> Clone for SmallBox<T, MAX_INLINE> {
+    fn clone(&self) -> Self {
+        Self::new((**self).clone())
+    }
+}
+
+impl<T: Default, const MAX_INLINE: usize> Default for SmallBox<T, MAX_INLINE> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: PartialEq, const MAX_INLINE: usize> PartialEq for SmallBox<T, MAX_INLINE> {
+    fn eq(&self, other: &Self) -> bool {
+        (**self).eq(&**other)
+    }
+}
+
+impl<T: Eq, const MAX_INLINE: usize> Eq for SmallBox<T, MAX_INLINE> {}
+
+impl<T: Debug, const MAX_INLINE: usize> Debug for SmallBox<T, MAX_INLINE> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SmallBox").field(&**self).finish()
+    }
+}
+
+// layout of small-box payloads: bounded by the inline capacity or a pointer, whichever is larger
+const _: () = assert!(size_of::<SmallBox<u8, 16>>() == 16);
+const _: () = assert!(size_of::<SmallBox<[u8; 1024], 16>>() == 16);
+const _: () = assert!(size_of::<SmallBox<u8, 1>>() == size_of::<usize>());
+const _: () =
+    assert!(size_of::<SmallBox<u8, 17>>() == 17usize.next_multiple_of(align_of::<usize>()));
+const _: () = assert!(SmallBox::<[u64; 2], 16>::IS_INLINE);
+const _: () = assert!(!SmallBox::<[u64; 3], 16>::IS_INLINE);
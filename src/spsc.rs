@@ -0,0 +1,153 @@
+//! Bounded single-producer single-consumer ring buffer built on [`ConcurrentOption`] slots.
+//!
+//! Each cell of the [`Ring`] is a concurrent option which is claimed and published by the [`Producer`]
+//! and taken by the [`Consumer`]. The state of each slot is the only synchronization between the two sides;
+//! the positions of the producer and the consumer are plain indices owned by the respective side.
+//!
+//! [`ConcurrentOption`]: crate::ConcurrentOption
+
+use crate::ConcurrentOption;
+
+/// Fixed-capacity single-producer single-consumer ring buffer of `N` concurrent option slots.
+///
+/// The ring is split into its [`Producer`] and [`Consumer`] halves by [`Ring::split`],
+/// which can then be sent to two different threads.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::spsc::*;
+///
+/// let mut ring = Ring::<String, 4>::new();
+/// let (mut producer, mut consumer) = ring.split();
+///
+/// std::thread::scope(|s| {
+///     s.spawn(move || {
+///         for i in 0..100 {
+///             let mut value = i.to_string();
+///             while let Err(back) = producer.push(value) {
+///                 value = back;
+///                 std::thread::yield_now();
+///             }
+///         }
+///     });
+///
+///     let mut received = vec![];
+///     while received.len() < 100 {
+///         match consumer.pop() {
+///             Some(value) => received.push(value),
+///             None => std::thread::yield_now(),
+///         }
+///     }
+///
+///     let expected: Vec<_> = (0..100).map(|i| i.to_string()).collect();
+///     assert_eq!(received, expected);
+/// });
+/// ```
+pub struct Ring<T, const N: usize> {
+    slots: [ConcurrentOption<T>; N],
+    head: usize,
+    tail: usize,
+}
+
+impl<T, const N: usize> Default for Ring<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Ring<T, N> {
+    /// Creates an empty ring with a capacity of `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub fn new() -> Self {
+        assert!(N > 0, "capacity of the ring must be positive");
+        Self {
+            slots: core::array::from_fn(|_| ConcurrentOption::none()),
+            head: 0,
+            tail: 0,
+        }
+    }
+
+    /// Returns the capacity of the ring.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Splits the ring into its producer and consumer halves.
+    ///
+    /// The ring can be split again once both halves are dropped, continuing from where they left off.
+    pub fn split(&mut self) -> (Producer<'_, T, N>, Consumer<'_, T, N>) {
+        let producer = Producer {
+            slots: &self.slots,
+            tail: &mut self.tail,
+        };
+        let consumer = Consumer {
+            slots: &self.slots,
+            head: &mut self.head,
+        };
+        (producer, consumer)
+    }
+}
+
+/// Producer half of a [`Ring`], created by [`Ring::split`].
+pub struct Producer<'a, T, const N: usize> {
+    slots: &'a [ConcurrentOption<T>; N],
+    tail: &'a mut usize,
+}
+
+impl<T, const N: usize> Producer<'_, T, N> {
+    /// Attempts to push the `value` to the ring without waiting.
+    ///
+    /// Returns back the value as the error if the ring is full;
+    /// i.e., if the next slot is not yet consumed or is being consumed at the moment.
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        match self.slots[*self.tail].try_claim() {
+            Some(claim) => {
+                claim.publish(value);
+                *self.tail = (*self.tail + 1) % N;
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+
+    /// Returns whether or not the ring is observed to be full by the producer.
+    pub fn is_full(&self) -> bool {
+        !self.slots[*self.tail].is_none()
+    }
+}
+
+/// Consumer half of a [`Ring`], created by [`Ring::split`].
+pub struct Consumer<'a, T, const N: usize> {
+    slots: &'a [ConcurrentOption<T>; N],
+    head: &'a mut usize,
+}
+
+impl<T, const N: usize> Consumer<'_, T, N> {
+    /// Pops the oldest value of the ring without waiting; returns None if the ring is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let mut value = None;
+        self.pop_into(&mut value);
+        value
+    }
+
+    /// Pops the oldest value of the ring into `destination` without waiting;
+    /// returns false, leaving the destination untouched, if the ring is empty.
+    pub fn pop_into(&mut self, destination: &mut Option<T>) -> bool {
+        match self.slots[*self.head].take_into(destination) {
+            true => {
+                *self.head = (*self.head + 1) % N;
+                true
+            }
+            false => false,
+        }
+    }
+
+    /// Returns whether or not the ring is observed to be empty by the consumer.
+    pub fn is_empty(&self) -> bool {
+        !self.slots[*self.head].is_some()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::custom_state::is_custom;
 use core::sync::atomic::Ordering;
 
 /// State represented as u8.
@@ -12,6 +13,8 @@ pub const NONE: StateU8 = 0;
 pub const RESERVED: StateU8 = 1;
 /// State where the optional contains a value.
 pub const SOME: StateU8 = 2;
+/// State where the optional contains a value which can never be mutated through a shared reference again.
+pub const FROZEN: StateU8 = 3;
 
 /// Concurrent state of the optional.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,6 +25,18 @@ pub enum State {
     Some,
     /// Optional is currently reserved for a mutation.
     Reserved,
+    /// Optional has some value and is permanently read-only.
+    ///
+    /// See [`ConcurrentOption::freeze`] for details.
+    ///
+    /// [`ConcurrentOption::freeze`]: crate::ConcurrentOption::freeze
+    Frozen,
+    /// Optional is in a user-defined state, holding the raw state value.
+    ///
+    /// See [`CustomState`] for details.
+    ///
+    /// [`CustomState`]: crate::CustomState
+    Custom(StateU8),
 }
 
 impl State {
@@ -31,7 +46,19 @@ impl State {
             NONE => Self::None,
             SOME => Self::Some,
             RESERVED => Self::Reserved,
-            _ => panic!("should be either of the three valid states"),
+            FROZEN => Self::Frozen,
+            x if is_custom(x) => Self::Custom(x),
+            _ => panic!("should be either of the built-in or a custom state"),
+        }
+    }
+    /// Returns the raw state value.
+    pub(crate) fn to_u8(self) -> StateU8 {
+        match self {
+            Self::None => NONE,
+            Self::Some => SOME,
+            Self::Reserved => RESERVED,
+            Self::Frozen => FROZEN,
+            Self::Custom(x) => x,
         }
     }
 }
@@ -0,0 +1,132 @@
+#[cfg(feature = "stats")]
+use crate::atomic::{AtomicU8, AtomicUsize};
+use crate::ConcurrentOption;
+#[cfg(feature = "stats")]
+use core::sync::atomic::Ordering;
+
+/// Describes how a value has left a [`ConcurrentOption`].
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitReason {
+    /// The value is moved out of the option to the caller, such as by `take`, `take_if` or `exclusive_take`,
+    /// or through a mut handle which brings the option from Some to None.
+    Taken,
+    /// The value is replaced by a new value; such as by `replace` or `exclusive_replace`, which return the old value
+    /// to the caller, or by `set_some`, `insert` or `exclusive_insert`, which drop the old value in place.
+    Replaced,
+    /// The value is dropped in place by the option without being replaced, such as by `exclusive_reset_to(None)`.
+    Cleared,
+}
+
+#[cfg(feature = "stats")]
+impl ExitReason {
+    const NOT_EXITED: u8 = 0;
+
+    fn to_u8(self) -> u8 {
+        match self {
+            Self::Taken => 1,
+            Self::Replaced => 2,
+            Self::Cleared => 3,
+        }
+    }
+
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::Taken),
+            2 => Some(Self::Replaced),
+            3 => Some(Self::Cleared),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "stats")]
+pub(crate) struct Stats {
+    last_exit: AtomicU8,
+    num_values_dropped: AtomicUsize,
+}
+
+#[cfg(feature = "stats")]
+impl Stats {
+    pub const fn new() -> Self {
+        Self {
+            last_exit: AtomicU8::new(ExitReason::NOT_EXITED),
+            num_values_dropped: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    #[inline(always)]
+    #[allow(unused_variables)]
+    pub(crate) fn record_exit(&self, exit: ExitReason) {
+        #[cfg(feature = "stats")]
+        self.stats.last_exit.store(exit.to_u8(), Ordering::Relaxed);
+    }
+
+    /// Records the exit of a value which is dropped in place by the option.
+    #[inline(always)]
+    pub(crate) fn record_drop(&self, exit: ExitReason) {
+        self.record_exit(exit);
+        #[cfg(feature = "stats")]
+        self.stats
+            .num_values_dropped
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns how the last value has left the option; None if no value has left the option yet.
+    ///
+    /// Note that the statistics belong to the option; hence, a value which is dropped together with the option
+    /// is not recorded as an exit.
+    ///
+    /// This is useful for leak hunts in long-running services where it is important to know whether the
+    /// values are being taken or silently replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3.to_string());
+    /// assert_eq!(x.last_exit(), None);
+    ///
+    /// _ = x.replace(4.to_string());
+    /// assert_eq!(x.last_exit(), Some(ExitReason::Replaced));
+    ///
+    /// _ = unsafe { x.insert(5.to_string()) };
+    /// assert_eq!(x.last_exit(), Some(ExitReason::Replaced));
+    ///
+    /// _ = x.take();
+    /// assert_eq!(x.last_exit(), Some(ExitReason::Taken));
+    ///
+    /// let mut x = ConcurrentOption::some(6.to_string());
+    /// x.exclusive_reset_to(None);
+    /// assert_eq!(x.last_exit(), Some(ExitReason::Cleared));
+    /// ```
+    #[cfg(feature = "stats")]
+    pub fn last_exit(&self) -> Option<ExitReason> {
+        ExitReason::from_u8(self.stats.last_exit.load(Ordering::Relaxed))
+    }
+
+    /// Returns the number of values which are dropped in place by the option, either while being overwritten by
+    /// new values, such as by `set_some`, or while being cleared, such as by `exclusive_reset_to(None)`.
+    ///
+    /// Values which are moved out to the caller, such as by `take` or `replace`, are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::none();
+    /// x.set_some(1);
+    /// x.set_some(2);
+    /// x.set_some(3);
+    /// assert_eq!(x.num_values_dropped(), 2);
+    /// ```
+    #[cfg(feature = "stats")]
+    pub fn num_values_dropped(&self) -> usize {
+        self.stats.num_values_dropped.load(Ordering::Relaxed)
+    }
+}
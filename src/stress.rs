@@ -0,0 +1,188 @@
+//! Reusable multi-threaded stress harness for [`ConcurrentOption`] and types built on top of it.
+//!
+//! A [`Stress`] run spawns groups of readers, writers and takers acting on the same option concurrently,
+//! each group with its own number of threads, while the invariants are checked after every action.
+//!
+//! [`ConcurrentOption`]: crate::ConcurrentOption
+
+use crate::ConcurrentOption;
+use alloc::{boxed::Box, vec::Vec};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+type Action<'a, T> = Box<dyn Fn(&ConcurrentOption<T>, usize) + Sync + 'a>;
+type Invariant<'a, T> = Box<dyn Fn(&ConcurrentOption<T>) -> bool + Sync + 'a>;
+
+struct Role<'a, T> {
+    num_threads: usize,
+    action: Action<'a, T>,
+}
+
+/// Summary of a completed [`Stress`] run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StressReport {
+    /// Total number of spawned threads.
+    pub num_threads: usize,
+    /// Total number of actions performed by all threads.
+    pub num_actions: usize,
+    /// Total number of invariant checks performed by all threads.
+    pub num_invariant_checks: usize,
+}
+
+/// Multi-threaded stress harness acting on a [`ConcurrentOption`].
+///
+/// Each thread of a role calls the role's action `num_iterations` times with the option and the iteration index;
+/// and checks all invariants after each action. The run panics as soon as an action panics or an invariant is violated.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::{stress::Stress, *};
+///
+/// let option = ConcurrentOption::some(0usize);
+///
+/// let report = Stress::new(&option, 100)
+///     .readers(4, |o, _| assert!(o.map(|x| *x % 2 == 0).unwrap_or(true)))
+///     .writers(2, |o, _| _ = o.update_if_some(|x| *x += 2))
+///     .takers(1, |o, i| match i % 10 {
+///         0 => _ = o.take(),
+///         _ => _ = o.initialize_if_none(0),
+///     })
+///     .invariant(|o| o.map(|x| *x % 2 == 0).unwrap_or(true))
+///     .run();
+///
+/// assert_eq!(report.num_threads, 7);
+/// assert_eq!(report.num_actions, 700);
+/// assert_eq!(report.num_invariant_checks, 700);
+/// ```
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+pub struct Stress<'a, T> {
+    option: &'a ConcurrentOption<T>,
+    num_iterations: usize,
+    roles: Vec<Role<'a, T>>,
+    invariants: Vec<Invariant<'a, T>>,
+}
+
+impl<'a, T: Sync> Stress<'a, T> {
+    /// Creates a stress harness on the `option` where each thread performs `num_iterations` actions.
+    pub fn new(option: &'a ConcurrentOption<T>, num_iterations: usize) -> Self {
+        Self {
+            option,
+            num_iterations,
+            roles: Vec::new(),
+            invariants: Vec::new(),
+        }
+    }
+
+    /// Adds `num_threads` threads each of which repeatedly calls `action` with the option and the iteration index.
+    pub fn role<F>(mut self, num_threads: usize, action: F) -> Self
+    where
+        F: Fn(&ConcurrentOption<T>, usize) + Sync + 'a,
+    {
+        self.roles.push(Role {
+            num_threads,
+            action: Box::new(action),
+        });
+        self
+    }
+
+    /// Adds `num_threads` reader threads; equivalent to [`role`], named for readability.
+    ///
+    /// [`role`]: Stress::role
+    pub fn readers<F>(self, num_threads: usize, action: F) -> Self
+    where
+        F: Fn(&ConcurrentOption<T>, usize) + Sync + 'a,
+    {
+        self.role(num_threads, action)
+    }
+
+    /// Adds `num_threads` writer threads; equivalent to [`role`], named for readability.
+    ///
+    /// [`role`]: Stress::role
+    pub fn writers<F>(self, num_threads: usize, action: F) -> Self
+    where
+        F: Fn(&ConcurrentOption<T>, usize) + Sync + 'a,
+    {
+        self.role(num_threads, action)
+    }
+
+    /// Adds `num_threads` taker threads; equivalent to [`role`], named for readability.
+    ///
+    /// [`role`]: Stress::role
+    pub fn takers<F>(self, num_threads: usize, action: F) -> Self
+    where
+        F: Fn(&ConcurrentOption<T>, usize) + Sync + 'a,
+    {
+        self.role(num_threads, action)
+    }
+
+    /// Adds an invariant which must hold after every action of every thread.
+    ///
+    /// The invariant is expected to access the option through its thread safe methods.
+    pub fn invariant<F>(mut self, invariant: F) -> Self
+    where
+        F: Fn(&ConcurrentOption<T>) -> bool + Sync + 'a,
+    {
+        self.invariants.push(Box::new(invariant));
+        self
+    }
+
+    /// Runs all roles concurrently and returns the report once all threads complete.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the actions panics or any of the invariants is violated.
+    pub fn run(&self) -> StressReport {
+        self.run_with_multiplier(1)
+    }
+
+    /// Repeats the run with exponentially growing contention: in the `k`-th run, `k = 0..num_doublings`,
+    /// the number of threads of each role is multiplied by `2^k`.
+    ///
+    /// Returns the reports of all runs in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the actions panics or any of the invariants is violated.
+    pub fn run_exponential(&self, num_doublings: usize) -> Vec<StressReport> {
+        (0..num_doublings)
+            .map(|k| self.run_with_multiplier(1 << k))
+            .collect()
+    }
+
+    #[allow(clippy::panic)]
+    fn run_with_multiplier(&self, multiplier: usize) -> StressReport {
+        let num_actions = AtomicUsize::new(0);
+        let num_invariant_checks = AtomicUsize::new(0);
+        let num_threads = self.roles.iter().map(|r| r.num_threads).sum::<usize>() * multiplier;
+
+        std::thread::scope(|s| {
+            for role in &self.roles {
+                for _ in 0..(role.num_threads * multiplier) {
+                    s.spawn(|| {
+                        for i in 0..self.num_iterations {
+                            (role.action)(self.option, i);
+                            num_actions.fetch_add(1, Ordering::Relaxed);
+
+                            for (k, invariant) in self.invariants.iter().enumerate() {
+                                if !invariant(self.option) {
+                                    panic!(
+                                        "stress invariant #{} is violated at iteration {}",
+                                        k, i
+                                    );
+                                }
+                                num_invariant_checks.fetch_add(1, Ordering::Relaxed);
+                            }
+                        }
+                    });
+                }
+            }
+        });
+
+        StressReport {
+            num_threads,
+            num_actions: num_actions.into_inner(),
+            num_invariant_checks: num_invariant_checks.into_inner(),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{states::*, stats::ExitReason, ConcurrentOption};
+use core::{mem::MaybeUninit, sync::atomic::Ordering};
+
+/// A token proving that a [`ConcurrentOption`] is emptied by the caller, created by [`ConcurrentOption::take_tagged`].
+///
+/// The token captures the generation of the option right after the take.
+/// It can be redeemed by [`ConcurrentOption::reinstall`] to put a value back into the option
+/// only if no other writer has intervened since the take.
+///
+/// The token is bound to the option it is created by; reinstalling with a token of another option always fails.
+///
+/// Available with the `generation` feature, which adds the generation counter to every option.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`ConcurrentOption::take_tagged`]: crate::ConcurrentOption::take_tagged
+/// [`ConcurrentOption::reinstall`]: crate::ConcurrentOption::reinstall
+#[derive(Debug)]
+#[must_use = "the token is required to reinstall a value"]
+pub struct TakeToken<'a, T> {
+    option: &'a ConcurrentOption<T>,
+    generation: usize,
+}
+
+impl<T> PartialEq for TakeToken<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self.option, other.option) && self.generation == other.generation
+    }
+}
+
+impl<T> Eq for TakeToken<'_, T> {}
+
+impl<T> ConcurrentOption<T> {
+    /// Thread safe method to take the value out of the option if Some, leaving a None in its place,
+    /// together with a [`TakeToken`] proving that the option is emptied by this call.
+    ///
+    /// Has no impact and returns None, if the option is of None variant.
+    ///
+    /// The token can be used with [`reinstall`] to put a value back into the option only if no other writer
+    /// has intervened in between; which supports optimistic check-out / check-in workflows on shared slots.
+    ///
+    /// [`reinstall`]: ConcurrentOption::reinstall
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(vec![1, 2]);
+    ///
+    /// // check-out
+    /// let (mut value, token) = x.take_tagged().unwrap();
+    /// assert!(x.is_none());
+    ///
+    /// // check-in
+    /// value.push(3);
+    /// assert_eq!(x.reinstall(token, value), Ok(()));
+    /// assert_eq!(x.get_cloned(), Some(vec![1, 2, 3]));
+    /// ```
+    pub fn take_tagged(&self) -> Option<(T, TakeToken<'_, T>)> {
+        match self.spin_get_write_handle(SOME, NONE) {
+            Some(_handle) => {
+                self.record_exit(ExitReason::Taken);
+                // the generation is incremented once the handle is dropped
+                let generation = self.generation.load(Ordering::Relaxed).wrapping_add(1);
+                let x = unsafe { &*self.value.get() };
+                let value = unsafe { MaybeUninit::assume_init_read(x) };
+                Some((
+                    value,
+                    TakeToken {
+                        option: self,
+                        generation,
+                    },
+                ))
+            }
+            None => None,
+        }
+    }
+
+    /// Thread safe method to put the `value` back into the option which is emptied by [`take_tagged`] creating the `token`,
+    /// only if no other writer has mutated the option since then.
+    ///
+    /// Returns back the `value` as the error if the option has been mutated in between,
+    /// such as initialized and taken again by another thread, in which case the option is not changed.
+    /// Similarly, the `value` is returned back if the `token` is created by another option.
+    ///
+    /// [`take_tagged`]: ConcurrentOption::take_tagged
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(1);
+    ///
+    /// let (value, token) = x.take_tagged().unwrap();
+    ///
+    /// // another writer intervenes
+    /// x.initialize_if_none(42);
+    /// _ = x.take();
+    ///
+    /// assert_eq!(x.reinstall(token, value + 1), Err(2));
+    /// assert!(x.is_none());
+    /// ```
+    pub fn reinstall(&self, token: TakeToken<'_, T>, value: T) -> Result<(), T> {
+        if !core::ptr::eq(token.option, self) {
+            return Err(value);
+        }
+
+        match self.spin_get_handle(NONE, NONE) {
+            Some(mut handle) if self.generation.load(ORDER_LOAD) == token.generation => {
+                unsafe { &mut *self.value.get() }.write(value);
+                handle.make_write(&self.generation);
+                handle.set_success_state(SOME);
+                Ok(())
+            }
+            _ => Err(value),
+        }
+    }
+}
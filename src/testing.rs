@@ -0,0 +1,173 @@
+//! Helpers to validate that a [`ConcurrentOption`] behaves identically to a standard `Option`.
+//!
+//! These are useful for downstream crates migrating from `Option` to `ConcurrentOption`,
+//! as well as for keeping the semantic parity of the two types as the API grows.
+//!
+//! [`ConcurrentOption`]: crate::ConcurrentOption
+
+use crate::{ConcurrentOption, IntoOption};
+use core::fmt::Debug;
+
+/// The common subset of the `Option` API which is implemented both by `Option<T>` and [`ConcurrentOption<T>`],
+/// allowing the same closure to be run against both by [`assert_option_parity`].
+///
+/// The trait is object safe; closures take it as `&mut dyn OptionLike<T>`.
+/// The implementation of `ConcurrentOption` uses its thread safe `&self` methods whenever available,
+/// and its exclusive methods for those returning a mutable reference.
+///
+/// [`ConcurrentOption<T>`]: crate::ConcurrentOption
+pub trait OptionLike<T> {
+    /// Returns true if the option is of Some variant.
+    fn is_some(&self) -> bool;
+
+    /// Returns true if the option is of None variant.
+    fn is_none(&self) -> bool;
+
+    /// Returns a mutable reference to the value if Some.
+    fn as_mut(&mut self) -> Option<&mut T>;
+
+    /// Takes the value out of the option, leaving a None in its place.
+    fn take(&mut self) -> Option<T>;
+
+    /// Takes the value out of the option only if the predicate returns true for it.
+    fn take_if(&mut self, predicate: &mut dyn FnMut(&mut T) -> bool) -> Option<T>;
+
+    /// Replaces the value with the given `value`, returning the old value if present.
+    fn replace(&mut self, value: T) -> Option<T>;
+
+    /// Inserts the `value` into the option and returns a mutable reference to it.
+    fn insert(&mut self, value: T) -> &mut T;
+
+    /// Inserts the `value` into the option if it is None, and returns a mutable reference to the contained value.
+    fn get_or_insert(&mut self, value: T) -> &mut T;
+
+    /// Inserts a value computed by `f` into the option if it is None, and returns a mutable reference to the contained value.
+    fn get_or_insert_with(&mut self, f: &mut dyn FnMut() -> T) -> &mut T;
+}
+
+impl<T> OptionLike<T> for Option<T> {
+    fn is_some(&self) -> bool {
+        Option::is_some(self)
+    }
+
+    fn is_none(&self) -> bool {
+        Option::is_none(self)
+    }
+
+    fn as_mut(&mut self) -> Option<&mut T> {
+        Option::as_mut(self)
+    }
+
+    fn take(&mut self) -> Option<T> {
+        Option::take(self)
+    }
+
+    fn take_if(&mut self, predicate: &mut dyn FnMut(&mut T) -> bool) -> Option<T> {
+        match self.as_mut().map(predicate) {
+            Some(true) => self.take(),
+            _ => None,
+        }
+    }
+
+    fn replace(&mut self, value: T) -> Option<T> {
+        Option::replace(self, value)
+    }
+
+    fn insert(&mut self, value: T) -> &mut T {
+        Option::insert(self, value)
+    }
+
+    fn get_or_insert(&mut self, value: T) -> &mut T {
+        Option::get_or_insert(self, value)
+    }
+
+    fn get_or_insert_with(&mut self, f: &mut dyn FnMut() -> T) -> &mut T {
+        Option::get_or_insert_with(self, f)
+    }
+}
+
+impl<T> OptionLike<T> for ConcurrentOption<T> {
+    fn is_some(&self) -> bool {
+        ConcurrentOption::is_some(self)
+    }
+
+    fn is_none(&self) -> bool {
+        ConcurrentOption::is_none(self)
+    }
+
+    fn as_mut(&mut self) -> Option<&mut T> {
+        self.exclusive_as_mut()
+    }
+
+    fn take(&mut self) -> Option<T> {
+        ConcurrentOption::take(self)
+    }
+
+    fn take_if(&mut self, predicate: &mut dyn FnMut(&mut T) -> bool) -> Option<T> {
+        ConcurrentOption::take_if(self, predicate)
+    }
+
+    fn replace(&mut self, value: T) -> Option<T> {
+        ConcurrentOption::replace(self, value)
+    }
+
+    fn insert(&mut self, value: T) -> &mut T {
+        self.exclusive_insert(value)
+    }
+
+    fn get_or_insert(&mut self, value: T) -> &mut T {
+        self.exclusive_get_or_insert(value)
+    }
+
+    fn get_or_insert_with(&mut self, f: &mut dyn FnMut() -> T) -> &mut T {
+        self.exclusive_get_or_insert_with(f)
+    }
+}
+
+/// Runs the closure `f` against both an `Option<T>` and a [`ConcurrentOption<T>`] created from the `initial` value;
+/// and asserts that the results of the closure as well as the final values of the two options are identical.
+///
+/// [`ConcurrentOption<T>`]: crate::ConcurrentOption
+///
+/// # Panics
+///
+/// Panics if the results of the closure or the final values of the options differ.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::testing::*;
+///
+/// assert_option_parity(Some(3), |x| {
+///     let old = x.replace(4);
+///     *x.get_or_insert(7) += 1;
+///     (old, x.take_if(&mut |x| *x > 10), x.is_some())
+/// });
+///
+/// assert_option_parity(None, |x| {
+///     let inserted = *x.get_or_insert_with(&mut || 42);
+///     (inserted, x.take(), x.take())
+/// });
+/// ```
+pub fn assert_option_parity<T, R, F>(initial: Option<T>, f: F)
+where
+    T: Clone + PartialEq + Debug,
+    R: PartialEq + Debug,
+    F: Fn(&mut dyn OptionLike<T>) -> R,
+{
+    let mut option = initial.clone();
+    let mut concurrent_option = ConcurrentOption::from(initial);
+
+    let expected = f(&mut option);
+    let actual = f(&mut concurrent_option);
+
+    assert_eq!(
+        actual, expected,
+        "results of ConcurrentOption (left) and Option (right) differ"
+    );
+    assert_eq!(
+        concurrent_option.into_option(),
+        option,
+        "final values of ConcurrentOption (left) and Option (right) differ"
+    );
+}
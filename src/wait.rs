@@ -0,0 +1,312 @@
+use crate::ConcurrentOption;
+#[cfg(feature = "std")]
+use crate::{atomic::AtomicU8, parking::park, states::*, Initialized};
+#[cfg(feature = "async")]
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+/// Blocks the current thread until `done` returns true, backing off from spinning to yielding and parking;
+/// `done` must depend on the `state`, whose changes wake up the parked thread.
+#[cfg(feature = "std")]
+pub(crate) fn block_until<F>(state: &AtomicU8, mut done: F)
+where
+    F: FnMut() -> bool,
+{
+    const NUM_SPINS: usize = 64;
+    const NUM_YIELDS: usize = 64;
+
+    for _ in 0..NUM_SPINS {
+        match done() {
+            true => return,
+            false => core::hint::spin_loop(),
+        }
+    }
+
+    for _ in 0..NUM_YIELDS {
+        match done() {
+            true => return,
+            false => std::thread::yield_now(),
+        }
+    }
+
+    loop {
+        let current = state.load(ORDER_LOAD);
+        if done() {
+            return;
+        }
+        park(state, current, &mut done);
+    }
+}
+
+/// Future which resolves once the [`ConcurrentOption`] is observed to be None,
+/// created by [`ConcurrentOption::wait_for_none_async`].
+///
+/// While pending, the future registers the waker of its task on the option; the task is woken by the next state
+/// transition of the option, such as `take`, rather than by polling.
+/// The registration is removed when the future is dropped before it resolves.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`ConcurrentOption::wait_for_none_async`]: crate::ConcurrentOption::wait_for_none_async
+#[cfg(feature = "async")]
+#[must_use = "futures do nothing unless awaited or polled"]
+pub struct WaitForNone<'a, T> {
+    option: &'a ConcurrentOption<T>,
+    waker: Option<Waker>,
+}
+
+#[cfg(feature = "async")]
+impl<T> WaitForNone<'_, T> {
+    fn is_none(&self) -> bool {
+        self.option.state.load(ORDER_LOAD) == NONE
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T> Future for WaitForNone<'_, T> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if !self.is_none() {
+            crate::waiters::register_waker(&self.option.state, cx.waker());
+            self.waker = Some(cx.waker().clone());
+
+            // the value might have been taken before the registration
+            if !self.is_none() {
+                return Poll::Pending;
+            }
+        }
+
+        // the registration is consumed by the release which took the value, unless the release happened
+        // before the registration; then, it must be removed here since no release might ever consume it
+        if let Some(waker) = self.waker.take() {
+            crate::waiters::deregister_waker(&self.option.state, &waker);
+        }
+        Poll::Ready(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T> Drop for WaitForNone<'_, T> {
+    fn drop(&mut self) {
+        if let Some(waker) = &self.waker {
+            crate::waiters::deregister_waker(&self.option.state, waker);
+        }
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Blocks the current thread until the option is observed to be None.
+    ///
+    /// The thread first spins and then yields while the option is of Some variant or reserved by another thread;
+    /// finally, it parks until the state of the option changes.
+    /// With the `futex` or `parking_lot` feature, the thread rather sleeps in the kernel or the parking lot, respectively.
+    ///
+    /// This is useful for single-slot backpressure, where a producer waits until the consumer has taken the previous
+    /// value before inserting the next one. Note that the option might become Some again right after this method returns,
+    /// if there are other producers; `initialize_if_none` can be used to insert the value only if it is still None.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let slot = ConcurrentOption::none();
+    /// let num_items = 100;
+    ///
+    /// let consumed: Vec<_> = std::thread::scope(|s| {
+    ///     s.spawn(|| {
+    ///         for i in 0..num_items {
+    ///             slot.wait_for_none();
+    ///             assert!(slot.initialize_if_none(i)); // single producer
+    ///         }
+    ///     });
+    ///
+    ///     let consumer = s.spawn(|| {
+    ///         let mut consumed = vec![];
+    ///         while consumed.len() < num_items {
+    ///             consumed.extend(slot.take());
+    ///         }
+    ///         consumed
+    ///     });
+    ///
+    ///     consumer.join().unwrap()
+    /// });
+    ///
+    /// assert_eq!(consumed, (0..num_items).collect::<Vec<_>>());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn wait_for_none(&self) {
+        block_until(&self.state, || self.state.load(ORDER_LOAD) == NONE)
+    }
+
+    /// Blocks the current thread until the option is observed to be Some, and returns an [`Initialized`] token
+    /// providing safe shared access to the value.
+    ///
+    /// The thread first spins and then yields while the option is None or reserved by another thread;
+    /// finally, it parks until the state of the option changes.
+    /// With the `futex` or `parking_lot` feature, the thread rather sleeps in the kernel or the parking lot, respectively.
+    ///
+    /// This is the reader side of the init-once pattern; rather than polling the option in a loop,
+    /// readers block until one of the initializers publishes the value.
+    /// Since the returned token registers as a shared reader, writers such as `take` or `replace` wait until it is dropped;
+    /// see [`Initialized`] for details.
+    ///
+    /// Note that the method blocks forever if the option never becomes Some.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let config = ConcurrentOption::<String>::none();
+    ///
+    /// std::thread::scope(|s| {
+    ///     for _ in 0..4 {
+    ///         s.spawn(|| {
+    ///             let value = config.wait_for_some();
+    ///             assert_eq!(value.as_str(), "ready");
+    ///         });
+    ///     }
+    ///
+    ///     s.spawn(|| {
+    ///         std::thread::sleep(std::time::Duration::from_millis(10));
+    ///         config.initialize_if_none("ready".to_string());
+    ///     });
+    /// });
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn wait_for_some(&self) -> Initialized<'_, T> {
+        loop {
+            if let Some(initialized) = self.initialized() {
+                return initialized;
+            }
+            block_until(&self.state, || {
+                matches!(self.state.load(ORDER_LOAD), SOME | FROZEN)
+            });
+        }
+    }
+
+    /// Blocks the current thread until the option is observed to be Some, and then takes the value out of the option,
+    /// leaving a None in its place.
+    ///
+    /// The thread first spins and then yields while the option is None or reserved by another thread;
+    /// finally, it parks until the state of the option changes.
+    /// With the `futex` or `parking_lot` feature, the thread rather sleeps in the kernel or the parking lot, respectively.
+    ///
+    /// Together with [`wait_for_none`], this turns the option into a rendezvous cell for producer-consumer handoff:
+    /// each value is taken by exactly one of the consumers waiting on the option.
+    ///
+    /// Note that the method blocks forever if the option never becomes Some.
+    ///
+    /// [`wait_for_none`]: ConcurrentOption::wait_for_none
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen, since its value can never be taken through a shared reference.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let slot = ConcurrentOption::none();
+    /// let num_items = 100;
+    ///
+    /// let consumed: Vec<_> = std::thread::scope(|s| {
+    ///     s.spawn(|| {
+    ///         for i in 0..num_items {
+    ///             slot.wait_for_none();
+    ///             assert!(slot.initialize_if_none(i)); // single producer
+    ///         }
+    ///     });
+    ///
+    ///     let consumer = s.spawn(|| (0..num_items).map(|_| slot.wait_and_take()).collect());
+    ///     consumer.join().unwrap()
+    /// });
+    ///
+    /// assert_eq!(consumed, (0..num_items).collect::<Vec<_>>());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn wait_and_take(&self) -> T {
+        loop {
+            if let Some(value) = self.take() {
+                return value;
+            }
+            self.assert_not_frozen();
+            block_until(&self.state, || {
+                matches!(self.state.load(ORDER_LOAD), SOME | FROZEN)
+            });
+        }
+    }
+
+    /// Returns the number of threads parked in the waiting methods of the option, such as `wait_for_some` or `wait_and_take`;
+    /// and with the `async` feature, the number of pending tasks registered on the option, such as the one of `initialized_async`.
+    ///
+    /// Threads which are still spinning or yielding before parking are not counted.
+    ///
+    /// This allows a producer to tell whether or not anybody is waiting on the option; note that every release of the option
+    /// notifies its waiters regardless, and the count is only a snapshot which might change right after it is read.
+    ///
+    /// With the `futex` or `parking_lot` feature, the parked threads are counted per bucket of options;
+    /// hence, the count might also include the threads waiting on other options sharing the bucket.
+    /// Zero still guarantees that no thread was parked on this option.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::<String>::none();
+    /// assert_eq!(x.waiter_count(), 0);
+    ///
+    /// std::thread::scope(|s| {
+    ///     let waiter = s.spawn(|| x.wait_and_take());
+    ///
+    ///     while x.waiter_count() == 0 {
+    ///         std::thread::yield_now();
+    ///     }
+    ///
+    ///     assert!(x.initialize_if_none(3.to_string()));
+    ///     assert_eq!(waiter.join().unwrap(), 3.to_string());
+    /// });
+    ///
+    /// assert_eq!(x.waiter_count(), 0);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn waiter_count(&self) -> usize {
+        crate::parking::waiter_count(&self.state)
+    }
+
+    /// Returns a future which resolves once the option is observed to be None.
+    ///
+    /// This is the async form of [`wait_for_none`]. Rather than asking the executor to poll it repeatedly,
+    /// the pending future is woken by the state transitions of the option.
+    ///
+    /// [`wait_for_none`]: ConcurrentOption::wait_for_none
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use std::{future::Future, pin::pin, task::*};
+    ///
+    /// let slot = ConcurrentOption::some(42);
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let mut waiting = pin!(slot.wait_for_none_async());
+    /// assert_eq!(waiting.as_mut().poll(&mut cx), Poll::Pending);
+    ///
+    /// assert_eq!(slot.take(), Some(42));
+    /// assert_eq!(waiting.as_mut().poll(&mut cx), Poll::Ready(()));
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn wait_for_none_async(&self) -> WaitForNone<'_, T> {
+        WaitForNone {
+            option: self,
+            waker: None,
+        }
+    }
+}
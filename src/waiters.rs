@@ -0,0 +1,192 @@
+//! Lists of the threads parked and the tasks waiting on the states of concurrent options.
+//!
+//! A thread blocked in one of the waiting methods, such as `wait_for_some` or `wait_and_take`, registers itself
+//! in the list of the state it waits on and parks. Similarly, with the `async` feature, a pending future such as
+//! the one of `initialized_async` registers the waker of its task. Every release of a state, such as the ones of
+//! `initialize_if_none`, `replace` or `take`, wakes exactly the threads and tasks registered for that state.
+//!
+//! Lists are kept in a fixed table of buckets indexed by the addresses of the states, each guarded by a mutex.
+//! A release only loads the number of waiters of its bucket, without any fence, unless there exists a registered
+//! waiter; hence, it does not touch the mutex in the common case where nobody waits.
+//! Options sharing a bucket never wake up each other's waiters since each waiter records the address of its state.
+
+use crate::atomic::AtomicU8;
+use core::sync::atomic::{fence, AtomicUsize, Ordering};
+use std::{
+    sync::{Mutex, MutexGuard},
+    vec::Vec,
+};
+
+const NUM_BUCKETS: usize = 64;
+
+enum Wake {
+    #[cfg(not(any(feature = "futex", feature = "parking_lot")))]
+    Thread(std::thread::Thread),
+    #[cfg(feature = "async")]
+    Waker(core::task::Waker),
+}
+
+impl Wake {
+    fn wake(&self) {
+        match self {
+            #[cfg(not(any(feature = "futex", feature = "parking_lot")))]
+            Self::Thread(thread) => thread.unpark(),
+            #[cfg(feature = "async")]
+            Self::Waker(waker) => waker.wake_by_ref(),
+        }
+    }
+}
+
+struct Waiter {
+    address: usize,
+    wake: Wake,
+}
+
+struct Bucket {
+    num_waiters: AtomicUsize,
+    waiters: Mutex<Vec<Waiter>>,
+}
+
+impl Bucket {
+    fn lock(&self) -> MutexGuard<'_, Vec<Waiter>> {
+        // the list is never left in an inconsistent state; hence, it is safe to recover from poisoning
+        self.waiters.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Keeps the waiters satisfying the predicate `f`.
+    fn retain<F>(&self, f: F)
+    where
+        F: FnMut(&Waiter) -> bool,
+    {
+        let mut waiters = self.lock();
+        waiters.retain(f);
+        self.num_waiters.store(waiters.len(), Ordering::Relaxed);
+    }
+
+    /// Registers the waiter; must be followed by a check of the state,
+    /// which either observes the release or is observed by the releasing thread in `notify`.
+    fn register(&self, waiter: Waiter) {
+        {
+            let mut waiters = self.lock();
+            waiters.push(waiter);
+            self.num_waiters.store(waiters.len(), Ordering::SeqCst);
+        }
+        // pairs with the sequentially consistent release of the state and the load of the count in `notify`
+        fence(Ordering::SeqCst);
+    }
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY_BUCKET: Bucket = Bucket {
+    num_waiters: AtomicUsize::new(0),
+    waiters: Mutex::new(Vec::new()),
+};
+
+static BUCKETS: [Bucket; NUM_BUCKETS] = [EMPTY_BUCKET; NUM_BUCKETS];
+
+fn address(state: &AtomicU8) -> usize {
+    state as *const AtomicU8 as usize
+}
+
+fn bucket(address: usize) -> &'static Bucket {
+    let hash = address.wrapping_mul(0x9E37_79B9) >> 16;
+    &BUCKETS[hash % NUM_BUCKETS]
+}
+
+/// Parks the current thread until the state is changed from `current`, unless `done` returns true
+/// after the registration; might return spuriously.
+#[cfg(not(any(feature = "futex", feature = "parking_lot")))]
+pub(crate) fn park<F>(state: &AtomicU8, current: crate::states::StateU8, done: &mut F)
+where
+    F: FnMut() -> bool,
+{
+    let address = address(state);
+    let bucket = bucket(address);
+    let thread = std::thread::current();
+
+    bucket.register(Waiter {
+        address,
+        wake: Wake::Thread(thread.clone()),
+    });
+    if state.load(Ordering::Relaxed) == current && !done() {
+        std::thread::park();
+    }
+
+    bucket.retain(|x| match &x.wake {
+        Wake::Thread(t) => x.address != address || t.id() != thread.id(),
+        #[cfg(feature = "async")]
+        Wake::Waker(_) => true,
+    });
+}
+
+/// Registers the `waker` to be woken by the next release of the `state`, replacing an earlier registration of the same task.
+///
+/// The caller must check the state right after the registration; a release happening before the registration
+/// is observed by this check, while any later release wakes the task.
+#[cfg(feature = "async")]
+pub(crate) fn register_waker(state: &AtomicU8, waker: &core::task::Waker) {
+    let address = address(state);
+    let bucket = bucket(address);
+    bucket.retain(|x| !is_waker_of(x, address, waker));
+    bucket.register(Waiter {
+        address,
+        wake: Wake::Waker(waker.clone()),
+    });
+}
+
+/// Removes the registration of the `waker` on the `state`, if any.
+#[cfg(feature = "async")]
+pub(crate) fn deregister_waker(state: &AtomicU8, waker: &core::task::Waker) {
+    let address = address(state);
+    let bucket = bucket(address);
+    if bucket.num_waiters.load(Ordering::Relaxed) > 0 {
+        bucket.retain(|x| !is_waker_of(x, address, waker));
+    }
+}
+
+#[cfg(feature = "async")]
+fn is_waker_of(waiter: &Waiter, address: usize, waker: &core::task::Waker) -> bool {
+    match &waiter.wake {
+        Wake::Waker(w) => waiter.address == address && w.will_wake(waker),
+        #[cfg(not(any(feature = "futex", feature = "parking_lot")))]
+        Wake::Thread(_) => false,
+    }
+}
+
+/// Returns the number of threads and tasks registered on the `state`.
+pub(crate) fn waiter_count(state: &AtomicU8) -> usize {
+    let address = address(state);
+    let bucket = bucket(address);
+    match bucket.num_waiters.load(Ordering::SeqCst) {
+        0 => 0,
+        _ => bucket
+            .lock()
+            .iter()
+            .filter(|x| x.address == address)
+            .count(),
+    }
+}
+
+/// Wakes up the threads and tasks waiting on the `state`; must be called right after the state is released or changed
+/// by a sequentially consistent operation.
+pub(crate) fn notify(state: &AtomicU8) {
+    let address = address(state);
+    let bucket = bucket(address);
+
+    // a waiter which is not observed here observes the release when it checks the state after its registration
+    if bucket.num_waiters.load(Ordering::SeqCst) == 0 {
+        return;
+    }
+
+    // waiters are woken after the lock is released, since a waker might re-register right away
+    let woken: Vec<_> = {
+        let mut waiters = bucket.lock();
+        let (woken, rest) = waiters.drain(..).partition(|x| x.address == address);
+        *waiters = rest;
+        bucket.num_waiters.store(waiters.len(), Ordering::Relaxed);
+        woken
+    };
+    for waiter in woken {
+        waiter.wake.wake();
+    }
+}
@@ -0,0 +1,64 @@
+use crate::generation::Generation;
+use core::sync::atomic::Ordering;
+
+/// A cheap change detector on a [`ConcurrentOption`].
+///
+/// Every mutation on the concurrent option increments its generation.
+/// The watcher caches the generation it has last seen and compares it against the current generation
+/// of the option with a single `Relaxed` load.
+///
+/// A watcher is created by [`ConcurrentOption::watcher`], which is available with the `generation` feature.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`ConcurrentOption::watcher`]: crate::ConcurrentOption::watcher
+pub struct Watcher<'a> {
+    generation: &'a Generation,
+    seen: usize,
+}
+
+impl<'a> Watcher<'a> {
+    pub(crate) fn new(generation: &'a Generation) -> Self {
+        let seen = generation.load(Ordering::Relaxed);
+        Self { generation, seen }
+    }
+
+    /// Returns whether or not the option has been mutated since the watcher is created or since the last call to `has_changed`.
+    ///
+    /// Mutations are conservatively detected; i.e., a method which obtains mutable access to the value,
+    /// such as `update_if_some` or `take_if`, is reported as a change even if the value is not actually altered.
+    ///
+    /// Since the check is a single `Relaxed` load, it is suitable for frequent polling, such as once per frame;
+    /// however, it does not synchronize with the mutation. The option must be read with one of its
+    /// thread safe methods in order to observe the new value.
+    ///
+    /// The generation is a wrapping `usize` counter; hence, mutations are missed only if their number
+    /// between two checks is an exact multiple of `2^usize::BITS`, which is only practical on 16-bit targets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    /// let mut watcher = x.watcher();
+    /// assert!(!watcher.has_changed());
+    ///
+    /// _ = x.map(|x| x + 1); // reads are not changes
+    /// assert!(!watcher.has_changed());
+    ///
+    /// x.update_if_some(|x| *x += 1);
+    /// assert!(watcher.has_changed());
+    /// assert!(!watcher.has_changed());
+    ///
+    /// _ = x.take();
+    /// _ = x.replace(7);
+    /// assert!(watcher.has_changed());
+    /// assert!(!watcher.has_changed());
+    /// ```
+    pub fn has_changed(&mut self) -> bool {
+        let current = self.generation.load(Ordering::Relaxed);
+        let changed = current != self.seen;
+        self.seen = current;
+        changed
+    }
+}
@@ -0,0 +1,325 @@
+use crate::{custom_state::is_custom, states::*, stats::ExitReason, ConcurrentOption, IntoOption};
+use core::{mem::MaybeUninit, sync::atomic::Ordering};
+
+/// An atomic word which can be carried alongside the value of a [`ConcurrentOptionWith`] as its metadata.
+///
+/// It is implemented for the atomic types of `core::sync::atomic` available on the target.
+pub trait AtomicMetadata {
+    /// Plain value type of the atomic metadata, such as `u64` for `AtomicU64`.
+    type Value: Copy;
+
+    /// Creates the atomic metadata with the given initial `value`.
+    fn new(value: Self::Value) -> Self;
+
+    /// Loads the value of the metadata with the given `order`.
+    fn load(&self, order: Ordering) -> Self::Value;
+
+    /// Stores the `value` to the metadata with the given `order`.
+    fn store(&self, value: Self::Value, order: Ordering);
+}
+
+macro_rules! impl_atomic_metadata {
+    ($($width:literal: $atomic:ident => $t:ty),* $(,)?) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            impl AtomicMetadata for core::sync::atomic::$atomic {
+                type Value = $t;
+
+                #[inline(always)]
+                fn new(value: Self::Value) -> Self {
+                    Self::new(value)
+                }
+
+                #[inline(always)]
+                fn load(&self, order: Ordering) -> Self::Value {
+                    self.load(order)
+                }
+
+                #[inline(always)]
+                fn store(&self, value: Self::Value, order: Ordering) {
+                    self.store(value, order)
+                }
+            }
+        )*
+    };
+}
+
+impl_atomic_metadata!(
+    "8": AtomicBool => bool,
+    "8": AtomicU8 => u8,
+    "8": AtomicI8 => i8,
+    "16": AtomicU16 => u16,
+    "16": AtomicI16 => i16,
+    "32": AtomicU32 => u32,
+    "32": AtomicI32 => i32,
+    "64": AtomicU64 => u64,
+    "64": AtomicI64 => i64,
+    "ptr": AtomicUsize => usize,
+    "ptr": AtomicIsize => isize,
+);
+
+/// A [`ConcurrentOption`] carrying a small atomic metadata word, such as a timestamp or a retry count,
+/// which is updated under the same reservation as the value.
+///
+/// Methods suffixed with **with_metadata** mutate or read the value together with the metadata;
+/// therefore, the value and the metadata observed by these methods are always consistent.
+///
+/// The metadata can also be loaded on its own, without a reservation, by the [`metadata`] method.
+///
+/// [`metadata`]: ConcurrentOptionWith::metadata
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_concurrent_option::*;
+/// use core::sync::atomic::AtomicU64;
+///
+/// let x = ConcurrentOptionWith::<String, AtomicU64>::none(0);
+///
+/// _ = x.replace_with_metadata(3.to_string(), 1);
+/// x.update_if_some_with_metadata(|value, retries| {
+///     value.push('!');
+///     *retries += 1;
+/// });
+///
+/// assert_eq!(x.metadata(), 2);
+/// let (value, retries) = x.map_with_metadata(|value, retries| (value.cloned(), retries));
+/// assert_eq!(value, Some("3!".to_string()));
+/// assert_eq!(retries, 2);
+///
+/// assert_eq!(x.take_with_metadata(0), Some("3!".to_string()));
+/// assert_eq!(x.metadata(), 0);
+/// ```
+pub struct ConcurrentOptionWith<T, M: AtomicMetadata> {
+    option: ConcurrentOption<T>,
+    metadata: M,
+}
+
+impl<T, M: AtomicMetadata> ConcurrentOptionWith<T, M> {
+    /// Creates a concurrent option of the Some variant with the given `value` and `metadata`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use core::sync::atomic::AtomicU32;
+    ///
+    /// let x = ConcurrentOptionWith::<_, AtomicU32>::some('a', 42);
+    /// assert_eq!(x.option().map(|x| *x), Some('a'));
+    /// assert_eq!(x.metadata(), 42);
+    /// ```
+    pub fn some(value: T, metadata: M::Value) -> Self {
+        Self {
+            option: ConcurrentOption::some(value),
+            metadata: M::new(metadata),
+        }
+    }
+
+    /// Creates a concurrent option of the None variant with the given `metadata`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use core::sync::atomic::AtomicU32;
+    ///
+    /// let x = ConcurrentOptionWith::<char, AtomicU32>::none(42);
+    /// assert!(x.option().is_none());
+    /// assert_eq!(x.metadata(), 42);
+    /// ```
+    pub fn none(metadata: M::Value) -> Self {
+        Self {
+            option: ConcurrentOption::none(),
+            metadata: M::new(metadata),
+        }
+    }
+
+    /// Returns a reference to the underlying concurrent option.
+    ///
+    /// Note that mutations performed directly on the returned option do not update the metadata.
+    pub fn option(&self) -> &ConcurrentOption<T> {
+        &self.option
+    }
+
+    /// Converts self into the underlying value and metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use core::sync::atomic::AtomicBool;
+    ///
+    /// let x = ConcurrentOptionWith::<_, AtomicBool>::some(7, true);
+    /// assert_eq!(x.into_parts(), (Some(7), true));
+    /// ```
+    pub fn into_parts(self) -> (Option<T>, M::Value) {
+        let metadata = self.metadata.load(Ordering::Relaxed);
+        (self.option.into_option(), metadata)
+    }
+
+    /// Thread safe method which loads the metadata without reserving the option.
+    ///
+    /// The loaded metadata is not necessarily consistent with a value read by a subsequent call;
+    /// [`map_with_metadata`] can be used to read both consistently.
+    ///
+    /// [`map_with_metadata`]: ConcurrentOptionWith::map_with_metadata
+    pub fn metadata(&self) -> M::Value {
+        self.metadata.load(ORDER_LOAD)
+    }
+
+    /// Thread safe method which maps the value (if any) together with the metadata by the function `f`.
+    ///
+    /// Both are read while holding a read handle on the option, or while it is reserved if it is None;
+    /// hence, they are consistent with each other.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use core::sync::atomic::AtomicU8;
+    ///
+    /// let x = ConcurrentOptionWith::<_, AtomicU8>::some(3, 1);
+    /// assert_eq!(x.map_with_metadata(|v, m| v.map(|v| v + m as i32)), Some(4));
+    ///
+    /// let x = ConcurrentOptionWith::<i32, AtomicU8>::none(1);
+    /// assert_eq!(x.map_with_metadata(|v, m| v.map(|v| v + m as i32)), None);
+    /// ```
+    pub fn map_with_metadata<U, F>(&self, f: F) -> U
+    where
+        F: FnOnce(Option<&T>, M::Value) -> U,
+    {
+        let option = &self.option;
+        loop {
+            if let Some(_handle) = option.spin_get_read_handle() {
+                let x = unsafe { MaybeUninit::assume_init_ref(&*option.value.get()) };
+                return f(Some(x), self.metadata.load(ORDER_LOAD));
+            }
+
+            if let Some(_handle) = option.spin_get_handle(NONE, NONE) {
+                return f(None, self.metadata.load(ORDER_LOAD));
+            }
+
+            // custom states are read as None
+            if is_custom(option.state.load(ORDER_LOAD)) {
+                return f(None, self.metadata.load(ORDER_LOAD));
+            }
+        }
+    }
+
+    /// Thread safe method which updates the value (if any) together with the metadata by the function `f`.
+    ///
+    /// Returns whether or not the value is updated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use core::sync::atomic::AtomicU16;
+    ///
+    /// let x = ConcurrentOptionWith::<_, AtomicU16>::some(3, 0);
+    /// assert!(x.update_if_some_with_metadata(|v, m| {
+    ///     *v *= 2;
+    ///     *m += 1;
+    /// }));
+    /// assert_eq!(x.into_parts(), (Some(6), 1));
+    ///
+    /// let x = ConcurrentOptionWith::<i32, AtomicU16>::none(0);
+    /// assert!(!x.update_if_some_with_metadata(|_, m| *m += 1));
+    /// assert_eq!(x.metadata(), 0);
+    /// ```
+    pub fn update_if_some_with_metadata<F>(&self, f: F) -> bool
+    where
+        F: FnOnce(&mut T, &mut M::Value),
+    {
+        let option = &self.option;
+        match option.spin_get_write_handle(SOME, SOME) {
+            Some(_handle) => {
+                let x = unsafe { MaybeUninit::assume_init_mut(&mut *option.value.get()) };
+                let mut metadata = self.metadata.load(ORDER_LOAD);
+                f(x, &mut metadata);
+                self.metadata.store(metadata, ORDER_STORE);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Thread safe method which replaces the value with the given `value` and the metadata with the given `metadata`;
+    /// and returns the old value (if any).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is frozen by [`freeze`], or if it is in a custom state set by [`transition_custom`].
+    ///
+    /// [`freeze`]: crate::ConcurrentOption::freeze
+    /// [`transition_custom`]: crate::ConcurrentOption::transition_custom
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use core::sync::atomic::AtomicU64;
+    ///
+    /// let x = ConcurrentOptionWith::<_, AtomicU64>::none(0);
+    /// assert_eq!(x.replace_with_metadata('a', 10), None);
+    /// assert_eq!(x.replace_with_metadata('b', 20), Some('a'));
+    /// assert_eq!(x.into_parts(), (Some('b'), 20));
+    /// ```
+    pub fn replace_with_metadata(&self, value: T, metadata: M::Value) -> Option<T> {
+        let option = &self.option;
+        loop {
+            if let Some(_handle) = option.spin_get_write_handle(SOME, SOME) {
+                let x = unsafe { (*option.value.get()).assume_init_mut() };
+                let old = core::mem::replace(x, value);
+                self.metadata.store(metadata, ORDER_STORE);
+                option.record_exit(ExitReason::Replaced);
+                return Some(old);
+            }
+
+            if let Some(_handle) = option.spin_get_write_handle(NONE, SOME) {
+                let x = unsafe { &mut *option.value.get() };
+                x.write(value);
+                self.metadata.store(metadata, ORDER_STORE);
+                return None;
+            }
+
+            option.assert_not_frozen();
+            option.assert_not_custom();
+        }
+    }
+
+    /// Thread safe method which takes the value out of the option (if any), leaving a None in its place;
+    /// and sets the metadata to the given `metadata` if the value is taken.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use core::sync::atomic::AtomicI32;
+    ///
+    /// let x = ConcurrentOptionWith::<_, AtomicI32>::some(3, 1);
+    /// assert_eq!(x.take_with_metadata(-1), Some(3));
+    /// assert_eq!(x.metadata(), -1);
+    ///
+    /// assert_eq!(x.take_with_metadata(-2), None);
+    /// assert_eq!(x.metadata(), -1);
+    /// ```
+    pub fn take_with_metadata(&self, metadata: M::Value) -> Option<T> {
+        let option = &self.option;
+        match option.spin_get_write_handle(SOME, NONE) {
+            Some(_handle) => {
+                option.record_exit(ExitReason::Taken);
+                self.metadata.store(metadata, ORDER_STORE);
+                let x = unsafe { &*option.value.get() };
+                Some(unsafe { MaybeUninit::assume_init_read(x) })
+            }
+            None => None,
+        }
+    }
+}
+
+impl<T, M: AtomicMetadata> From<ConcurrentOptionWith<T, M>> for ConcurrentOption<T> {
+    fn from(value: ConcurrentOptionWith<T, M>) -> Self {
+        value.option
+    }
+}
@@ -1,5 +1,9 @@
-use crate::{states::*, ConcurrentOption};
-use core::{ops::Deref, sync::atomic::Ordering};
+use crate::{parking::snooze, states::*, ConcurrentOption, DisplaySnapshot, LoadOrdering};
+use core::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+    sync::atomic::Ordering,
+};
 
 impl<T> ConcurrentOption<T> {
     /// Loads and returns the concurrent state of the option with the given `order`.
@@ -8,16 +12,15 @@ impl<T> ConcurrentOption<T> {
     ///
     /// ```
     /// use orx_concurrent_option::*;
-    /// use core::sync::atomic::Ordering;
     ///
     /// let x: ConcurrentOption<u32> = ConcurrentOption::some(2);
-    /// assert_eq!(x.state(Ordering::Relaxed), State::Some);
+    /// assert_eq!(x.state(LoadOrdering::Relaxed), State::Some);
     ///
     /// let x: ConcurrentOption<u32> = ConcurrentOption::none();
-    /// assert_eq!(x.state(Ordering::SeqCst), State::None);
+    /// assert_eq!(x.state(LoadOrdering::SeqCst), State::None);
     /// ```
-    pub fn state(&self, order: Ordering) -> State {
-        State::new(self.state.load(order))
+    pub fn state(&self, order: LoadOrdering) -> State {
+        State::new(self.state.load(order.into()))
     }
 
     /// Returns `true` if the option is a Some variant.
@@ -26,7 +29,6 @@ impl<T> ConcurrentOption<T> {
     ///
     /// ```
     /// use orx_concurrent_option::*;
-    /// use core::sync::atomic::Ordering;
     ///
     /// let x: ConcurrentOption<u32> = ConcurrentOption::some(2);
     /// assert_eq!(x.is_some(), true);
@@ -35,8 +37,8 @@ impl<T> ConcurrentOption<T> {
     /// assert_eq!(x.is_some(), false);
     /// ```
     #[inline]
-    pub fn is_some_with_order(&self, order: Ordering) -> bool {
-        self.state.load(order) == SOME
+    pub fn is_some_with_order(&self, order: LoadOrdering) -> bool {
+        matches!(self.state.load(order.into()), SOME | FROZEN)
     }
 
     /// Returns `true` if the option is a None variant.
@@ -53,13 +55,13 @@ impl<T> ConcurrentOption<T> {
     /// assert_eq!(x.is_none(), true);
     /// ```
     #[inline]
-    pub fn is_none_with_order(&self, order: Ordering) -> bool {
-        self.state.load(order) != SOME
+    pub fn is_none_with_order(&self, order: LoadOrdering) -> bool {
+        !matches!(self.state.load(order.into()), SOME | FROZEN)
     }
 
     /// Converts from `&Option<T>` to `Option<&T>`.
     ///
-    /// Depending on requirement of the use case, `Relaxed`, `Acquire` or `SeqCst` can be used as the `order`.
+    /// Depending on requirement of the use case, `Relaxed`, `Acquire` or `SeqCst` can be used as the [`LoadOrdering`].
     ///
     /// # Safety
     ///
@@ -69,27 +71,26 @@ impl<T> ConcurrentOption<T> {
     ///
     /// * It is safe to use this method if the returned reference is discarded (miri would still complain).
     /// * It is also safe to use this method if the caller is able to guarantee that there exist
-    /// no concurrent writes while holding onto this reference.
+    ///   no concurrent writes while holding onto this reference.
     ///   * One such case is using `as_ref` together with `initialize_when_none` method.
-    /// This is perfectly safe since the value will be written only once,
-    /// and `as_ref` returns a valid reference only after the value is initialized.
+    ///     This is perfectly safe since the value will be written only once,
+    ///     and `as_ref` returns a valid reference only after the value is initialized.
     /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use orx_concurrent_option::*;
-    /// use core::sync::atomic::Ordering;
     ///
     /// let x = ConcurrentOption::some(3.to_string());
-    /// assert_eq!(unsafe { x.as_ref_with_order(Ordering::Relaxed) }, Some(&3.to_string()));
+    /// assert_eq!(unsafe { x.as_ref_with_order(LoadOrdering::Relaxed) }, Some(&3.to_string()));
     ///
     /// _ = x.take();
-    /// assert_eq!(unsafe { x.as_ref_with_order(Ordering::Acquire) }, None);
+    /// assert_eq!(unsafe { x.as_ref_with_order(LoadOrdering::Acquire) }, None);
     /// ```
-    pub unsafe fn as_ref_with_order(&self, order: Ordering) -> Option<&T> {
-        match self.state.load(order) {
-            SOME => {
+    pub unsafe fn as_ref_with_order(&self, order: LoadOrdering) -> Option<&T> {
+        match self.state.load(order.into()) {
+            SOME | FROZEN => {
                 let x = &*self.value.get();
                 Some(x.assume_init_ref())
             }
@@ -102,7 +103,7 @@ impl<T> ConcurrentOption<T> {
     /// Leaves the original Option in-place, creating a new one with a reference
     /// to the original one, additionally coercing the contents via [`Deref`].
     ///
-    /// Depending on requirement of the use case, `Relaxed`, `Acquire` or `SeqCst` can be used as the `order`.
+    /// Depending on requirement of the use case, `Relaxed`, `Acquire` or `SeqCst` can be used as the [`LoadOrdering`].
     ///
     /// # Safety
     ///
@@ -112,33 +113,32 @@ impl<T> ConcurrentOption<T> {
     ///
     /// * It is safe to use this method if the returned reference is discarded (miri would still complain).
     /// * It is also safe to use this method if the caller is able to guarantee that there exist
-    /// no concurrent writes while holding onto this reference.
+    ///   no concurrent writes while holding onto this reference.
     ///   * One such case is using `as_ref` together with `initialize_when_none` method.
-    /// This is perfectly safe since the value will be written only once,
-    /// and `as_ref` returns a valid reference only after the value is initialized.
+    ///     This is perfectly safe since the value will be written only once,
+    ///     and `as_ref` returns a valid reference only after the value is initialized.
     /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use orx_concurrent_option::*;
-    /// use core::sync::atomic::Ordering;
     ///
     /// unsafe
     /// {
     ///     let x: ConcurrentOption<String> = ConcurrentOption::some("hey".to_owned());
-    ///     assert_eq!(x.as_deref_with_order(Ordering::Acquire), Some("hey"));
+    ///     assert_eq!(x.as_deref_with_order(LoadOrdering::Acquire), Some("hey"));
     ///
     ///     let x: ConcurrentOption<String> = ConcurrentOption::none();
-    ///     assert_eq!(x.as_deref_with_order(Ordering::SeqCst), None);
+    ///     assert_eq!(x.as_deref_with_order(LoadOrdering::SeqCst), None);
     /// }
     /// ```
-    pub unsafe fn as_deref_with_order(&self, order: Ordering) -> Option<&<T as Deref>::Target>
+    pub unsafe fn as_deref_with_order(&self, order: LoadOrdering) -> Option<&<T as Deref>::Target>
     where
         T: Deref,
     {
-        match self.state.load(order) {
-            SOME => {
+        match self.state.load(order.into()) {
+            SOME | FROZEN => {
                 let x = &*self.value.get();
                 Some(x.assume_init_ref())
             }
@@ -146,11 +146,61 @@ impl<T> ConcurrentOption<T> {
         }
     }
 
+    /// Converts from `Option<T>` (or `&Option<T>`) to `Option<&mut T::Target>` by loading the state with the desired `order`.
+    ///
+    /// Leaves the original Option in-place, creating a new one containing a mutable reference to
+    /// the inner type's [`Deref::Target`] type.
+    ///
+    /// # Safety
+    ///
+    /// Note that creating a valid reference part of this method is thread safe.
+    ///
+    /// The method is `unsafe` due to the returned mutable reference to the underlying value.
+    ///
+    /// * It is safe to use this method if the returned mutable reference is discarded (miri would still complain).
+    /// * It is also safe to use this method if the caller is able to guarantee that there exist
+    ///   no concurrent reads or writes while holding onto this reference.
+    /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// unsafe
+    /// {
+    ///     let x: ConcurrentOption<String> = ConcurrentOption::some("hey".to_owned());
+    ///     if let Some(x) = x.as_deref_mut_with_order(LoadOrdering::Acquire) {
+    ///         x.make_ascii_uppercase();
+    ///     }
+    ///     assert_eq!(x.as_deref_with_order(LoadOrdering::Acquire), Some("HEY"));
+    ///
+    ///     let x: ConcurrentOption<String> = ConcurrentOption::none();
+    ///     assert_eq!(x.as_deref_mut_with_order(LoadOrdering::SeqCst), None);
+    /// }
+    /// ```
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn as_deref_mut_with_order(
+        &self,
+        order: LoadOrdering,
+    ) -> Option<&mut <T as Deref>::Target>
+    where
+        T: DerefMut,
+    {
+        match self.state.load(order.into()) {
+            SOME => {
+                let x = &mut *self.value.get();
+                Some(x.assume_init_mut())
+            }
+            _ => None,
+        }
+    }
+
     /// Returns an iterator over the possibly contained value; yields
     /// * the single element if the option is of Some variant;
     /// * no elements otherwise.
     ///
-    /// Depending on requirement of the use case, `Relaxed`, `Acquire` or `SeqCst` can be used as the `order`.
+    /// Depending on requirement of the use case, `Relaxed`, `Acquire` or `SeqCst` can be used as the [`LoadOrdering`].
     ///
     /// # Safety
     ///
@@ -160,17 +210,16 @@ impl<T> ConcurrentOption<T> {
     ///
     /// * It is safe to use this method if the returned reference is discarded (miri would still complain).
     /// * It is also safe to use this method if the caller is able to guarantee that there exist
-    /// no concurrent writes while holding onto this reference.
+    ///   no concurrent writes while holding onto this reference.
     ///   * One such case is using `as_ref` together with `initialize_when_none` method.
-    /// This is perfectly safe since the value will be written only once,
-    /// and `as_ref` returns a valid reference only after the value is initialized.
+    ///     This is perfectly safe since the value will be written only once,
+    ///     and `as_ref` returns a valid reference only after the value is initialized.
     /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use orx_concurrent_option::*;
-    /// use core::sync::atomic::Ordering;
     ///
     /// fn validate<'a>(mut iter: impl ExactSizeIterator<Item = &'a String>) {
     ///     assert_eq!(iter.len(), 0);
@@ -181,16 +230,51 @@ impl<T> ConcurrentOption<T> {
     /// let x = ConcurrentOption::<String>::none();
     /// unsafe
     /// {
-    /// validate(x.iter_with_order(Ordering::SeqCst));
-    ///     validate(x.iter_with_order(Ordering::Relaxed).rev());
+    /// validate(x.iter_with_order(LoadOrdering::SeqCst));
+    ///     validate(x.iter_with_order(LoadOrdering::Relaxed).rev());
     ///     validate((&x).into_iter());
     /// }
     /// ```
-    pub unsafe fn iter_with_order(&self, order: Ordering) -> crate::iter::Iter<'_, T> {
+    pub unsafe fn iter_with_order(&self, order: LoadOrdering) -> crate::iter::Iter<'_, T> {
         let maybe = unsafe { self.as_ref_with_order(order) };
         crate::iter::Iter { maybe }
     }
 
+    /// Converts the concurrent option into an iterator over the possibly contained value; yields
+    /// * the single element if the option is of Some variant;
+    /// * no elements otherwise.
+    ///
+    /// Since the option is owned, no atomic reservation is required; the state is only loaded once with the given `order`.
+    ///
+    /// Note that the `IntoIterator` trait implementation converts the concurrent option with the `Relaxed` ordering.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3.to_string());
+    /// let mut iter = x.into_iter_with_order(LoadOrdering::Acquire);
+    /// assert_eq!(iter.len(), 1);
+    /// assert_eq!(iter.next(), Some(3.to_string()));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// let x = ConcurrentOption::<String>::none();
+    /// let mut iter = x.into_iter_with_order(LoadOrdering::SeqCst);
+    /// assert_eq!(iter.len(), 0);
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn into_iter_with_order(self, order: LoadOrdering) -> core::option::IntoIter<T> {
+        let value = match self.state.load(order.into()) {
+            SOME | FROZEN => {
+                self.state.store(NONE, Ordering::Relaxed);
+                Some(unsafe { (*self.value.get()).assume_init_read() })
+            }
+            _ => None,
+        };
+        value.into_iter()
+    }
+
     /// Clones the concurrent option with the desired `order` into an Option.
     ///
     /// Note that the `Clone` trait implementation clones the concurrent option with the default ordering.
@@ -199,19 +283,157 @@ impl<T> ConcurrentOption<T> {
     ///
     /// ```rust
     /// use orx_concurrent_option::*;
-    /// use core::sync::atomic::Ordering;
     ///
     /// let mut x = ConcurrentOption::some(42);
-    /// let y = x.clone_with_order(Ordering::SeqCst);
+    /// let y = x.clone_with_order(LoadOrdering::SeqCst);
     /// assert_eq!(x.take(), y);
     /// ```
-    pub fn clone_with_order(&self, order: Ordering) -> Option<T>
+    pub fn clone_with_order(&self, order: LoadOrdering) -> Option<T>
     where
         T: Clone,
     {
         unsafe { self.as_ref_with_order(order) }.cloned()
     }
 
+    /// Thread safe method which returns a clone of the value if the option is of Some variant; None otherwise.
+    ///
+    /// The state is first loaded with the desired `order`; None is returned immediately without waiting for a read handle
+    /// if it is observed to be None. Otherwise, the value is cloned while holding a read handle, as in [`get_cloned`].
+    ///
+    /// [`get_cloned`]: ConcurrentOption::get_cloned
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(12.to_string());
+    /// assert_eq!(x.get_cloned_with_order(LoadOrdering::Acquire), Some(12.to_string()));
+    ///
+    /// let x = ConcurrentOption::<String>::none();
+    /// assert_eq!(x.get_cloned_with_order(LoadOrdering::Relaxed), None);
+    /// ```
+    pub fn get_cloned_with_order(&self, order: LoadOrdering) -> Option<T>
+    where
+        T: Clone,
+    {
+        match self.state.load(order.into()) {
+            NONE => None,
+            _ => self.get_cloned(),
+        }
+    }
+
+    /// Thread safe method which returns a copy of the value if the option is of Some variant; None otherwise.
+    ///
+    /// The state is first loaded with the desired `order`; None is returned immediately without waiting for a read handle
+    /// if it is observed to be None. Otherwise, the value is copied while holding a read handle, as in [`get_copied`].
+    ///
+    /// [`get_copied`]: ConcurrentOption::get_copied
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(12);
+    /// assert_eq!(x.get_copied_with_order(LoadOrdering::Acquire), Some(12));
+    ///
+    /// let x = ConcurrentOption::<i32>::none();
+    /// assert_eq!(x.get_copied_with_order(LoadOrdering::SeqCst), None);
+    /// ```
+    pub fn get_copied_with_order(&self, order: LoadOrdering) -> Option<T>
+    where
+        T: Copy,
+    {
+        match self.state.load(order.into()) {
+            NONE => None,
+            _ => self.get_copied(),
+        }
+    }
+
+    /// Formats the option using the given formatter `f`, loading its state with the desired `order`.
+    ///
+    /// Note that the `Debug` trait implementation reserves the option while formatting.
+    /// This method, on the other hand, is a best-effort alternative which does not acquire the reservation.
+    ///
+    /// See also [`snapshot`] to obtain a value which can directly be used with formatting macros.
+    ///
+    /// [`snapshot`]: ConcurrentOption::snapshot
+    ///
+    /// # Safety
+    ///
+    /// Since the option is not reserved, the value might be concurrently mutated while being formatted.
+    ///
+    /// * It is safe to use this method if the caller is able to guarantee that there exist
+    ///   no concurrent writes while formatting.
+    ///   * One such case is using `fmt_with_order` together with the `initialize_if_none` method.
+    /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    /// use core::fmt;
+    ///
+    /// struct Acquired<'a>(&'a ConcurrentOption<String>);
+    ///
+    /// impl fmt::Debug for Acquired<'_> {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         unsafe { self.0.fmt_with_order(f, LoadOrdering::Acquire) }
+    ///     }
+    /// }
+    ///
+    /// let x = ConcurrentOption::some(3.to_string());
+    /// assert_eq!(format!("{:?}", Acquired(&x)), "ConcurrentSome(\"3\")");
+    /// ```
+    pub unsafe fn fmt_with_order(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        order: LoadOrdering,
+    ) -> core::fmt::Result
+    where
+        T: Debug,
+    {
+        let maybe = unsafe { self.as_ref_with_order(order) };
+        write!(f, "Concurrent{:?}", maybe)
+    }
+
+    /// Creates a [`DisplaySnapshot`] of the option which formats the option with the desired `order`
+    /// and without acquiring the reservation.
+    ///
+    /// See [`fmt_with_order`] for details.
+    ///
+    /// [`fmt_with_order`]: ConcurrentOption::fmt_with_order
+    ///
+    /// # Safety
+    ///
+    /// Since the option is not reserved, the value might be concurrently mutated while the snapshot is formatted.
+    ///
+    /// * It is safe to use this method if the caller is able to guarantee that there exist
+    ///   no concurrent writes while formatting the snapshot.
+    ///   * One such case is using `snapshot` together with the `initialize_if_none` method.
+    /// * Otherwise, it will lead to an **Undefined Behavior** due to data race.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3.to_string());
+    /// assert_eq!(format!("{:?}", unsafe { x.snapshot(LoadOrdering::Acquire) }), "ConcurrentSome(\"3\")");
+    /// assert_eq!(format!("{}", unsafe { x.snapshot(LoadOrdering::Acquire) }), "ConcurrentSome(3)");
+    ///
+    /// let x = ConcurrentOption::<String>::none();
+    /// assert_eq!(format!("{:?}", unsafe { x.snapshot(LoadOrdering::SeqCst) }), "ConcurrentNone");
+    /// assert_eq!(format!("{}", unsafe { x.snapshot(LoadOrdering::SeqCst) }), "ConcurrentNone");
+    /// ```
+    pub unsafe fn snapshot(&self, order: LoadOrdering) -> DisplaySnapshot<'_, T> {
+        DisplaySnapshot {
+            option: self,
+            order,
+        }
+    }
+
     /// Returns whether or not self is equal to the `other` with the desired `order`.
     ///
     /// Note that the `PartialEq` trait implementation checks equality with the default ordering.
@@ -220,13 +442,12 @@ impl<T> ConcurrentOption<T> {
     ///
     /// ```rust
     /// use orx_concurrent_option::*;
-    /// use core::sync::atomic::Ordering;
     ///
     /// let x = ConcurrentOption::some(3);
     /// let y = ConcurrentOption::some(7);
     /// let z = ConcurrentOption::<i32>::none();
     ///
-    /// let o = Ordering::SeqCst;
+    /// let o = LoadOrdering::SeqCst;
     ///
     /// assert!(x.eq_with_order(&x, o));
     /// assert!(!x.eq_with_order(&y, o));
@@ -236,7 +457,7 @@ impl<T> ConcurrentOption<T> {
     /// assert!(!z.eq_with_order(&y, o));
     /// assert!(z.eq_with_order(&z, o));
     /// ```
-    pub fn eq_with_order(&self, other: &Self, order: Ordering) -> bool
+    pub fn eq_with_order(&self, other: &Self, order: LoadOrdering) -> bool
     where
         T: PartialEq,
     {
@@ -249,6 +470,52 @@ impl<T> ConcurrentOption<T> {
         }
     }
 
+    /// Returns whether or not the current value of the option is equal to the `snapshot`, such as one taken earlier by
+    /// [`get_cloned`], where the state of the option is loaded with the desired `order`.
+    ///
+    /// Unlike [`eq_with_order`], the comparison is made while no mutation can take place on the option;
+    /// hence, this method can be used as the validation step of optimistic concurrency.
+    ///
+    /// Note that the `eq_snapshot` method uses the default ordering.
+    ///
+    /// [`get_cloned`]: ConcurrentOption::get_cloned
+    /// [`eq_with_order`]: ConcurrentOption::eq_with_order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    /// let o = LoadOrdering::SeqCst;
+    ///
+    /// let snapshot = x.get_cloned();
+    /// assert!(x.eq_snapshot_with_order(&snapshot, o));
+    ///
+    /// x.update_if_some(|x| *x += 1);
+    /// assert!(!x.eq_snapshot_with_order(&snapshot, o));
+    ///
+    /// _ = x.take();
+    /// assert!(x.eq_snapshot_with_order(&None, o));
+    /// ```
+    pub fn eq_snapshot_with_order(&self, snapshot: &Option<T>, order: LoadOrdering) -> bool
+    where
+        T: PartialEq,
+    {
+        loop {
+            match self.state.load(order.into()) {
+                SOME | FROZEN => {
+                    if let Some(_handle) = self.spin_get_read_handle() {
+                        let x = unsafe { (*self.value.get()).assume_init_ref() };
+                        return snapshot.as_ref() == Some(x);
+                    }
+                }
+                RESERVED => snooze(&self.state, RESERVED),
+                _ => return snapshot.is_none(),
+            }
+        }
+    }
+
     /// Returns an ordering between `self` and `other` with the desired `order`.
     ///
     /// Note that the `PartialOrd` trait implementation checks equality with the default ordering.
@@ -263,7 +530,7 @@ impl<T> ConcurrentOption<T> {
     /// let y = ConcurrentOption::some(7);
     /// let z = ConcurrentOption::<i32>::none();
     ///
-    /// let ord = core::sync::atomic::Ordering::SeqCst;
+    /// let ord = LoadOrdering::SeqCst;
     ///
     /// assert_eq!(x.partial_cmp_with_order(&x, ord), Some(Equal));
     /// assert_eq!(x.partial_cmp_with_order(&y, ord), Some(Less));
@@ -280,7 +547,7 @@ impl<T> ConcurrentOption<T> {
     pub fn partial_cmp_with_order(
         &self,
         other: &Self,
-        order: Ordering,
+        order: LoadOrdering,
     ) -> Option<core::cmp::Ordering>
     where
         T: PartialOrd,
@@ -311,7 +578,7 @@ impl<T> ConcurrentOption<T> {
     /// let y = ConcurrentOption::some(7);
     /// let z = ConcurrentOption::<i32>::none();
     ///
-    /// let ord = core::sync::atomic::Ordering::SeqCst;
+    /// let ord = LoadOrdering::SeqCst;
     ///
     /// assert_eq!(x.cmp_with_order(&x, ord), Equal);
     /// assert_eq!(x.cmp_with_order(&y, ord), Less);
@@ -325,7 +592,7 @@ impl<T> ConcurrentOption<T> {
     /// assert_eq!(z.cmp_with_order(&y, ord), Less);
     /// assert_eq!(z.cmp_with_order(&z, ord), Equal);
     /// ```
-    pub fn cmp_with_order(&self, other: &Self, order: Ordering) -> core::cmp::Ordering
+    pub fn cmp_with_order(&self, other: &Self, order: LoadOrdering) -> core::cmp::Ordering
     where
         T: Ord,
     {
@@ -0,0 +1,268 @@
+use crate::{ConcurrentOption, LoadOrdering, State};
+use core::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+    sync::atomic::Ordering,
+};
+
+// unsafe escape hatches of the with_order methods accepting a raw Ordering rather than a LoadOrdering
+
+impl<T> ConcurrentOption<T> {
+    /// Escape hatch of [`state`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// The `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`state`]: ConcurrentOption::state
+    pub unsafe fn state_with_raw_order(&self, order: Ordering) -> State {
+        self.state(unsafe { LoadOrdering::from_raw_unchecked(order) })
+    }
+
+    /// Escape hatch of [`is_some_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// The `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`is_some_with_order`]: ConcurrentOption::is_some_with_order
+    pub unsafe fn is_some_with_raw_order(&self, order: Ordering) -> bool {
+        self.is_some_with_order(unsafe { LoadOrdering::from_raw_unchecked(order) })
+    }
+
+    /// Escape hatch of [`is_none_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// The `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`is_none_with_order`]: ConcurrentOption::is_none_with_order
+    pub unsafe fn is_none_with_raw_order(&self, order: Ordering) -> bool {
+        self.is_none_with_order(unsafe { LoadOrdering::from_raw_unchecked(order) })
+    }
+
+    /// Escape hatch of [`as_ref_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// In addition to the safety requirements of [`as_ref_with_order`], the `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`as_ref_with_order`]: ConcurrentOption::as_ref_with_order
+    pub unsafe fn as_ref_with_raw_order(&self, order: Ordering) -> Option<&T> {
+        unsafe { self.as_ref_with_order(LoadOrdering::from_raw_unchecked(order)) }
+    }
+
+    /// Escape hatch of [`as_deref_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// In addition to the safety requirements of [`as_deref_with_order`], the `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`as_deref_with_order`]: ConcurrentOption::as_deref_with_order
+    pub unsafe fn as_deref_with_raw_order(&self, order: Ordering) -> Option<&<T as Deref>::Target>
+    where
+        T: Deref,
+    {
+        unsafe { self.as_deref_with_order(LoadOrdering::from_raw_unchecked(order)) }
+    }
+
+    /// Escape hatch of [`as_deref_mut_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// In addition to the safety requirements of [`as_deref_mut_with_order`], the `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`as_deref_mut_with_order`]: ConcurrentOption::as_deref_mut_with_order
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn as_deref_mut_with_raw_order(
+        &self,
+        order: Ordering,
+    ) -> Option<&mut <T as Deref>::Target>
+    where
+        T: DerefMut,
+    {
+        unsafe { self.as_deref_mut_with_order(LoadOrdering::from_raw_unchecked(order)) }
+    }
+
+    /// Escape hatch of [`iter_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// In addition to the safety requirements of [`iter_with_order`], the `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`iter_with_order`]: ConcurrentOption::iter_with_order
+    pub unsafe fn iter_with_raw_order(&self, order: Ordering) -> crate::iter::Iter<'_, T> {
+        unsafe { self.iter_with_order(LoadOrdering::from_raw_unchecked(order)) }
+    }
+
+    /// Escape hatch of [`into_iter_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// The `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`into_iter_with_order`]: ConcurrentOption::into_iter_with_order
+    pub unsafe fn into_iter_with_raw_order(self, order: Ordering) -> core::option::IntoIter<T> {
+        self.into_iter_with_order(unsafe { LoadOrdering::from_raw_unchecked(order) })
+    }
+
+    /// Escape hatch of [`clone_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// The `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`clone_with_order`]: ConcurrentOption::clone_with_order
+    pub unsafe fn clone_with_raw_order(&self, order: Ordering) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.clone_with_order(unsafe { LoadOrdering::from_raw_unchecked(order) })
+    }
+
+    /// Escape hatch of [`get_cloned_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// The `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`get_cloned_with_order`]: ConcurrentOption::get_cloned_with_order
+    pub unsafe fn get_cloned_with_raw_order(&self, order: Ordering) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.get_cloned_with_order(unsafe { LoadOrdering::from_raw_unchecked(order) })
+    }
+
+    /// Escape hatch of [`get_copied_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// The `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`get_copied_with_order`]: ConcurrentOption::get_copied_with_order
+    pub unsafe fn get_copied_with_raw_order(&self, order: Ordering) -> Option<T>
+    where
+        T: Copy,
+    {
+        self.get_copied_with_order(unsafe { LoadOrdering::from_raw_unchecked(order) })
+    }
+
+    /// Escape hatch of [`fmt_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// In addition to the safety requirements of [`fmt_with_order`], the `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`fmt_with_order`]: ConcurrentOption::fmt_with_order
+    pub unsafe fn fmt_with_raw_order(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        order: Ordering,
+    ) -> core::fmt::Result
+    where
+        T: Debug,
+    {
+        unsafe { self.fmt_with_order(f, LoadOrdering::from_raw_unchecked(order)) }
+    }
+
+    /// Escape hatch of [`eq_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// The `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`eq_with_order`]: ConcurrentOption::eq_with_order
+    pub unsafe fn eq_with_raw_order(&self, other: &Self, order: Ordering) -> bool
+    where
+        T: PartialEq,
+    {
+        self.eq_with_order(other, unsafe { LoadOrdering::from_raw_unchecked(order) })
+    }
+
+    /// Escape hatch of [`eq_snapshot_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// The `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`eq_snapshot_with_order`]: ConcurrentOption::eq_snapshot_with_order
+    pub unsafe fn eq_snapshot_with_raw_order(&self, snapshot: &Option<T>, order: Ordering) -> bool
+    where
+        T: PartialEq,
+    {
+        self.eq_snapshot_with_order(snapshot, unsafe { LoadOrdering::from_raw_unchecked(order) })
+    }
+
+    /// Escape hatch of [`partial_cmp_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// The `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`partial_cmp_with_order`]: ConcurrentOption::partial_cmp_with_order
+    pub unsafe fn partial_cmp_with_raw_order(
+        &self,
+        other: &Self,
+        order: Ordering,
+    ) -> Option<core::cmp::Ordering>
+    where
+        T: PartialOrd,
+    {
+        self.partial_cmp_with_order(other, unsafe { LoadOrdering::from_raw_unchecked(order) })
+    }
+
+    /// Escape hatch of [`cmp_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// The `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`cmp_with_order`]: ConcurrentOption::cmp_with_order
+    pub unsafe fn cmp_with_raw_order(&self, other: &Self, order: Ordering) -> core::cmp::Ordering
+    where
+        T: Ord,
+    {
+        self.cmp_with_order(other, unsafe { LoadOrdering::from_raw_unchecked(order) })
+    }
+
+    /// Escape hatch of [`get_raw_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// The `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`get_raw_with_order`]: ConcurrentOption::get_raw_with_order
+    pub unsafe fn get_raw_with_raw_order(&self, order: Ordering) -> Option<*const T> {
+        self.get_raw_with_order(unsafe { LoadOrdering::from_raw_unchecked(order) })
+    }
+
+    /// Escape hatch of [`get_raw_mut_with_order`] accepting a raw `Ordering`.
+    ///
+    /// # Safety
+    ///
+    /// The `order` must be one of `Relaxed`, `Acquire` or `SeqCst`;
+    /// see [`LoadOrdering::from_raw_unchecked`].
+    ///
+    /// [`get_raw_mut_with_order`]: ConcurrentOption::get_raw_mut_with_order
+    pub unsafe fn get_raw_mut_with_raw_order(&self, order: Ordering) -> Option<*mut T> {
+        self.get_raw_mut_with_order(unsafe { LoadOrdering::from_raw_unchecked(order) })
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{
+    handle::{Handle, ReadHandle},
+    states::*,
+    ConcurrentOption, Contended, MappedWriteGuard, ReadGuard,
+};
+use core::{
+    fmt::Debug,
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+};
+
+/// A guard providing exclusive mutable access to the value of a [`ConcurrentOption`] of Some variant.
+///
+/// The option is reserved while the guard is alive and the reservation is released as soon as the guard is dropped.
+/// A write guard is obtained by [`ConcurrentOption::lock_mut`] or by upgrading a [`ReadGuard`] with [`ReadGuard::try_upgrade`],
+/// and can be turned back into a read guard by [`WriteGuard::downgrade`];
+/// in both directions, the value cannot be taken or replaced by another thread in between.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`ConcurrentOption::lock_mut`]: crate::ConcurrentOption::lock_mut
+/// [`ReadGuard`]: crate::ReadGuard
+pub struct WriteGuard<'a, T> {
+    pub(crate) handle: Handle<'a>,
+    pub(crate) option: &'a ConcurrentOption<T>,
+}
+
+impl<'a, T> WriteGuard<'a, T> {
+    /// Downgrades the write guard into a [`ReadGuard`] registered as a shared reader of the option,
+    /// and releases the reservation; hence, the caller can continue reading the value it has just written
+    /// while other readers proceed concurrently.
+    ///
+    /// The caller is registered as a reader before the reservation is released; therefore, no writer can take
+    /// or replace the value in between.
+    ///
+    /// The mutation is published to the generation of the option, such as observed by [`Watcher`]s, at this point.
+    ///
+    /// [`ReadGuard`]: crate::ReadGuard
+    /// [`Watcher`]: crate::Watcher
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let slots = [ConcurrentOption::some(vec![1, 2])];
+    ///
+    /// let (_, guard) = ConcurrentOption::iter_guards(&slots).next().unwrap();
+    /// let mut guard = guard.try_upgrade().unwrap();
+    /// guard.push(3);
+    ///
+    /// let guard = guard.downgrade();
+    /// assert_eq!(guard.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn downgrade(self) -> ReadGuard<'a, T> {
+        let Self { handle, option } = self;
+        // registered as a reader before the option is released as Some
+        option.generation.enter_read();
+        drop(handle);
+        ReadGuard {
+            handle: ReadHandle::entered(&option.generation),
+            option,
+        }
+    }
+
+    /// Projects the guard into a part of the value by the function `f`, such as a field of the value,
+    /// while keeping the reservation.
+    ///
+    /// Note that this method shadows a `map` method of the value, if any;
+    /// such a method can still be called by explicitly dereferencing the guard as in `(*guard).map(..)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let slots = [ConcurrentOption::some((1, "a".to_string()))];
+    ///
+    /// let (_, guard) = ConcurrentOption::iter_guards(&slots).next().unwrap();
+    /// let mut name = guard.try_upgrade().unwrap().map(|x| &mut x.1);
+    /// name.push('b');
+    /// drop(name);
+    ///
+    /// assert_eq!(slots[0].get_cloned(), Some((1, "ab".to_string())));
+    /// ```
+    pub fn map<U: ?Sized, F>(self, f: F) -> MappedWriteGuard<'a, U>
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let value = unsafe { MaybeUninit::assume_init_mut(&mut *self.option.value.get()) };
+        MappedWriteGuard {
+            value: f(value),
+            _handle: self.handle,
+        }
+    }
+
+    /// Projects the guard into a part of the value by the function `f` while keeping the reservation;
+    /// returns back the guard as the error if `f` returns None.
+    ///
+    /// Note that this method shadows a `try_map` method of the value, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let slots = [ConcurrentOption::some(vec![1, 2, 3])];
+    ///
+    /// let (_, guard) = ConcurrentOption::iter_guards(&slots).next().unwrap();
+    /// let guard = guard.try_upgrade().unwrap();
+    /// let mut first = guard.try_map(|x| x.first_mut()).unwrap();
+    /// *first = 42;
+    /// drop(first);
+    ///
+    /// assert_eq!(slots[0].get_cloned(), Some(vec![42, 2, 3]));
+    /// ```
+    pub fn try_map<U: ?Sized, F>(self, f: F) -> Result<MappedWriteGuard<'a, U>, Self>
+    where
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        let value = unsafe { MaybeUninit::assume_init_mut(&mut *self.option.value.get()) };
+        match f(value) {
+            Some(value) => Ok(MappedWriteGuard {
+                value,
+                _handle: self.handle,
+            }),
+            None => Err(self),
+        }
+    }
+}
+
+impl<T> Deref for WriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { MaybeUninit::assume_init_ref(&*self.option.value.get()) }
+    }
+}
+
+impl<T> DerefMut for WriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { MaybeUninit::assume_init_mut(&mut *self.option.value.get()) }
+    }
+}
+
+impl<T: Debug> Debug for WriteGuard<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("WriteGuard").field(&**self).finish()
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Thread safe method to obtain a [`WriteGuard`] to the value of the option if it is of Some variant;
+    /// returns None if the option is None.
+    ///
+    /// The option is reserved while the guard is alive and it is released back to Some when the guard is dropped.
+    /// Unlike the closure based [`update_if_some`], the guard allows multi-statement mutations,
+    /// such as mutations interleaved with early returns, without any unsafe code.
+    ///
+    /// Note that other threads attempting to read or write the option wait until the guard is dropped.
+    /// Therefore, the guard is meant to be short-lived.
+    ///
+    /// [`update_if_some`]: ConcurrentOption::update_if_some
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(vec![1, 2]);
+    ///
+    /// if let Some(mut guard) = x.lock_mut() {
+    ///     guard.push(3);
+    ///     if guard.len() > 2 {
+    ///         guard.remove(0);
+    ///     }
+    /// } // reservation is released here
+    ///
+    /// assert_eq!(x.take(), Some(vec![2, 3]));
+    /// assert!(x.lock_mut().is_none());
+    /// ```
+    pub fn lock_mut(&self) -> Option<WriteGuard<'_, T>> {
+        self.spin_get_write_handle(SOME, SOME)
+            .map(|handle| WriteGuard {
+                handle,
+                option: self,
+            })
+    }
+
+    /// Non-blocking version of [`lock_mut`] which never waits for another thread holding the reservation.
+    ///
+    /// Returns
+    /// * `Ok(Some(guard))` with a [`WriteGuard`] to the value if the option is of Some variant;
+    /// * `Ok(None)` if the option is None;
+    /// * a [`Contended`] error if the option is reserved by another thread at the moment.
+    ///
+    /// This allows the caller to distinguish an empty option from a busy one, and to back off on its own terms.
+    ///
+    /// [`lock_mut`]: ConcurrentOption::lock_mut
+    /// [`Contended`]: crate::Contended
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    ///
+    /// let mut guard = x.try_lock().unwrap().unwrap();
+    /// assert_eq!(x.try_lock().err(), Some(Contended(()))); // busy
+    /// *guard += 1;
+    /// drop(guard);
+    ///
+    /// assert_eq!(x.take(), Some(4));
+    /// assert!(x.try_lock().unwrap().is_none()); // empty
+    /// ```
+    pub fn try_lock(&self) -> Result<Option<WriteGuard<'_, T>>, Contended> {
+        loop {
+            match self.state.load(ORDER_LOAD) {
+                SOME => {
+                    if let Some(handle) = self.get_write_handle(SOME, SOME) {
+                        return Ok(Some(WriteGuard {
+                            handle,
+                            option: self,
+                        }));
+                    }
+                }
+                RESERVED => return Err(Contended(())),
+                _ => return Ok(None),
+            }
+        }
+    }
+}
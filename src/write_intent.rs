@@ -0,0 +1,101 @@
+use crate::atomic::AtomicU16;
+use crate::ConcurrentOption;
+use core::sync::atomic::Ordering;
+
+/// Announcement of the intent to write to a [`ConcurrentOption`], created by [`ConcurrentOption::announce_write`].
+///
+/// The announcement is retracted when the intent is dropped.
+///
+/// Available with the `write-intent` feature, which adds the 16-bit count of the pending writers to every option.
+///
+/// [`ConcurrentOption`]: crate::ConcurrentOption
+/// [`ConcurrentOption::announce_write`]: crate::ConcurrentOption::announce_write
+#[must_use = "the announcement is retracted as soon as the intent is dropped"]
+pub struct WriteIntent<'a> {
+    num_pending_writers: &'a AtomicU16,
+}
+
+impl Drop for WriteIntent<'_> {
+    fn drop(&mut self) {
+        self.num_pending_writers.fetch_sub(1, Ordering::Release);
+    }
+}
+
+impl<T> ConcurrentOption<T> {
+    /// Announces the intent to write to the option; the announcement lasts until the returned [`WriteIntent`] is dropped.
+    ///
+    /// This is an optional cooperative protocol and has no impact on the behavior of the other methods.
+    /// Readers may consult [`is_writer_pending`] and voluntarily back off,
+    /// reducing the reader-induced reservation failures of the writer on heavily read options.
+    ///
+    /// [`is_writer_pending`]: ConcurrentOption::is_writer_pending
+    ///
+    /// # Panics
+    ///
+    /// Panics if there already exist `u16::MAX` alive announcements on the option;
+    /// the count of the pending writers never wraps around.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// let x = ConcurrentOption::some(3);
+    /// assert!(!x.is_writer_pending());
+    ///
+    /// {
+    ///     let _intent = x.announce_write();
+    ///     assert!(x.is_writer_pending());
+    ///     x.update_if_some(|x| *x += 1);
+    /// }
+    ///
+    /// assert!(!x.is_writer_pending());
+    /// assert_eq!(x.map(|x| *x), Some(4));
+    /// ```
+    #[allow(clippy::panic)]
+    pub fn announce_write(&self) -> WriteIntent<'_> {
+        let mut current = self.num_pending_writers.load(Ordering::Relaxed);
+        loop {
+            let Some(next) = current.checked_add(1) else {
+                panic!("ConcurrentOption cannot have more than u16::MAX announced writers at the same time");
+            };
+            match self.num_pending_writers.compare_exchange(
+                current,
+                next,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(x) => current = x,
+            }
+        }
+        WriteIntent {
+            num_pending_writers: &self.num_pending_writers,
+        }
+    }
+
+    /// Returns whether or not there exists at least one writer which announced its intent to write to the option
+    /// by [`announce_write`].
+    ///
+    /// [`announce_write`]: ConcurrentOption::announce_write
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_concurrent_option::*;
+    ///
+    /// fn polite_read(x: &ConcurrentOption<String>) -> Option<usize> {
+    ///     while x.is_writer_pending() {
+    ///         std::thread::yield_now();
+    ///     }
+    ///     x.map(|x| x.len())
+    /// }
+    ///
+    /// let x = ConcurrentOption::some(3.to_string());
+    /// assert_eq!(polite_read(&x), Some(1));
+    /// ```
+    #[inline]
+    pub fn is_writer_pending(&self) -> bool {
+        self.num_pending_writers.load(Ordering::Relaxed) > 0
+    }
+}
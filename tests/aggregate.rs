@@ -0,0 +1,84 @@
+use orx_concurrent_option::*;
+
+fn options() -> Vec<ConcurrentOption<i64>> {
+    (0..10)
+        .map(|i| match i % 3 {
+            0 => ConcurrentOption::none(),
+            _ => ConcurrentOption::some(i - 5),
+        })
+        .collect()
+}
+
+#[test]
+fn sum_some() {
+    assert_eq!(ConcurrentOption::sum_some(&options()), -3);
+    assert_eq!(ConcurrentOption::<i64>::sum_some(&[]), 0);
+}
+
+#[test]
+fn min_max_some() {
+    assert_eq!(ConcurrentOption::max_some(&options()), Some(3));
+    assert_eq!(ConcurrentOption::min_some(&options()), Some(-4));
+
+    let none = [ConcurrentOption::<i64>::none(), ConcurrentOption::none()];
+    assert_eq!(ConcurrentOption::max_some(&none), None);
+    assert_eq!(ConcurrentOption::min_some(&none), None);
+}
+
+#[test]
+fn count_matching() {
+    let options = options();
+    assert_eq!(ConcurrentOption::count_matching(&options, |x| *x > 0), 2);
+    assert_eq!(ConcurrentOption::count_matching(&options, |_| true), 6);
+
+    let mut num_calls = 0;
+    ConcurrentOption::count_matching(&options, |_| {
+        num_calls += 1;
+        false
+    });
+    assert_eq!(num_calls, 6);
+}
+
+#[test]
+fn frozen_values_are_included() {
+    let options = [ConcurrentOption::some(2), ConcurrentOption::some(5)];
+    _ = options[1].freeze();
+    assert_eq!(ConcurrentOption::sum_some(&options), 7);
+    assert_eq!(ConcurrentOption::max_some(&options), Some(5));
+}
+
+#[test]
+fn concurrent_aggregation_reads_consistent_values() {
+    let num_slots = 16;
+    let options: Vec<_> = (0..num_slots)
+        .map(|_| ConcurrentOption::some(0u64))
+        .collect();
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            for i in 1..=1000 {
+                for x in &options {
+                    _ = x.replace(i);
+                }
+            }
+        });
+        s.spawn(|| {
+            for _ in 0..1000 {
+                let max = ConcurrentOption::max_some(&options).unwrap();
+                let min = ConcurrentOption::min_some(&options).unwrap();
+                assert!(max <= 1000 && min <= 1000);
+                let sum = ConcurrentOption::sum_some(&options);
+                assert!(sum <= 1000 * num_slots as u64);
+            }
+        });
+    });
+
+    assert_eq!(
+        ConcurrentOption::sum_some(&options),
+        1000 * num_slots as u64
+    );
+    assert_eq!(
+        ConcurrentOption::count_matching(&options, |x| *x == 1000),
+        num_slots
+    );
+}
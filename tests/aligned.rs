@@ -0,0 +1,32 @@
+use orx_concurrent_option::*;
+
+fn address<T, A: Alignment>(x: &AlignedConcurrentOption<T, A>) -> usize {
+    x.map(|x| x as *const Aligned<T, A> as usize).unwrap()
+}
+
+#[test]
+fn payloads_are_aligned() {
+    let options: Vec<AlignedConcurrentOption<u8, Align64>> = (0..10)
+        .map(|i| ConcurrentOption::some(Aligned::new(i)))
+        .collect();
+
+    for (i, x) in options.iter().enumerate() {
+        assert_eq!(address(x) % 64, 0);
+        assert_eq!(x.map(|x| **x), Some(i as u8));
+    }
+
+    let x: AlignedConcurrentOption<[u8; 3], Align4096> = ConcurrentOption::some([1, 2, 3].into());
+    assert_eq!(address(&x) % Align4096::ALIGN, 0);
+}
+
+#[test]
+fn aligned_payload_mutations() {
+    let x: AlignedConcurrentOption<String, Align128> = ConcurrentOption::none();
+    assert!(x.initialize_if_none(Aligned::new(3.to_string())));
+    assert!(x.update_if_some(|x| x.push('!')));
+    assert_eq!(address(&x) % 128, 0);
+
+    let old = x.replace(Aligned::new(7.to_string()));
+    assert_eq!(old.map(Aligned::into_inner), Some("3!".to_string()));
+    assert_eq!(format!("{:?}", x.take()), "Some(Aligned(\"7\"))");
+}
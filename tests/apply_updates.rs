@@ -0,0 +1,50 @@
+use orx_concurrent_option::*;
+use std::sync::Arc;
+
+#[test]
+fn apply_updates_drops_replaced_values() {
+    let value = Arc::new(0);
+    let x = ConcurrentOption::none();
+
+    let log = vec![
+        Some(value.clone()),
+        Some(value.clone()),
+        None,
+        Some(value.clone()),
+    ];
+    assert_eq!(x.apply_updates(log), 4);
+    assert_eq!(Arc::strong_count(&value), 2);
+
+    assert_eq!(x.apply_updates(None::<Option<Arc<i32>>>), 0);
+    assert_eq!(Arc::strong_count(&value), 2);
+
+    assert_eq!(x.apply_updates([None]), 1);
+    assert_eq!(Arc::strong_count(&value), 1);
+    assert!(x.is_none());
+}
+
+#[test]
+fn apply_updates_concurrently() {
+    let x = ConcurrentOption::some(0);
+
+    std::thread::scope(|s| {
+        for t in 0..4 {
+            let x = &x;
+            s.spawn(move || {
+                let log = (0..100).map(|i| match i % 3 {
+                    2 => None,
+                    _ => Some(t * 100 + i),
+                });
+                assert_eq!(x.apply_updates(log), 100);
+            });
+        }
+        s.spawn(|| {
+            for _ in 0..100 {
+                if let Some(value) = x.map(|v| *v) {
+                    assert!(value < 400);
+                }
+                std::thread::yield_now();
+            }
+        });
+    });
+}
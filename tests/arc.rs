@@ -0,0 +1,85 @@
+#![cfg(feature = "alloc")]
+
+use orx_concurrent_option::*;
+use std::sync::Arc;
+
+#[test]
+fn downgrade() {
+    let x = ConcurrentOption::some(Arc::new(3.to_string()));
+    let weak = x.downgrade().unwrap();
+    assert_eq!(weak.upgrade().as_deref(), Some(&3.to_string()));
+
+    _ = x.replace(Arc::new(4.to_string()));
+    assert!(weak.upgrade().is_none());
+
+    let x = ConcurrentOption::<Arc<String>>::none();
+    assert!(x.downgrade().is_none());
+}
+
+#[test]
+fn upgrade_into() {
+    let arc = Arc::new(3.to_string());
+    let weak = Arc::downgrade(&arc);
+
+    let x = ConcurrentOption::<Arc<String>>::none();
+    assert!(x.upgrade_into(&weak));
+    assert_eq!(Arc::strong_count(&arc), 2);
+    assert!(!x.upgrade_into(&weak));
+    assert_eq!(Arc::strong_count(&arc), 2);
+
+    _ = x.take();
+    drop(arc);
+    assert!(!x.upgrade_into(&weak));
+    assert!(x.is_none());
+}
+
+#[test]
+fn upgrade_into_concurrently() {
+    let arc = Arc::new(42);
+    let weak = Arc::downgrade(&arc);
+    let x = ConcurrentOption::<Arc<i32>>::none();
+
+    let num_installed: usize = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..8)
+            .map(|_| s.spawn(|| x.upgrade_into(&weak) as usize))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+
+    assert_eq!(num_installed, 1);
+    assert_eq!(Arc::strong_count(&arc), 2);
+}
+
+#[test]
+fn shared() {
+    let x: SharedOption<String> = ConcurrentOption::shared(3.to_string());
+    let y = x.clone();
+    assert!(Arc::ptr_eq(&x, &y));
+
+    assert_eq!(y.take(), Some(3.to_string()));
+    assert!(x.is_none());
+    assert_eq!(Arc::strong_count(&x), 2);
+}
+
+#[test]
+fn shared_none() {
+    let x = ConcurrentOption::<usize>::shared_none();
+    assert!(x.is_none());
+
+    let num_threads = 8;
+    let handles: Vec<_> = (0..num_threads)
+        .map(|i| {
+            let x = x.clone();
+            std::thread::spawn(move || x.initialize_if_none(i))
+        })
+        .collect();
+    let num_initialized = handles
+        .into_iter()
+        .map(|h| h.join().unwrap())
+        .filter(|x| *x)
+        .count();
+
+    assert_eq!(num_initialized, 1);
+    assert!(x.map(|x| *x < num_threads).unwrap());
+    assert_eq!(Arc::strong_count(&x), 1);
+}
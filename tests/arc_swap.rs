@@ -0,0 +1,117 @@
+#![cfg(feature = "arc-swap")]
+
+use arc_swap::ArcSwapOption;
+use orx_concurrent_option::*;
+use std::sync::Arc;
+
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    Load,
+    Store(Option<i32>),
+    Swap(Option<i32>),
+}
+
+fn apply_to_arc_swap(x: &ArcSwapOption<i32>, op: Op) -> Option<i32> {
+    match op {
+        Op::Load => x.load_full().map(|x| *x),
+        Op::Store(value) => {
+            x.store(value.map(Arc::new));
+            None
+        }
+        Op::Swap(value) => x.swap(value.map(Arc::new)).map(|x| *x),
+    }
+}
+
+fn apply_to_adapter(x: &ArcSwapAdapter<'_, i32>, op: Op) -> Option<i32> {
+    match op {
+        Op::Load => x.load_full().map(|x| *x),
+        Op::Store(value) => {
+            x.store(value.map(Arc::new));
+            None
+        }
+        Op::Swap(value) => x.swap(value.map(Arc::new)).map(|x| *x),
+    }
+}
+
+#[test]
+fn arc_swap_conversions_keep_the_arc() {
+    let arc = Arc::new(3.to_string());
+
+    let x = ConcurrentOption::some(arc.clone());
+    let y: ArcSwapOption<String> = x.into();
+    let z: ConcurrentOption<Arc<String>> = y.into();
+    assert!(Arc::ptr_eq(&z.unwrap(), &arc));
+
+    let x = ConcurrentOption::<Arc<String>>::none();
+    let y: ArcSwapOption<String> = x.into();
+    assert!(y.load_full().is_none());
+    let z: ConcurrentOption<Arc<String>> = y.into();
+    assert!(z.is_none());
+}
+
+#[test]
+fn arc_swap_adapter_matches_arc_swap_option() {
+    let ops = [
+        Op::Load,
+        Op::Swap(None),
+        Op::Store(Some(1)),
+        Op::Load,
+        Op::Swap(Some(2)),
+        Op::Swap(Some(3)),
+        Op::Store(None),
+        Op::Load,
+        Op::Store(None),
+        Op::Swap(Some(4)),
+        Op::Swap(None),
+        Op::Load,
+    ];
+
+    let expected = ArcSwapOption::<i32>::empty();
+    let option = ConcurrentOption::<Arc<i32>>::none();
+    let adapter = option.as_arc_swap();
+
+    for op in ops {
+        assert_eq!(
+            apply_to_adapter(&adapter, op),
+            apply_to_arc_swap(&expected, op),
+            "{:?}",
+            op
+        );
+        assert_eq!(
+            adapter.load_full().map(|x| *x),
+            expected.load_full().map(|x| *x)
+        );
+    }
+}
+
+#[test]
+fn arc_swap_adapter_concurrent_swaps_lose_nothing() {
+    let num_threads = 8;
+    let num_swaps = 1000;
+    let option = ConcurrentOption::<Arc<usize>>::none();
+
+    let mut swapped_out: Vec<usize> = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..num_threads)
+            .map(|t| {
+                let adapter = option.as_arc_swap();
+                s.spawn(move || {
+                    (0..num_swaps)
+                        .filter_map(|i| adapter.swap(Some(Arc::new(t * num_swaps + i))))
+                        .map(|x| *x)
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .collect()
+    });
+
+    swapped_out.extend(option.as_arc_swap().load_full().map(|x| *x));
+    swapped_out.sort();
+    assert_eq!(
+        swapped_out,
+        (0..num_threads * num_swaps).collect::<Vec<_>>()
+    );
+}
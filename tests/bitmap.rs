@@ -0,0 +1,77 @@
+use orx_concurrent_option::*;
+
+#[test]
+fn some_bitmap_empty() {
+    let options: [ConcurrentOption<i32>; 0] = [];
+    let mut out = [7u64; 1];
+    some_bitmap(&options, &mut out);
+    assert_eq!(out, [7]);
+}
+
+#[test]
+fn some_bitmap_word_boundaries() {
+    for len in [1usize, 63, 64, 65, 128, 130] {
+        let options: Vec<_> = (0..len)
+            .map(|i| match i % 5 == 1 || i == len - 1 {
+                true => ConcurrentOption::some(i),
+                false => ConcurrentOption::none(),
+            })
+            .collect();
+
+        let mut out = vec![u64::MAX; len.div_ceil(64) + 1];
+        some_bitmap(&options, &mut out);
+
+        for (i, x) in options.iter().enumerate() {
+            let bit = (out[i / 64] >> (i % 64)) & 1 == 1;
+            assert_eq!(bit, x.is_some());
+        }
+        if len % 64 != 0 {
+            assert_eq!(out[len / 64] >> (len % 64), 0);
+        }
+        assert_eq!(out[out.len() - 1], u64::MAX);
+    }
+}
+
+#[test]
+fn some_bitmap_skips_reserved() {
+    let options = [
+        ConcurrentOption::some(0),
+        ConcurrentOption::some(1),
+        ConcurrentOption::none(),
+    ];
+    let _handle = unsafe { options[1].mut_handle(SOME, SOME) };
+
+    let mut out = [0u64; 1];
+    some_bitmap(&options, &mut out);
+    assert_eq!(out, [0b001]);
+}
+
+#[test]
+#[should_panic]
+fn some_bitmap_too_short_out() {
+    let options: Vec<_> = (0..65).map(ConcurrentOption::some).collect();
+    let mut out = [0u64; 1];
+    some_bitmap(&options, &mut out);
+}
+
+#[test]
+fn len_hint_and_is_probably_some() {
+    let options: Vec<_> = (0..130)
+        .map(|i| match i % 3 {
+            0 => ConcurrentOption::some(i),
+            _ => ConcurrentOption::none(),
+        })
+        .collect();
+
+    assert_eq!(ConcurrentOption::len_hint(&options), 44);
+    assert_eq!(ConcurrentOption::<i32>::len_hint(&[]), 0);
+
+    for (i, x) in options.iter().enumerate() {
+        assert_eq!(x.is_probably_some(), i % 3 == 0);
+    }
+
+    let reserved = unsafe { options[0].mut_handle(SOME, SOME) };
+    assert!(!options[0].is_probably_some());
+    assert_eq!(ConcurrentOption::len_hint(&options), 43);
+    drop(reserved);
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "alloc")]
+
+use orx_concurrent_option::*;
+
+#[test]
+fn vec_round_trip() {
+    let options = vec![Some(1.to_string()), None, Some(3.to_string())];
+    let slots = into_concurrent_options(options);
+    assert_eq!(slots.len(), 3);
+    assert_eq!(slots[0], ConcurrentOption::some(1.to_string()));
+    assert!(slots[1].is_none());
+
+    std::thread::scope(|s| {
+        s.spawn(|| _ = slots[1].initialize_if_none(2.to_string()));
+        s.spawn(|| _ = slots[2].take());
+    });
+
+    let options = into_options(slots);
+    assert_eq!(options, [Some(1.to_string()), Some(2.to_string()), None]);
+}
+
+#[test]
+fn boxed_round_trip() {
+    let options: Box<[Option<String>]> = vec![None, Some(2.to_string())].into_boxed_slice();
+    let slots = into_concurrent_options_boxed(options);
+    assert_eq!(slots.len(), 2);
+    assert!(slots[1].is_some());
+
+    let options = into_options_boxed(slots);
+    assert_eq!(options.as_ref(), [None, Some(2.to_string())]);
+}
+
+#[test]
+fn empty() {
+    let slots = into_concurrent_options(Vec::<Option<String>>::new());
+    assert!(slots.is_empty());
+
+    let options = into_options(slots);
+    assert!(options.is_empty());
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "std")]
+
+use orx_concurrent_option::*;
+use std::time::Duration;
+
+/// Gives the waiting threads enough time to pass the spinning and yielding phases and park.
+fn let_waiters_park() {
+    std::thread::sleep(Duration::from_millis(50));
+}
+
+#[test]
+fn cancel_wakes_parked_waiters() {
+    let none = ConcurrentOption::<String>::none();
+    let some = ConcurrentOption::some(3.to_string());
+    let token = CancelToken::new();
+
+    std::thread::scope(|s| {
+        let for_some = s.spawn(|| none.wait_for_some_cancellable(&token).map(|x| x.clone()));
+        let and_take = s.spawn(|| none.wait_and_take_cancellable(&token));
+        let for_none = s.spawn(|| some.wait_for_none_cancellable(&token));
+
+        let_waiters_park();
+        assert!(!token.is_cancelled());
+        token.cancel();
+        assert!(token.is_cancelled());
+
+        assert_eq!(for_some.join().unwrap(), Err(Cancelled));
+        assert_eq!(and_take.join().unwrap(), Err(Cancelled));
+        assert_eq!(for_none.join().unwrap(), Err(Cancelled));
+    });
+
+    assert!(none.is_none());
+    assert_eq!(some.take(), Some(3.to_string()));
+}
+
+#[test]
+fn cancelled_token_returns_right_away() {
+    let token = CancelToken::new();
+    token.cancel();
+
+    let x = ConcurrentOption::<i32>::none();
+    assert_eq!(
+        x.wait_for_some_cancellable(&token).map(|x| *x),
+        Err(Cancelled)
+    );
+    assert_eq!(x.wait_and_take_cancellable(&token), Err(Cancelled));
+    assert_eq!(x.wait_for_none_cancellable(&token), Ok(()));
+
+    let x = ConcurrentOption::some(7);
+    assert_eq!(x.wait_for_some_cancellable(&token).map(|x| *x), Ok(7));
+    assert_eq!(x.wait_and_take_cancellable(&token), Ok(7));
+    assert_eq!(x.wait_for_none_cancellable(&token), Ok(()));
+}
+
+#[test]
+fn state_change_wakes_cancellable_waiters() {
+    let x = ConcurrentOption::none();
+    let token = CancelToken::new();
+
+    std::thread::scope(|s| {
+        let waiter = s.spawn(|| x.wait_and_take_cancellable(&token));
+
+        let_waiters_park();
+        assert!(x.initialize_if_none(42));
+        assert_eq!(waiter.join().unwrap(), Ok(42));
+    });
+
+    assert!(!token.is_cancelled());
+}
+
+#[test]
+fn cancel_wakes_waiters_of_many_options() {
+    let options: Vec<_> = (0..64).map(|_| ConcurrentOption::<usize>::none()).collect();
+    let token = CancelToken::new();
+
+    std::thread::scope(|s| {
+        let waiters: Vec<_> = options
+            .iter()
+            .map(|x| s.spawn(|| x.wait_and_take_cancellable(&token)))
+            .collect();
+
+        let_waiters_park();
+        assert!(options[7].initialize_if_none(7));
+        std::thread::sleep(Duration::from_millis(10));
+        token.cancel();
+
+        for (i, waiter) in waiters.into_iter().enumerate() {
+            let expected = match i {
+                7 => Ok(7),
+                _ => Err(Cancelled),
+            };
+            assert_eq!(waiter.join().unwrap(), expected);
+        }
+    });
+}
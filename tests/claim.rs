@@ -0,0 +1,80 @@
+use orx_concurrent_option::*;
+
+#[test]
+fn try_claim_publish() {
+    let x = ConcurrentOption::<String>::none();
+    #[cfg(feature = "generation")]
+    let mut watcher = x.watcher();
+
+    let claim = x.try_claim().unwrap();
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Reserved);
+    assert!(x.try_claim().is_none());
+    assert!(!x.initialize_if_none(7.to_string()));
+
+    claim.publish(3.to_string());
+    #[cfg(feature = "generation")]
+    assert!(watcher.has_changed());
+    assert!(x.try_claim().is_none());
+    assert_eq!(x.take(), Some(3.to_string()));
+}
+
+#[test]
+fn try_claim_dropped_without_publish() {
+    let x = ConcurrentOption::<String>::none();
+
+    drop(x.try_claim().unwrap());
+    assert_eq!(x.state(LoadOrdering::Acquire), State::None);
+    assert!(x.initialize_if_none(1.to_string()));
+}
+
+#[test]
+fn publish_in_place() {
+    #[derive(Debug, PartialEq)]
+    struct Large([u32; 256]);
+
+    let x = ConcurrentOption::<Large>::none();
+    let claim = x.try_claim().unwrap();
+    unsafe {
+        claim.publish_in_place(|slot| {
+            slot.write(Large([7; 256]));
+        })
+    };
+    assert_eq!(x.take(), Some(Large([7; 256])));
+}
+
+#[test]
+fn take_into() {
+    let x = ConcurrentOption::some(1.to_string());
+    let mut destination = Some(0.to_string());
+
+    assert!(x.take_into(&mut destination));
+    assert_eq!(destination, Some(1.to_string()));
+    assert!(x.is_none());
+
+    assert!(!x.take_into(&mut destination));
+    assert_eq!(destination, Some(1.to_string()));
+}
+
+#[test]
+fn concurrent_claims_are_exclusive() {
+    let x = ConcurrentOption::<usize>::none();
+
+    let num_published: usize = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let x = &x;
+                s.spawn(move || match x.try_claim() {
+                    Some(claim) => {
+                        claim.publish(i);
+                        1
+                    }
+                    None => 0,
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+
+    assert_eq!(num_published, 1);
+    assert!(x.is_some());
+}
@@ -28,6 +28,21 @@ fn debug() {
     assert_eq!(y, "ConcurrentNone");
 }
 
+#[test]
+fn snapshot() {
+    let x = ConcurrentOption::some(3.to_string());
+    let y = format!("{:?}", unsafe { x.snapshot(LoadOrdering::Acquire) });
+    assert_eq!(y, "ConcurrentSome(\"3\")");
+    let y = format!("{}", unsafe { x.snapshot(LoadOrdering::Relaxed) });
+    assert_eq!(y, "ConcurrentSome(3)");
+
+    let x = ConcurrentOption::<String>::none();
+    let y = format!("{:?}", unsafe { x.snapshot(LoadOrdering::SeqCst) });
+    assert_eq!(y, "ConcurrentNone");
+    let y = format!("{}", unsafe { x.snapshot(LoadOrdering::SeqCst) });
+    assert_eq!(y, "ConcurrentNone");
+}
+
 #[test]
 fn partial_ord() {
     use core::cmp::Ordering::*;
@@ -95,6 +110,17 @@ fn from() {
 
     let x: ConcurrentOption<String> = None.into();
     assert_eq!(unsafe { x.as_ref() }, None);
+
+    let mut value = 3.to_string();
+
+    let x: ConcurrentOption<String> = (&value).into();
+    assert_eq!(unsafe { x.as_ref() }, Some(&3.to_string()));
+
+    let x: ConcurrentOption<String> = (&mut value).into();
+    assert_eq!(unsafe { x.as_ref() }, Some(&3.to_string()));
+
+    let x: ConcurrentOption<&String> = (&value).into();
+    assert_eq!(unsafe { x.as_ref() }, Some(&&3.to_string()));
 }
 
 #[test]
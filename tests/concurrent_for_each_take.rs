@@ -0,0 +1,64 @@
+use orx_concurrent_option::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn for_each_take() {
+    let slots = [
+        ConcurrentOption::some(0),
+        ConcurrentOption::none(),
+        ConcurrentOption::some(2),
+    ];
+
+    let mut taken = vec![];
+    ConcurrentOption::for_each_take(&slots, |idx, value| taken.push((idx, value)));
+    assert_eq!(taken, [(0, 0), (2, 2)]);
+    assert!(slots.iter().all(|x| x.is_none()));
+
+    let mut taken = vec![];
+    ConcurrentOption::for_each_take(&slots, |idx, value| taken.push((idx, value)));
+    assert!(taken.is_empty());
+}
+
+#[test]
+fn concurrent_for_each_take_single_consumer_many_producers() {
+    let num_producers = 8;
+    let num_items_per_producer = 100;
+    let slots: Vec<_> = (0..16).map(|_| ConcurrentOption::<usize>::none()).collect();
+    let num_produced = AtomicUsize::new(0);
+    let num_consumed = AtomicUsize::new(0);
+    let num_active_producers = AtomicUsize::new(num_producers);
+
+    std::thread::scope(|s| {
+        for p in 0..num_producers {
+            let slots = &slots;
+            let num_produced = &num_produced;
+            let num_active_producers = &num_active_producers;
+            s.spawn(move || {
+                let mut i = 0;
+                while i < num_items_per_producer {
+                    let slot = &slots[(p + i) % slots.len()];
+                    if slot.initialize_if_none(i) {
+                        num_produced.fetch_add(1, Ordering::Relaxed);
+                        i += 1;
+                    }
+                }
+                num_active_producers.fetch_sub(1, Ordering::Release);
+            });
+        }
+
+        s.spawn(|| loop {
+            let producers_done = num_active_producers.load(Ordering::Acquire) == 0;
+            ConcurrentOption::for_each_take(&slots, |_, _| {
+                num_consumed.fetch_add(1, Ordering::Relaxed);
+            });
+            if producers_done {
+                break;
+            }
+        });
+    });
+
+    let total = num_producers * num_items_per_producer;
+    assert_eq!(num_produced.load(Ordering::Relaxed), total);
+    assert_eq!(num_consumed.load(Ordering::Relaxed), total);
+    assert!(slots.iter().all(|x| x.is_none()));
+}
@@ -53,6 +53,28 @@ fn concurrent_get_or_insert_with_multiple_writer(
     });
 }
 
+#[test_matrix([2, 4, 8])]
+fn concurrent_get_or_insert_with_status_inserts_once(num_writers: usize) {
+    let maybe = ConcurrentOption::<String>::none();
+    let maybe_ref = &maybe;
+
+    let num_inserted: usize = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..num_writers)
+            .map(|i| {
+                s.spawn(move || {
+                    let (_, inserted) =
+                        unsafe { maybe_ref.get_or_insert_with_status(|| i.to_string()) };
+                    inserted as usize
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+
+    assert_eq!(num_inserted, 1);
+    assert!(maybe.is_some());
+}
+
 // helpers
 fn reader(do_sleep: bool, maybe: &ConcurrentOption<String>) {
     for _ in 0..100 {
@@ -0,0 +1,123 @@
+use orx_concurrent_option::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn commit_empty() {
+    assert!(ConcurrentInit::new().commit().is_ok());
+}
+
+#[test]
+fn commit_all_none() {
+    let a = ConcurrentOption::none();
+    let b = ConcurrentOption::none();
+    let c = ConcurrentOption::none();
+
+    let init = ConcurrentInit::new()
+        .with(&a, 1)
+        .with(&b, 'x')
+        .with(&c, 3.to_string());
+    assert!(init.commit().is_ok());
+
+    assert_eq!(a.get_cloned(), Some(1));
+    assert_eq!(b.get_cloned(), Some('x'));
+    assert_eq!(c.get_cloned(), Some(3.to_string()));
+}
+
+#[test]
+fn commit_rolls_back_when_any_is_some() {
+    let a = ConcurrentOption::none();
+    let b = ConcurrentOption::some('y');
+    let c = ConcurrentOption::none();
+
+    let init = ConcurrentInit::new()
+        .with(&a, 1)
+        .with(&b, 'x')
+        .with(&c, 3.to_string());
+    let init = init.commit().err().unwrap();
+
+    assert_eq!(a.state(LoadOrdering::Acquire), State::None);
+    assert_eq!(b.get_cloned(), Some('y'));
+    assert_eq!(c.state(LoadOrdering::Acquire), State::None);
+
+    // retry once the blocking option is emptied
+    _ = b.take();
+    assert!(init.commit().is_ok());
+    assert_eq!(a.get_cloned(), Some(1));
+    assert_eq!(b.get_cloned(), Some('x'));
+    assert_eq!(c.get_cloned(), Some(3.to_string()));
+}
+
+#[test]
+fn commit_fails_when_reserved() {
+    let a = ConcurrentOption::none();
+    let b = ConcurrentOption::<i32>::none();
+
+    let claim = b.try_claim().unwrap();
+    let init = ConcurrentInit::new().with(&a, 1).with(&b, 2);
+    let init = init.commit().err().unwrap();
+    assert!(a.is_none());
+    drop(claim);
+
+    assert!(init.commit().is_ok());
+    assert_eq!((a.get_cloned(), b.get_cloned()), (Some(1), Some(2)));
+}
+
+#[test]
+fn same_option_twice_never_commits() {
+    let a = ConcurrentOption::none();
+    let init = ConcurrentInit::new().with(&a, 1).with(&a, 2);
+    assert!(init.commit().is_err());
+    assert!(a.is_none());
+}
+
+#[test]
+fn uncommitted_values_are_dropped() {
+    struct Counted<'a>(&'a AtomicUsize);
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let num_dropped = AtomicUsize::new(0);
+    let a = ConcurrentOption::some(Counted(&num_dropped));
+    let b = ConcurrentOption::none();
+
+    let init = ConcurrentInit::new()
+        .with(&b, Counted(&num_dropped))
+        .with(&a, Counted(&num_dropped));
+    drop(init.commit());
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 2);
+    assert!(b.is_none());
+}
+
+#[test]
+fn concurrent_commits_are_all_or_nothing() {
+    let num_threads = 8;
+    let a = ConcurrentOption::none();
+    let b = ConcurrentOption::none();
+    let num_committed = AtomicUsize::new(0);
+
+    std::thread::scope(|s| {
+        for t in 0..num_threads {
+            let (a, b, num_committed) = (&a, &b, &num_committed);
+            s.spawn(move || {
+                // overlapping reservations might fail all commits; retry until one succeeds
+                while a.is_none() && b.is_none() {
+                    // alternate the order of the targets to exercise overlapping reservations
+                    let committed = match t % 2 == 0 {
+                        true => ConcurrentInit::new().with(a, t).with(b, t).commit(),
+                        false => ConcurrentInit::new().with(b, t).with(a, t).commit(),
+                    };
+                    if committed.is_ok() {
+                        num_committed.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            });
+        }
+    });
+
+    assert_eq!(num_committed.load(Ordering::Relaxed), 1);
+    assert_eq!(a.get_cloned(), b.get_cloned());
+    assert!(a.is_some());
+}
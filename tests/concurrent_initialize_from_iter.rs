@@ -0,0 +1,60 @@
+use orx_concurrent_option::*;
+
+#[test]
+fn initialize_from_iter() {
+    let slots: Vec<ConcurrentOption<String>> = vec![
+        ConcurrentOption::none(),
+        ConcurrentOption::some(10.to_string()),
+        ConcurrentOption::none(),
+    ];
+
+    let num_installed = ConcurrentOption::initialize_from_iter(&slots, []);
+    assert_eq!(num_installed, 0);
+
+    let num_installed =
+        ConcurrentOption::initialize_from_iter(&slots, (0..5).map(|x| x.to_string()));
+    assert_eq!(num_installed, 2);
+
+    let values: Vec<_> = slots.iter().map(|x| x.clone_into_option()).collect();
+    assert_eq!(
+        values,
+        [
+            Some(0.to_string()),
+            Some(10.to_string()),
+            Some(1.to_string())
+        ]
+    );
+
+    let num_installed =
+        ConcurrentOption::initialize_from_iter(&slots, (0..5).map(|x| x.to_string()));
+    assert_eq!(num_installed, 0);
+}
+
+#[test]
+fn concurrent_initialize_from_iter() {
+    let num_slots = 64;
+    let num_threads = 8;
+    let slots: Vec<ConcurrentOption<usize>> = (0..num_slots)
+        .map(|i| match i % 3 {
+            0 => ConcurrentOption::some(usize::MAX),
+            _ => ConcurrentOption::none(),
+        })
+        .collect();
+    let num_none = slots.iter().filter(|x| x.is_none()).count();
+
+    let total_installed: usize = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..num_threads)
+            .map(|t| {
+                let slots = &slots;
+                s.spawn(move || {
+                    let values = (0..num_slots / 4).map(move |i| t * num_slots + i);
+                    ConcurrentOption::initialize_from_iter(slots, values)
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+
+    assert_eq!(total_installed, num_none);
+    assert!(slots.iter().all(|x| x.is_some()));
+}
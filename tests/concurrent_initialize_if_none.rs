@@ -1,16 +1,16 @@
 use orx_concurrent_option::*;
-use std::{sync::atomic::Ordering, time::Duration};
+use std::time::Duration;
 use test_case::test_matrix;
 
 #[test_matrix(
     [2, 4, 8, 16],
     [false, true],
-    [Ordering::SeqCst, Ordering::Acquire]
+    [LoadOrdering::SeqCst, LoadOrdering::Acquire]
 )]
 fn concurrent_initialize_if_none_single_writer(
     num_readers: usize,
     do_sleep: bool,
-    read_order: Ordering,
+    read_order: LoadOrdering,
 ) {
     let maybe = ConcurrentOption::<String>::none();
     let maybe_ref = &maybe;
@@ -32,13 +32,13 @@ fn concurrent_initialize_if_none_single_writer(
     [4, 8],
     [2, 4, 8, 16],
     [false, true],
-    [Ordering::SeqCst, Ordering::Acquire]
+    [LoadOrdering::SeqCst, LoadOrdering::Acquire]
 )]
 fn concurrent_initialize_if_none_multiple_writer(
     num_writers: usize,
     num_readers: usize,
     do_sleep: bool,
-    read_order: Ordering,
+    read_order: LoadOrdering,
 ) {
     let maybe = ConcurrentOption::<String>::none();
     let maybe_ref = &maybe;
@@ -59,7 +59,7 @@ fn concurrent_initialize_if_none_multiple_writer(
 }
 
 // helpers
-fn read(do_sleep: bool, maybe_ref: &ConcurrentOption<String>, read_order: Ordering) {
+fn read(do_sleep: bool, maybe_ref: &ConcurrentOption<String>, read_order: LoadOrdering) {
     for _ in 0..100 {
         sleep(do_sleep);
         let read = unsafe { maybe_ref.as_ref_with_order(read_order) };
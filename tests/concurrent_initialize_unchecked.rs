@@ -1,13 +1,15 @@
+#![allow(clippy::single_match)]
+
 use orx_concurrent_option::*;
-use std::{sync::atomic::Ordering, time::Duration};
+use std::time::Duration;
 use test_case::test_matrix;
 
 #[test_matrix(
     [2, 4, 8, 16],
     [false, true],
-    [Ordering::SeqCst, Ordering::Acquire]
+    [LoadOrdering::SeqCst, LoadOrdering::Acquire]
 )]
-fn concurrent_initialize_unchecked(num_readers: usize, do_sleep: bool, read_order: Ordering) {
+fn concurrent_initialize_unchecked(num_readers: usize, do_sleep: bool, read_order: LoadOrdering) {
     let maybe = ConcurrentOption::<String>::none();
     let maybe_ref = &maybe;
 
@@ -25,7 +27,7 @@ fn concurrent_initialize_unchecked(num_readers: usize, do_sleep: bool, read_orde
 }
 
 // helpers
-fn read(do_sleep: bool, maybe_ref: &ConcurrentOption<String>, read_order: Ordering) {
+fn read(do_sleep: bool, maybe_ref: &ConcurrentOption<String>, read_order: LoadOrdering) {
     for _ in 0..100 {
         sleep(do_sleep);
         let read = unsafe { maybe_ref.as_ref_with_order(read_order) };
@@ -1,13 +1,13 @@
 use orx_concurrent_option::*;
-use std::{sync::atomic::Ordering, time::Duration};
+use std::time::Duration;
 use test_case::test_matrix;
 
 #[test_matrix(
     [2, 4, 8, 16],
     [false, true],
-    [Ordering::SeqCst, Ordering::Acquire]
+    [LoadOrdering::SeqCst, LoadOrdering::Acquire]
 )]
-fn concurrent_get_raw_single_writer(num_readers: usize, do_sleep: bool, read_order: Ordering) {
+fn concurrent_get_raw_single_writer(num_readers: usize, do_sleep: bool, read_order: LoadOrdering) {
     let maybe = ConcurrentOption::<String>::none();
     let maybe_ref = &maybe;
 
@@ -28,13 +28,13 @@ fn concurrent_get_raw_single_writer(num_readers: usize, do_sleep: bool, read_ord
     [4, 8],
     [2, 4, 8, 16],
     [false, true],
-    [Ordering::SeqCst, Ordering::Acquire]
+    [LoadOrdering::SeqCst, LoadOrdering::Acquire]
 )]
 fn concurrent_get_raw_multiple_writer(
     num_writers: usize,
     num_readers: usize,
     do_sleep: bool,
-    read_order: Ordering,
+    read_order: LoadOrdering,
 ) {
     let maybe = ConcurrentOption::<String>::none();
     let maybe_ref = &maybe;
@@ -55,7 +55,7 @@ fn concurrent_get_raw_multiple_writer(
 }
 
 // helpers
-fn read(do_sleep: bool, maybe_ref: &ConcurrentOption<String>, read_order: Ordering) {
+fn read(do_sleep: bool, maybe_ref: &ConcurrentOption<String>, read_order: LoadOrdering) {
     let mut ptr: Option<*const String> = None;
     for _ in 0..100 {
         sleep(do_sleep);
@@ -0,0 +1,105 @@
+use orx_concurrent_option::*;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Barrier,
+};
+
+#[test]
+fn nested_reads_do_not_block() {
+    let x = ConcurrentOption::some(3);
+    let sum = x.map(|a| x.map(|b| a + b));
+    assert_eq!(sum, Some(Some(6)));
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+}
+
+#[test]
+fn readers_read_simultaneously() {
+    let x = ConcurrentOption::some(3.to_string());
+    let num_readers = 4;
+    let barrier = Barrier::new(num_readers);
+
+    std::thread::scope(|s| {
+        for _ in 0..num_readers {
+            s.spawn(|| {
+                // each reader waits inside its read until all readers are inside theirs
+                let len = x.map(|v| {
+                    barrier.wait();
+                    v.len()
+                });
+                assert_eq!(len, Some(1));
+            });
+        }
+    });
+}
+
+#[test]
+fn writer_waits_for_active_reader() {
+    let x = ConcurrentOption::some(3.to_string());
+    let reader_done = AtomicBool::new(false);
+    let barrier = Barrier::new(2);
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            x.map(|v| {
+                barrier.wait();
+                for _ in 0..10 {
+                    std::thread::yield_now();
+                }
+                assert_eq!(v, "3");
+                reader_done.store(true, Ordering::SeqCst);
+            });
+        });
+
+        s.spawn(|| {
+            barrier.wait();
+            assert_eq!(x.take(), Some(3.to_string()));
+            assert!(reader_done.load(Ordering::SeqCst));
+        });
+    });
+
+    assert!(x.is_none());
+}
+
+#[test]
+fn bounded_writer_gives_up_on_active_reader() {
+    let x = ConcurrentOption::some(3);
+
+    x.map(|_| {
+        assert_eq!(x.try_take(16), Err(Contended(())));
+        assert_eq!(x.try_replace(7, 16), Err(ReplaceError::Contended(7)));
+        assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+    });
+
+    assert_eq!(x.try_take(DEFAULT_MAX_SPINS), Ok(Some(3)));
+}
+
+#[test]
+fn readers_and_writers() {
+    let x = ConcurrentOption::some((0usize, 0usize));
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..200 {
+                    if let Some((a, b)) = x.map(|v| *v) {
+                        assert_eq!(a, b);
+                    }
+                    std::thread::yield_now();
+                }
+            });
+        }
+        for _ in 0..2 {
+            s.spawn(|| {
+                for _ in 0..100 {
+                    x.update_if_some(|v| {
+                        v.0 += 1;
+                        v.1 += 1;
+                    });
+                    std::thread::yield_now();
+                }
+            });
+        }
+    });
+
+    assert_eq!(x.take(), Some((200, 200)));
+}
@@ -84,3 +84,23 @@ fn sleep(do_sleep: bool) {
         std::thread::sleep(duration);
     }
 }
+
+#[test]
+fn concurrent_replace_with_fn_counter() {
+    let num_threads = 8;
+    let num_increments = 100;
+
+    let maybe = ConcurrentOption::<usize>::none();
+
+    std::thread::scope(|s| {
+        for _ in 0..num_threads {
+            s.spawn(|| {
+                for _ in 0..num_increments {
+                    _ = maybe.replace_with_fn(|old| old.copied().unwrap_or(0) + 1);
+                }
+            });
+        }
+    });
+
+    assert_eq!(maybe.unwrap(), num_threads * num_increments);
+}
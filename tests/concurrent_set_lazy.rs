@@ -0,0 +1,77 @@
+use orx_concurrent_option::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn set_lazy_writes_when_not_reserved() {
+    let x = ConcurrentOption::none();
+    #[cfg(feature = "generation")]
+    let mut watcher = x.watcher();
+
+    assert!(x.set_lazy(1.to_string()));
+    #[cfg(feature = "generation")]
+    assert!(watcher.has_changed());
+    assert!(x.set_lazy(2.to_string()));
+    #[cfg(feature = "generation")]
+    assert!(watcher.has_changed());
+    assert_eq!(x.take(), Some(2.to_string()));
+}
+
+#[test]
+fn set_lazy_drops_value_when_reserved() {
+    let x = ConcurrentOption::some(1.to_string());
+    #[cfg(feature = "generation")]
+    let mut watcher = x.watcher();
+
+    let handle = unsafe { x.mut_handle(SOME, SOME) };
+    assert!(!x.set_lazy(2.to_string()));
+    handle.unwrap().abort();
+
+    #[cfg(feature = "generation")]
+    assert!(!watcher.has_changed());
+    assert_eq!(x.take(), Some(1.to_string()));
+}
+
+#[test]
+fn set_lazy_waits_for_shared_readers() {
+    let x = ConcurrentOption::some(1.to_string());
+    let guard = x.get_guard().unwrap();
+
+    std::thread::scope(|s| {
+        let writer = s.spawn(|| x.set_lazy(2.to_string()));
+
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(!writer.is_finished());
+        assert_eq!(guard.as_str(), "1");
+
+        drop(guard);
+        assert!(writer.join().unwrap());
+    });
+
+    assert_eq!(x.take(), Some(2.to_string()));
+}
+
+#[test]
+fn set_lazy_concurrent_writers() {
+    let x = ConcurrentOption::none();
+    let num_written = AtomicUsize::new(0);
+    let num_threads = 8;
+    let num_iters = 200;
+
+    std::thread::scope(|s| {
+        for t in 0..num_threads {
+            let x = &x;
+            let num_written = &num_written;
+            s.spawn(move || {
+                for i in 0..num_iters {
+                    if x.set_lazy((t, i)) {
+                        num_written.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            });
+        }
+    });
+
+    let num_written = num_written.load(Ordering::Relaxed);
+    assert!(num_written > 0 && num_written <= num_threads * num_iters);
+    assert!(x.take().is_some());
+}
@@ -71,3 +71,28 @@ fn sleep(do_sleep: bool) {
         std::thread::sleep(duration);
     }
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn take_boxed_concurrently() {
+    let num_threads = 8;
+    let x = ConcurrentOption::some([42usize; 256]);
+
+    let num_taken: usize = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..num_threads)
+            .map(|_| {
+                s.spawn(|| match x.take_boxed() {
+                    Some(boxed) => {
+                        assert!(boxed.iter().all(|x| *x == 42));
+                        1
+                    }
+                    None => 0,
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+
+    assert_eq!(num_taken, 1);
+    assert!(x.is_none());
+}
@@ -0,0 +1,82 @@
+use orx_concurrent_option::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use test_case::test_matrix;
+
+#[derive(Debug, PartialEq)]
+struct TooSmall(usize);
+
+#[test]
+fn take_if_else_outcomes() {
+    let x = ConcurrentOption::none();
+    assert_eq!(
+        x.take_if_else(|_: &mut usize| Ok::<_, TooSmall>(true)),
+        Ok(None)
+    );
+
+    x.initialize_if_none(3);
+    assert_eq!(x.take_if_else(|v| Err(TooSmall(*v))), Err(TooSmall(3)));
+    assert_eq!(x.take_if_else(|v| Ok::<_, TooSmall>(*v > 3)), Ok(None));
+    assert_eq!(x.get_cloned(), Some(3));
+
+    #[cfg(feature = "generation")]
+    let mut watcher = x.watcher();
+    assert_eq!(
+        x.take_if_else(|v| {
+            *v += 1;
+            Err(TooSmall(*v))
+        }),
+        Err(TooSmall(4))
+    );
+    #[cfg(feature = "generation")]
+    assert!(watcher.has_changed());
+    assert_eq!(x.get_cloned(), Some(4));
+
+    assert_eq!(x.take_if_else(|v| Ok::<_, TooSmall>(*v > 3)), Ok(Some(4)));
+    assert!(x.is_none());
+}
+
+#[test_matrix([2, 4, 8])]
+fn concurrent_take_if_else_single_take(num_takers: usize) {
+    let maybe = ConcurrentOption::some(0usize);
+    let num_taken = AtomicUsize::new(0);
+
+    std::thread::scope(|s| {
+        for _ in 0..num_takers {
+            s.spawn(|| loop {
+                match maybe.take_if_else(|v| match *v >= 100 {
+                    true => Ok(true),
+                    false => {
+                        *v += 1;
+                        Err(TooSmall(*v))
+                    }
+                }) {
+                    Ok(Some(value)) => {
+                        assert_eq!(value, 100);
+                        num_taken.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Ok(None) => break,
+                    Err(TooSmall(v)) => assert!(v <= 100),
+                }
+            });
+        }
+    });
+
+    assert_eq!(num_taken.load(Ordering::Relaxed), 1);
+    assert!(maybe.is_none());
+}
+
+#[test]
+fn take_if_else_releases_the_option_when_predicate_panics() {
+    let maybe = ConcurrentOption::some(7.to_string());
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        maybe.take_if_else(|_| -> Result<bool, ()> { panic!("failed to validate") })
+    }));
+    assert!(result.is_err());
+
+    assert!(maybe.is_some());
+    assert_eq!(
+        maybe.take_if_else(|_| Ok::<_, ()>(true)),
+        Ok(Some(7.to_string()))
+    );
+}
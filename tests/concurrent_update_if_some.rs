@@ -84,3 +84,12 @@ fn sleep(do_sleep: bool) {
         std::thread::sleep(duration);
     }
 }
+
+#[test]
+fn update_if_some_with_fn_once() {
+    let maybe = ConcurrentOption::some(vec![1]);
+
+    let captured = vec![2, 3];
+    assert!(maybe.update_if_some(move |x| x.extend(captured)));
+    assert_eq!(maybe.get_cloned(), Some(vec![1, 2, 3]));
+}
@@ -0,0 +1,49 @@
+use orx_concurrent_option::*;
+
+#[test]
+fn try_methods_bounded_by_max_spins() {
+    let x = ConcurrentOption::some(3.to_string());
+
+    {
+        let _stalled_writer = unsafe { x.mut_handle(SOME, SOME) };
+        assert_eq!(x.try_take(8), Err(Contended(())));
+        assert_eq!(x.try_update_if_some(|x| x.push('!'), 8), Err(Contended(())));
+        assert_eq!(
+            x.try_replace(7.to_string(), 8)
+                .map_err(ReplaceError::into_inner),
+            Err(7.to_string())
+        );
+    }
+
+    assert_eq!(
+        x.try_update_if_some(|x| x.push('!'), DEFAULT_MAX_SPINS),
+        Ok(true)
+    );
+    assert_eq!(
+        x.try_replace(7.to_string(), DEFAULT_MAX_SPINS),
+        Ok(Some("3!".to_string()))
+    );
+    assert_eq!(x.try_take(DEFAULT_MAX_SPINS), Ok(Some(7.to_string())));
+    assert_eq!(x.try_take(DEFAULT_MAX_SPINS), Ok(None));
+    assert_eq!(
+        x.try_update_if_some(|x| x.push('!'), DEFAULT_MAX_SPINS),
+        Ok(false)
+    );
+    assert_eq!(x.try_replace(8.to_string(), DEFAULT_MAX_SPINS), Ok(None));
+}
+
+#[test]
+fn try_take_concurrently() {
+    let num_threads = 8;
+    let x = ConcurrentOption::some(42);
+
+    let num_taken: usize = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..num_threads)
+            .map(|_| s.spawn(|| matches!(x.try_take(DEFAULT_MAX_SPINS), Ok(Some(42))) as usize))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+
+    assert!(num_taken <= 1);
+    assert!(x.is_none() || num_taken == 0);
+}
@@ -0,0 +1,226 @@
+use core::sync::atomic::Ordering;
+use orx_concurrent_option::*;
+use std::sync::atomic::AtomicUsize;
+
+#[derive(Clone, Copy)]
+enum SlotState {
+    Claimed,
+    Dirty,
+}
+
+impl CustomState for SlotState {
+    fn id(self) -> u8 {
+        self as u8
+    }
+
+    fn holds_value(self) -> bool {
+        match self {
+            Self::Claimed => false,
+            Self::Dirty => true,
+        }
+    }
+}
+
+#[test]
+fn custom_state_raw_values_are_distinct() {
+    let claimed = SlotState::Claimed.state();
+    let dirty = SlotState::Dirty.state();
+    assert_ne!(claimed, dirty);
+    for s in [claimed, dirty] {
+        assert_ne!(s, NONE);
+        assert_ne!(s, SOME);
+        assert_ne!(s, RESERVED);
+    }
+}
+
+#[test]
+fn custom_state_built_in_methods_treat_as_unavailable() {
+    let x = ConcurrentOption::some(3.to_string());
+    assert!(x.transition_custom(SOME, SlotState::Dirty.state()));
+
+    assert_eq!(
+        x.state(LoadOrdering::Relaxed),
+        State::Custom(SlotState::Dirty.state())
+    );
+    assert!(!x.is_some());
+    assert!(x.is_none());
+    assert_eq!(x.map(|x| x.len()), None);
+    assert_eq!(unsafe { x.as_ref() }, None);
+    assert_eq!(x.take(), None);
+    assert!(!x.initialize_if_none(7.to_string()));
+    assert!(!x.update_if_some(|x| x.push('!')));
+
+    assert!(x.transition_custom(SlotState::Dirty.state(), SOME));
+    assert_eq!(x.take(), Some(3.to_string()));
+}
+
+#[test]
+fn custom_state_transition_fails_when_not_in_from_state() {
+    let x = ConcurrentOption::<String>::none();
+    assert!(!x.transition_custom(SOME, SlotState::Dirty.state()));
+    assert!(!x.transition_custom(SlotState::Claimed.state(), NONE));
+    assert_eq!(x.state(LoadOrdering::Relaxed), State::None);
+
+    assert!(x.transition_custom(NONE, SlotState::Claimed.state()));
+    assert!(!x.transition_custom(NONE, SlotState::Claimed.state()));
+    assert!(x.transition_custom(SlotState::Claimed.state(), NONE));
+}
+
+#[test]
+#[should_panic]
+fn custom_state_transition_losing_value_panics() {
+    let x = ConcurrentOption::some(3.to_string());
+    x.transition_custom(SOME, SlotState::Claimed.state());
+}
+
+#[test]
+#[should_panic]
+fn custom_state_transition_to_reserved_panics() {
+    let x = ConcurrentOption::some(3.to_string());
+    x.transition_custom(SlotState::Dirty.state(), RESERVED);
+}
+
+#[test]
+fn custom_state_drop_holding_value() {
+    static NUM_DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+    struct Droppable;
+    impl Drop for Droppable {
+        fn drop(&mut self) {
+            NUM_DROPPED.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let x = ConcurrentOption::some(Droppable);
+    assert!(x.transition_custom(SOME, SlotState::Dirty.state()));
+    drop(x);
+    assert_eq!(NUM_DROPPED.load(Ordering::Relaxed), 1);
+
+    let x = ConcurrentOption::<Droppable>::none();
+    assert!(x.transition_custom(NONE, SlotState::Claimed.state()));
+    drop(x);
+    assert_eq!(NUM_DROPPED.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn custom_state_concurrent_claims() {
+    let slots: Vec<_> = (0..16).map(|_| ConcurrentOption::<usize>::none()).collect();
+    let num_claimed = AtomicUsize::new(0);
+
+    std::thread::scope(|s| {
+        for _ in 0..8 {
+            s.spawn(|| {
+                for slot in &slots {
+                    if slot.transition_custom(NONE, SlotState::Claimed.state()) {
+                        num_claimed.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            });
+        }
+    });
+
+    assert_eq!(num_claimed.load(Ordering::Relaxed), slots.len());
+}
+
+#[test]
+fn exclusive_reset_to_drops_value_held_in_custom_state() {
+    let value = std::sync::Arc::new(42);
+
+    let mut x = ConcurrentOption::some(value.clone());
+    assert!(x.transition_custom(SOME, SlotState::Dirty.state()));
+    assert_eq!(std::sync::Arc::strong_count(&value), 2);
+
+    x.exclusive_reset_to(None);
+    assert_eq!(std::sync::Arc::strong_count(&value), 1);
+    assert_eq!(x.state(LoadOrdering::Relaxed), State::None);
+
+    assert!(x.transition_custom(NONE, SlotState::Claimed.state()));
+    x.exclusive_reset_to(Some(value.clone()));
+    assert_eq!(x.state(LoadOrdering::Relaxed), State::Some);
+    assert_eq!(std::sync::Arc::strong_count(&value), 2);
+}
+
+#[test]
+#[should_panic]
+fn custom_state_transition_between_built_in_states_panics() {
+    let x = ConcurrentOption::some(3.to_string());
+    x.transition_custom(SOME, SOME);
+}
+
+#[test]
+#[should_panic]
+fn custom_state_replace_panics() {
+    let x = ConcurrentOption::none();
+    assert!(x.transition_custom(NONE, SlotState::Claimed.state()));
+    _ = x.replace(3.to_string());
+}
+
+#[test]
+#[should_panic]
+fn custom_state_try_replace_panics() {
+    let x = ConcurrentOption::none();
+    assert!(x.transition_custom(NONE, SlotState::Claimed.state()));
+    _ = x.try_replace(3.to_string(), DEFAULT_MAX_SPINS);
+}
+
+#[test]
+#[should_panic]
+fn custom_state_set_some_panics() {
+    let x = ConcurrentOption::some(3.to_string());
+    assert!(x.transition_custom(SOME, SlotState::Dirty.state()));
+    _ = x.set_some(7.to_string());
+}
+
+#[test]
+#[should_panic]
+fn custom_state_replace_with_fn_panics() {
+    let x = ConcurrentOption::none();
+    assert!(x.transition_custom(NONE, SlotState::Claimed.state()));
+    _ = x.replace_with_fn(|_| 3.to_string());
+}
+
+#[test]
+#[should_panic]
+fn custom_state_insert_panics() {
+    let x = ConcurrentOption::none();
+    assert!(x.transition_custom(NONE, SlotState::Claimed.state()));
+    _ = unsafe { x.insert(3.to_string()) };
+}
+
+#[test]
+#[should_panic]
+fn custom_state_entry_panics() {
+    let x = ConcurrentOption::<String>::none();
+    assert!(x.transition_custom(NONE, SlotState::Claimed.state()));
+    _ = x.entry();
+}
+
+#[test]
+#[should_panic]
+fn custom_state_replace_with_metadata_panics() {
+    use std::sync::atomic::AtomicU8;
+
+    let x = ConcurrentOptionWith::<_, AtomicU8>::none(0);
+    let option = x.option();
+    assert!(option.transition_custom(NONE, SlotState::Claimed.state()));
+    _ = x.replace_with_metadata(3.to_string(), 1);
+}
+
+#[test]
+fn custom_state_reads_return_as_none() {
+    use std::sync::atomic::AtomicU8;
+
+    let x = ConcurrentOptionWith::<_, AtomicU8>::some(3.to_string(), 1);
+    let option = x.option();
+    assert!(option.transition_custom(SOME, SlotState::Dirty.state()));
+
+    assert_eq!(x.map_with_metadata(|v, m| (v.cloned(), m)), (None, 1));
+    assert!(option.eq_snapshot(&None));
+    assert!(!option.eq_snapshot(&Some(3.to_string())));
+
+    assert!(option.transition_custom(SlotState::Dirty.state(), SOME));
+    assert_eq!(
+        x.map_with_metadata(|v, m| (v.cloned(), m)),
+        (Some(3.to_string()), 1)
+    );
+}
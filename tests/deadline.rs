@@ -0,0 +1,85 @@
+#![cfg(feature = "std")]
+
+use orx_concurrent_option::*;
+use std::{
+    sync::Barrier,
+    time::{Duration, Instant},
+};
+
+#[test]
+fn deadline_methods_fail_when_stalled() {
+    let x = ConcurrentOption::some(3.to_string());
+    let deadline = Instant::now() + Duration::from_millis(5);
+
+    {
+        let _stalled_writer = unsafe { x.mut_handle(SOME, SOME) };
+        assert_eq!(x.take_deadline(deadline), Err(Contended(())));
+        assert_eq!(
+            x.update_if_some_deadline(|x| x.push('!'), deadline),
+            Err(Contended(()))
+        );
+        assert_eq!(
+            x.replace_deadline(7.to_string(), deadline),
+            Err(ReplaceError::Contended(7.to_string()))
+        );
+        assert!(Instant::now() >= deadline);
+    }
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    assert_eq!(
+        x.update_if_some_deadline(|x| x.push('!'), deadline),
+        Ok(true)
+    );
+    assert_eq!(
+        x.replace_deadline(7.to_string(), deadline),
+        Ok(Some("3!".to_string()))
+    );
+    assert_eq!(x.take_deadline(deadline), Ok(Some(7.to_string())));
+    assert_eq!(x.take_deadline(deadline), Ok(None));
+    assert_eq!(
+        x.update_if_some_deadline(|x| x.push('!'), deadline),
+        Ok(false)
+    );
+    assert_eq!(x.replace_deadline(8.to_string(), deadline), Ok(None));
+}
+
+#[test]
+fn deadline_outlasts_short_reservation() {
+    let x = ConcurrentOption::some(42);
+    let deadline = Instant::now() + Duration::from_secs(10);
+
+    let reserved = Barrier::new(2);
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let _handle = unsafe { x.mut_handle(SOME, SOME) };
+            reserved.wait();
+            std::thread::sleep(Duration::from_millis(10));
+        });
+
+        reserved.wait();
+        assert_eq!(x.take_deadline(deadline), Ok(Some(42)));
+    });
+}
+
+#[test]
+fn shared_deadline_across_options() {
+    let slots: Vec<_> = (0..8).map(ConcurrentOption::some).collect();
+    let deadline = Instant::now() + Duration::from_secs(10);
+
+    let taken: usize = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                s.spawn(|| {
+                    slots
+                        .iter()
+                        .filter(|x| matches!(x.take_deadline(deadline), Ok(Some(_))))
+                        .count()
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+
+    assert_eq!(taken, 8);
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "deadlock-detection")]
+
+use orx_concurrent_option::{deadlock::*, *};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Barrier,
+    },
+    time::Duration,
+};
+
+// all tests manipulating the global hook are in a single test to avoid interference
+
+#[test]
+fn stuck_hook_is_called_once_per_wait() {
+    assert_eq!(stuck_threshold(), DEFAULT_STUCK_THRESHOLD);
+
+    let num_reports = Arc::new(AtomicUsize::new(0));
+    let hook_reports = num_reports.clone();
+    set_stuck_hook(move |stuck| {
+        assert_eq!(stuck.thread.name(), Some("stuck-taker"));
+        assert!(stuck.waited >= Duration::from_millis(10));
+        hook_reports.fetch_add(1, Ordering::Relaxed);
+    });
+    set_stuck_threshold(Duration::from_millis(10));
+    assert_eq!(stuck_threshold(), Duration::from_millis(10));
+
+    let x = ConcurrentOption::some(42);
+    let reserved = Barrier::new(2);
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let _stalled_writer = unsafe { x.mut_handle(SOME, SOME) };
+            reserved.wait();
+            std::thread::sleep(Duration::from_millis(100));
+        });
+
+        reserved.wait();
+        let taker = std::thread::Builder::new()
+            .name("stuck-taker".to_string())
+            .spawn_scoped(s, || x.take())
+            .unwrap();
+        assert_eq!(taker.join().unwrap(), Some(42));
+    });
+
+    assert_eq!(num_reports.load(Ordering::Relaxed), 1);
+
+    // short waits are not reported
+    set_stuck_threshold(Duration::from_secs(60));
+    let x = ConcurrentOption::some(42);
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let _stalled_writer = unsafe { x.mut_handle(SOME, SOME) };
+            reserved.wait();
+            std::thread::sleep(Duration::from_millis(20));
+        });
+        reserved.wait();
+        assert_eq!(x.take(), Some(42));
+    });
+    assert_eq!(num_reports.load(Ordering::Relaxed), 1);
+
+    reset_stuck_hook();
+    set_stuck_threshold(DEFAULT_STUCK_THRESHOLD);
+}
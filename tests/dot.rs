@@ -0,0 +1,73 @@
+#![cfg(feature = "dot")]
+
+use orx_concurrent_option::*;
+
+#[derive(Clone, Copy)]
+enum SlotState {
+    Dirty,
+    Claimed,
+    Sealed,
+}
+
+impl CustomState for SlotState {
+    fn id(self) -> u8 {
+        self as u8
+    }
+
+    fn holds_value(self) -> bool {
+        !matches!(self, Self::Claimed)
+    }
+}
+
+#[test]
+fn built_in_transitions() {
+    let expected = r#"digraph ConcurrentOption {
+    NONE [peripheries=1];
+    RESERVED [peripheries=1];
+    SOME [peripheries=2];
+    FROZEN [peripheries=2];
+    NONE -> RESERVED [label="reserve"];
+    SOME -> RESERVED [label="reserve"];
+    RESERVED -> SOME [label="release as some"];
+    RESERVED -> NONE [label="release as none"];
+    SOME -> FROZEN [label="freeze"];
+}
+"#;
+    assert_eq!(dot::transitions_as_dot(), expected);
+}
+
+#[test]
+fn custom_transitions_match_transition_custom() {
+    let states = [
+        ("DIRTY", SlotState::Dirty.state()),
+        ("CLAIMED", SlotState::Claimed.state()),
+        ("SEALED", SlotState::Sealed.state()),
+    ];
+    let dot = dot::export_dot(&states);
+
+    assert!(dot.contains("DIRTY [peripheries=2];"));
+    assert!(dot.contains("CLAIMED [peripheries=1];"));
+
+    let all = [("NONE", NONE), ("SOME", SOME)].into_iter().chain(states);
+    for (from_name, from) in all.clone() {
+        for (to_name, to) in all.clone() {
+            let is_edge = dot.contains(&format!("    {} -> {};", from_name, to_name));
+            let is_custom = from_name != "NONE" && from_name != "SOME"
+                || to_name != "NONE" && to_name != "SOME";
+
+            // transition_custom panics on the transitions which are not allowed
+            let is_allowed = std::panic::catch_unwind(|| {
+                ConcurrentOption::<u32>::none().transition_custom(from, to)
+            })
+            .is_ok();
+
+            assert_eq!(is_edge, from != to && is_custom && is_allowed);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn export_dot_rejects_built_in_states() {
+    _ = dot::export_dot(&[("SOME", SOME)]);
+}
@@ -0,0 +1,48 @@
+use orx_concurrent_option::*;
+use std::sync::Arc;
+
+#[test]
+fn dyn_slot_heterogeneous_registry() {
+    let value = Arc::new(42);
+    let a = ConcurrentOption::some(value.clone());
+    let b = ConcurrentOption::some(7.to_string());
+    let c = ConcurrentOption::<u64>::none();
+
+    let registry: Vec<&dyn DynSlot> = vec![&a, &b, &c];
+    let states: Vec<_> = registry.iter().map(|x| x.debug_state()).collect();
+    assert_eq!(states, [State::Some, State::Some, State::None]);
+
+    let cleared: Vec<_> = registry.iter().map(|x| x.clear()).collect();
+    assert_eq!(cleared, [true, true, false]);
+    assert_eq!(Arc::strong_count(&value), 1);
+    assert!(registry.iter().all(|x| !x.is_some()));
+}
+
+#[test]
+fn dyn_slot_reserved_state() {
+    let a = ConcurrentOption::some(1);
+    let slot: &dyn DynSlot = &a;
+
+    let handle = unsafe { a.mut_handle(SOME, SOME) };
+    assert_eq!(slot.debug_state(), State::Reserved);
+    assert!(!slot.is_some());
+
+    drop(handle);
+    assert_eq!(slot.debug_state(), State::Some);
+}
+
+#[test]
+fn dyn_slot_shared_across_threads() {
+    let a = ConcurrentOption::some(1u8);
+    let b = ConcurrentOption::some(2.to_string());
+    let registry: [&(dyn DynSlot + Sync); 2] = [&a, &b];
+
+    let num_cleared: usize = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|i| s.spawn(move || registry[i % 2].clear() as usize))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+
+    assert_eq!(num_cleared, 2);
+}
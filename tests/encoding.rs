@@ -0,0 +1,150 @@
+use orx_concurrent_option::encoding::*;
+use orx_concurrent_option::*;
+
+struct Pair;
+
+impl Encoder<(u8, u32)> for Pair {
+    fn encoded_len(&self, _: &(u8, u32)) -> usize {
+        5
+    }
+
+    fn encode(&self, value: &(u8, u32), buffer: &mut [u8]) {
+        buffer[0] = value.0;
+        buffer[1..].copy_from_slice(&value.1.to_le_bytes());
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Option<(u8, u32)> {
+        let (first, rest) = bytes.split_first()?;
+        Some((*first, u32::from_le_bytes(rest.try_into().ok()?)))
+    }
+}
+
+#[test]
+fn encode_into_round_trip() {
+    let mut buffer = [0u8; 16];
+    for x in [
+        ConcurrentOption::none(),
+        ConcurrentOption::some(0i64),
+        ConcurrentOption::some(i64::MIN),
+        ConcurrentOption::some(123456789),
+    ] {
+        let len = x.encode_into(&LittleEndian, &mut buffer).unwrap();
+        let y = ConcurrentOption::from_bytes(&LittleEndian, &buffer[..len]).unwrap();
+        assert_eq!(x, y);
+    }
+}
+
+#[test]
+fn encode_into_header() {
+    let mut buffer = [0xffu8; 4];
+
+    let x = ConcurrentOption::<u16>::none();
+    assert_eq!(x.encode_into(&LittleEndian, &mut buffer), Ok(1));
+    assert_eq!(buffer, [FORMAT_VERSION << 4, 0xff, 0xff, 0xff]);
+
+    let x = ConcurrentOption::some(0x0a0bu16);
+    assert_eq!(x.encode_into(&LittleEndian, &mut buffer), Ok(3));
+    assert_eq!(buffer, [(FORMAT_VERSION << 4) | 1, 0x0b, 0x0a, 0xff]);
+}
+
+#[test]
+fn encode_into_buffer_too_small() {
+    let x = ConcurrentOption::some(1u64);
+    let mut buffer = [0u8; 8];
+    assert_eq!(
+        x.encode_into(&LittleEndian, &mut buffer),
+        Err(EncodingError::BufferTooSmall { required: 9 })
+    );
+    assert_eq!(buffer, [0; 8]);
+
+    let x = ConcurrentOption::<u64>::none();
+    assert_eq!(
+        x.encode_into(&LittleEndian, &mut []),
+        Err(EncodingError::BufferTooSmall { required: 1 })
+    );
+}
+
+#[test]
+fn encode_frozen_as_some() {
+    let x = ConcurrentOption::some(3.5f32);
+    _ = x.freeze();
+    let mut buffer = [0u8; 5];
+    assert_eq!(x.encode_into(&LittleEndian, &mut buffer), Ok(5));
+    let y = ConcurrentOption::<f32>::from_bytes(&LittleEndian, &buffer).unwrap();
+    assert_eq!(y.get_cloned(), Some(3.5));
+}
+
+#[test]
+fn custom_encoder() {
+    let x = ConcurrentOption::some((7u8, 42u32));
+    let mut buffer = [0u8; 6];
+    assert_eq!(x.encode_into(&Pair, &mut buffer), Ok(6));
+    assert_eq!(buffer, [0x11, 7, 42, 0, 0, 0]);
+
+    let y = ConcurrentOption::from_bytes(&Pair, &buffer).unwrap();
+    assert_eq!(y.get_cloned(), Some((7, 42)));
+}
+
+#[test]
+fn from_bytes_errors() {
+    let decode = |bytes: &[u8]| ConcurrentOption::<u32>::from_bytes(&LittleEndian, bytes);
+
+    assert_eq!(decode(&[]), Err(EncodingError::Empty));
+    assert_eq!(decode(&[0x01]), Err(EncodingError::UnsupportedVersion(0)));
+    assert_eq!(decode(&[0xf0]), Err(EncodingError::UnsupportedVersion(15)));
+    assert_eq!(decode(&[0x12]), Err(EncodingError::InvalidVariant(2)));
+    assert_eq!(decode(&[0x10, 0]), Err(EncodingError::InvalidPayload));
+    assert_eq!(decode(&[0x11, 1, 2]), Err(EncodingError::InvalidPayload));
+    assert_eq!(
+        decode(&[0x11, 1, 2, 3, 4, 5]),
+        Err(EncodingError::InvalidPayload)
+    );
+    assert_eq!(
+        decode(&[0x11, 1, 0, 0, 0]).map(|x| x.get_cloned()),
+        Ok(Some(1))
+    );
+}
+
+#[test]
+fn error_display() {
+    assert_eq!(
+        EncodingError::BufferTooSmall { required: 3 }.to_string(),
+        "buffer is too small; 3 bytes are required"
+    );
+    assert_eq!(
+        EncodingError::UnsupportedVersion(2).to_string(),
+        "unsupported encoding format version 2"
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_bytes_matches_encode_into() {
+    let mut buffer = [0u8; 16];
+    for x in [ConcurrentOption::none(), ConcurrentOption::some(-7i32)] {
+        let len = x.encode_into(&LittleEndian, &mut buffer).unwrap();
+        assert_eq!(x.to_bytes(&LittleEndian), &buffer[..len]);
+    }
+}
+
+#[test]
+fn concurrent_snapshots_are_consistent() {
+    let x = ConcurrentOption::some((0u8, 0u32));
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            for i in 1..1000u32 {
+                _ = x.replace((i as u8, i));
+            }
+        });
+        s.spawn(|| {
+            let mut buffer = [0u8; 6];
+            for _ in 0..1000 {
+                let len = x.encode_into(&Pair, &mut buffer).unwrap();
+                let (a, b) = ConcurrentOption::from_bytes(&Pair, &buffer[..len])
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(a, b as u8);
+            }
+        });
+    });
+}
@@ -0,0 +1,81 @@
+use orx_concurrent_option::*;
+
+#[test]
+fn entry_reserves_option() {
+    let x = ConcurrentOption::some(3);
+    #[cfg(feature = "generation")]
+    let mut watcher = x.watcher();
+
+    let entry = x.entry();
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Reserved);
+    assert_eq!(format!("{:?}", entry), "Entry(OccupiedEntry(3))");
+    drop(entry);
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+    #[cfg(feature = "generation")]
+    assert!(watcher.has_changed());
+
+    _ = x.take();
+    let entry = x.entry();
+    assert_eq!(format!("{:?}", entry), "Entry(VacantEntry)");
+    drop(entry);
+    assert_eq!(x.state(LoadOrdering::Acquire), State::None);
+}
+
+#[test]
+fn occupied_remove_and_replace() {
+    let x = ConcurrentOption::some(3.to_string());
+
+    match x.entry() {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(entry.replace(4.to_string()), 3.to_string());
+            entry.push('!');
+        }
+        Entry::Vacant(_) => unreachable!(),
+    }
+    assert_eq!(x.get_cloned(), Some("4!".to_string()));
+
+    match x.entry() {
+        Entry::Occupied(entry) => assert_eq!(entry.remove(), "4!".to_string()),
+        Entry::Vacant(_) => unreachable!(),
+    }
+    assert!(x.is_none());
+}
+
+#[test]
+fn vacant_insert() {
+    let x = ConcurrentOption::none();
+
+    match x.entry() {
+        Entry::Occupied(_) => unreachable!(),
+        Entry::Vacant(entry) => {
+            let mut guard = entry.insert(vec![1]);
+            assert_eq!(x.state(LoadOrdering::Acquire), State::Reserved);
+            guard.push(2);
+        }
+    }
+
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+    assert_eq!(x.take(), Some(vec![1, 2]));
+}
+
+#[test]
+fn concurrent_increment_or_insert() {
+    let x = ConcurrentOption::none();
+    let num_threads = 8;
+    let num_iters = 100;
+
+    std::thread::scope(|s| {
+        for _ in 0..num_threads {
+            s.spawn(|| {
+                for _ in 0..num_iters {
+                    match x.entry() {
+                        Entry::Occupied(mut entry) => *entry += 1,
+                        Entry::Vacant(entry) => _ = entry.insert(1),
+                    }
+                }
+            });
+        }
+    });
+
+    assert_eq!(x.take(), Some(num_threads * num_iters));
+}
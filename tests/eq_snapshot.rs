@@ -0,0 +1,49 @@
+use orx_concurrent_option::*;
+
+#[test]
+fn eq_snapshot_variants() {
+    let x = ConcurrentOption::some(3);
+
+    assert!(x.eq_snapshot(&Some(3)));
+    assert!(!x.eq_snapshot(&Some(4)));
+    assert!(!x.eq_snapshot(&None));
+
+    _ = x.take();
+    assert!(x.eq_snapshot(&None));
+    assert!(!x.eq_snapshot(&Some(3)));
+
+    for o in [
+        LoadOrdering::Relaxed,
+        LoadOrdering::Acquire,
+        LoadOrdering::SeqCst,
+    ] {
+        assert!(x.eq_snapshot_with_order(&None, o));
+    }
+}
+
+#[test]
+fn eq_snapshot_never_observes_torn_value() {
+    let x = ConcurrentOption::some((0usize, 0usize));
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for i in 0..200 {
+                    // a pair with differing elements never exists in the option
+                    assert!(!x.eq_snapshot(&Some((i, i + 1))));
+                    std::thread::yield_now();
+                }
+            });
+        }
+        s.spawn(|| {
+            for _ in 0..200 {
+                x.update_if_some(|v| {
+                    v.0 += 1;
+                    v.1 += 1;
+                });
+            }
+        });
+    });
+
+    assert!(x.eq_snapshot(&Some((200, 200))));
+}
@@ -0,0 +1,147 @@
+use orx_concurrent_option::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn freeze_none_is_no_op() {
+    let x = ConcurrentOption::<String>::none();
+    assert_eq!(x.freeze(), None);
+    assert!(!x.is_frozen());
+    assert_eq!(x.state(LoadOrdering::Relaxed), State::None);
+
+    x.initialize_if_none(1.to_string());
+    assert_eq!(x.freeze(), Some(&1.to_string()));
+    assert_eq!(x.freeze(), Some(&1.to_string()));
+    assert_eq!(x.state(LoadOrdering::Relaxed), State::Frozen);
+}
+
+#[test]
+fn reads_treat_frozen_as_some() {
+    let x = ConcurrentOption::some(3.to_string());
+    assert_eq!(x.get(), None);
+    _ = x.freeze();
+
+    assert!(x.is_some());
+    assert!(!x.is_none());
+    assert_eq!(x.get(), Some(&3.to_string()));
+    assert_eq!(x.get_cloned(), Some(3.to_string()));
+    assert_eq!(x.map(|x| x.len()), Some(1));
+    assert!(x.eq_snapshot(&Some(3.to_string())));
+    assert_eq!(x.clone().get_cloned(), Some(3.to_string()));
+    assert_eq!(ConcurrentOption::len_hint(core::slice::from_ref(&x)), 1);
+}
+
+#[test]
+fn mutations_fail_on_frozen() {
+    let x = ConcurrentOption::some(3.to_string());
+    #[cfg(feature = "generation")]
+    let mut watcher = x.watcher();
+    _ = x.freeze();
+
+    assert_eq!(x.take(), None);
+    assert_eq!(x.take_if(|_| true), None);
+    assert!(!x.update_if_some(|x| x.push('!')));
+    assert!(!x.initialize_if_none(4.to_string()));
+    assert!(!x.set_lazy(4.to_string()));
+    assert_eq!(
+        x.try_replace(4.to_string(), DEFAULT_MAX_SPINS),
+        Err(ReplaceError::Frozen(4.to_string()))
+    );
+    #[cfg(feature = "std")]
+    assert_eq!(
+        x.replace_deadline(4.to_string(), std::time::Instant::now()),
+        Err(ReplaceError::Frozen(4.to_string()))
+    );
+    assert!(x.get_guard().unwrap().try_upgrade().is_err());
+    assert!(x.lock_mut().is_none());
+    assert!(x.get_raw_mut().is_none());
+
+    #[cfg(feature = "generation")]
+    assert!(!watcher.has_changed());
+    assert_eq!(x.get(), Some(&3.to_string()));
+}
+
+#[test]
+#[should_panic]
+fn replace_panics_on_frozen() {
+    let x = ConcurrentOption::some(3);
+    _ = x.freeze();
+    _ = x.replace(4);
+}
+
+#[test]
+fn exclusive_methods_unfreeze() {
+    let mut x = ConcurrentOption::some(3);
+    _ = x.freeze();
+
+    *x.exclusive_as_mut().unwrap() += 1;
+    assert_eq!(x.get(), Some(&4));
+
+    assert_eq!(x.exclusive_replace(5), Some(4));
+    assert!(!x.is_frozen());
+    assert_eq!(x.take(), Some(5));
+}
+
+#[test]
+fn frozen_value_is_dropped() {
+    struct Counted<'a>(&'a AtomicUsize);
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let num_dropped = AtomicUsize::new(0);
+    let x = ConcurrentOption::some(Counted(&num_dropped));
+    _ = x.freeze();
+    drop(x);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn concurrent_freeze_and_writers() {
+    let x = ConcurrentOption::some(0usize);
+    let num_threads = 8;
+
+    std::thread::scope(|s| {
+        for _ in 0..num_threads {
+            s.spawn(|| while x.update_if_some(|x| *x += 1) {});
+        }
+        s.spawn(|| {
+            let value = *x.freeze().unwrap();
+            assert_eq!(x.get(), Some(&value));
+        });
+    });
+
+    let value = *x.get().unwrap();
+    assert_eq!(x.freeze(), Some(&value));
+}
+
+#[test]
+fn into_frozen() {
+    let x = ConcurrentOption::some(3.to_string());
+    _ = x.freeze();
+    let frozen = x.into_frozen();
+    assert_eq!(frozen.as_deref(), Some("3"));
+    assert_eq!(*frozen, Some(3.to_string()));
+
+    let frozen = ConcurrentOption::<String>::none().into_frozen();
+    assert!(frozen.is_none());
+    assert_eq!(frozen, FrozenOption::default());
+    assert_eq!(frozen.into_inner(), None);
+}
+
+#[test]
+fn into_frozen_is_shared_across_threads() {
+    fn assert_sync<S: Sync>(_: &S) {}
+
+    let frozen = ConcurrentOption::some(vec![1, 2, 3]).into_frozen();
+    assert_sync(&frozen);
+
+    let sums: Vec<_> = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| s.spawn(|| frozen.as_ref().map(|x| x.iter().sum::<i32>())))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    assert_eq!(sums, [Some(6); 4]);
+}
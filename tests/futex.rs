@@ -0,0 +1,140 @@
+#![cfg(feature = "futex")]
+
+use orx_concurrent_option::*;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    time::{Duration, Instant},
+};
+
+/// Counts the allocations of each thread, so that waits can be verified to be allocation-free.
+struct CountingAllocator;
+
+thread_local! {
+    static NUM_ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        NUM_ALLOCATIONS.with(|x| x.set(x.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn num_allocations() -> usize {
+    NUM_ALLOCATIONS.with(|x| x.get())
+}
+
+#[test]
+fn writers_wait_for_long_reservations() {
+    let num_threads = 8;
+    let num_updates = 100;
+    let x = ConcurrentOption::some(0);
+
+    std::thread::scope(|s| {
+        for _ in 0..num_threads {
+            s.spawn(|| {
+                for _ in 0..num_updates {
+                    x.update_if_some(|x| {
+                        std::thread::sleep(Duration::from_micros(50));
+                        *x += 1;
+                    });
+                }
+            });
+        }
+    });
+
+    assert_eq!(x.get_cloned(), Some(num_threads * num_updates));
+}
+
+#[test]
+fn readers_wait_for_long_reservations() {
+    let x = ConcurrentOption::some(1);
+
+    std::thread::scope(|s| {
+        let handle = unsafe { x.mut_handle(SOME, SOME) }.unwrap();
+        let readers: Vec<_> = (0..4).map(|_| s.spawn(|| x.get_cloned())).collect();
+        std::thread::sleep(Duration::from_millis(20));
+        unsafe { *handle.get_mut() = 2 };
+        drop(handle);
+
+        for reader in readers {
+            assert_eq!(reader.join().unwrap(), Some(2));
+        }
+    });
+}
+
+#[test]
+fn waiters_are_woken_by_initialization() {
+    let x = ConcurrentOption::<String>::none();
+
+    std::thread::scope(|s| {
+        let waiters: Vec<_> = (0..4)
+            .map(|_| {
+                s.spawn(|| {
+                    let value = x.wait_for_some().clone();
+                    (value, Instant::now())
+                })
+            })
+            .collect();
+
+        std::thread::sleep(Duration::from_millis(20));
+        let initialized_at = Instant::now();
+        x.initialize_if_none(7.to_string());
+
+        for waiter in waiters {
+            let (value, woken_at) = waiter.join().unwrap();
+            assert_eq!(value, 7.to_string());
+            assert!(woken_at >= initialized_at);
+        }
+    });
+}
+
+#[test]
+fn rendezvous_handoff() {
+    let num_items = 1000;
+    let slot = ConcurrentOption::none();
+
+    let consumed: Vec<_> = std::thread::scope(|s| {
+        s.spawn(|| {
+            for i in 0..num_items {
+                slot.wait_for_none();
+                assert!(slot.initialize_if_none(i));
+            }
+        });
+
+        let consumer = s.spawn(|| (0..num_items).map(|_| slot.wait_and_take()).collect());
+        consumer.join().unwrap()
+    });
+
+    assert_eq!(consumed, (0..num_items).collect::<Vec<_>>());
+}
+
+#[test]
+fn blocking_waits_are_allocation_free() {
+    let x = ConcurrentOption::<u64>::none();
+
+    std::thread::scope(|s| {
+        let waiter = s.spawn(|| {
+            let before = num_allocations();
+            let value = *x.wait_for_some();
+            let taken = x.wait_and_take();
+            x.wait_for_none();
+            (value, taken, num_allocations() - before)
+        });
+
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(x.initialize_if_none(42));
+
+        let (value, taken, num_allocations) = waiter.join().unwrap();
+        assert_eq!((value, taken), (42, 42));
+        assert_eq!(num_allocations, 0);
+    });
+}
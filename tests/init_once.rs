@@ -0,0 +1,78 @@
+#![cfg(feature = "init-once")]
+
+use orx_concurrent_option::*;
+
+#[test]
+fn begin_once_is_sticky() {
+    let x = ConcurrentOption::<String>::none();
+    assert!(!x.is_once_begun());
+
+    let token = x.begin_once().unwrap();
+    assert!(x.is_once_begun());
+    assert!(x.begin_once().is_none());
+
+    drop(token);
+    assert!(x.begin_once().is_none());
+    assert!(x.is_none());
+}
+
+#[test]
+fn complete_initializes() {
+    let x = ConcurrentOption::none();
+    #[cfg(feature = "generation")]
+    let mut watcher = x.watcher();
+
+    let token = x.begin_once().unwrap();
+    #[cfg(feature = "generation")]
+    assert!(!watcher.has_changed());
+    assert_eq!(x.complete(token, 3.to_string()), Ok(()));
+    #[cfg(feature = "generation")]
+    assert!(watcher.has_changed());
+    assert_eq!(x.take(), Some(3.to_string()));
+
+    assert!(x.begin_once().is_none());
+}
+
+#[test]
+fn complete_rejects_foreign_token() {
+    let x = ConcurrentOption::none();
+    let y = ConcurrentOption::none();
+
+    let token = y.begin_once().unwrap();
+    assert_eq!(x.complete(token, 1), Err(1));
+    assert!(x.is_none() && y.is_none());
+    assert!(!x.is_once_begun());
+}
+
+#[test]
+fn complete_on_some_returns_value() {
+    let x = ConcurrentOption::some(1);
+    let token = x.begin_once().unwrap();
+    assert_eq!(x.complete(token, 2), Err(2));
+    assert_eq!(x.get_cloned(), Some(1));
+}
+
+#[test]
+fn single_token_among_concurrent_initializers() {
+    let x = ConcurrentOption::none();
+    let num_threads = 8;
+
+    let num_completed: usize = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..num_threads)
+            .map(|i| {
+                let x = &x;
+                s.spawn(move || match x.begin_once() {
+                    Some(token) => {
+                        assert!(x.complete(token, i).is_ok());
+                        1
+                    }
+                    None => 0,
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+
+    assert_eq!(num_completed, 1);
+    assert!(x.is_some());
+}
@@ -0,0 +1,68 @@
+use orx_concurrent_option::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[test]
+fn initialized_tokens_share_the_value() {
+    let x = ConcurrentOption::<String>::none();
+    assert!(x.initialized().is_none());
+
+    let first = x.get_or_initialize(1.to_string());
+    let second = x.get_or_initialize(2.to_string());
+    let third = x.initialized().unwrap();
+
+    assert_eq!(first.get(), "1");
+    assert_eq!(second.as_str(), "1");
+    assert_eq!(format!("{:?}", third), "Initialized(\"1\")");
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+    assert_eq!(x.map(|x| x.len()), Some(1));
+
+    drop((first, second, third));
+    assert_eq!(x.take(), Some(1.to_string()));
+    assert!(x.initialized().is_none());
+}
+
+#[test]
+fn writer_waits_for_initialized_token() {
+    let x = ConcurrentOption::none();
+    let token_dropped = AtomicBool::new(false);
+
+    std::thread::scope(|s| {
+        let token = x.get_or_initialize(3.to_string());
+
+        s.spawn(|| {
+            assert_eq!(x.take(), Some(3.to_string()));
+            assert!(token_dropped.load(Ordering::SeqCst));
+        });
+
+        for _ in 0..10 {
+            std::thread::yield_now();
+        }
+        assert_eq!(token.get(), "3");
+        token_dropped.store(true, Ordering::SeqCst);
+        drop(token);
+    });
+
+    assert!(x.is_none());
+}
+
+#[test]
+fn get_or_initialize_concurrently_with_takes() {
+    let x = ConcurrentOption::none();
+
+    std::thread::scope(|s| {
+        for i in 0..4 {
+            let x = &x;
+            s.spawn(move || {
+                for j in 0..100 {
+                    let value = x.get_or_initialize(i * 1000 + j);
+                    assert!(*value.get() < 4000);
+                }
+            });
+        }
+        s.spawn(|| {
+            for _ in 0..100 {
+                _ = x.take();
+            }
+        });
+    });
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "async")]
+
+use orx_concurrent_option::*;
+use std::{
+    future::Future,
+    pin::pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll, RawWaker, RawWakerVTable, Wake, Waker},
+    thread::Thread,
+};
+
+/// Waker which unparks the blocked thread and counts the number of wake-ups.
+struct ThreadWaker {
+    thread: Thread,
+    num_wakes: AtomicUsize,
+}
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.num_wakes.fetch_add(1, Ordering::SeqCst);
+        self.thread.unpark();
+    }
+}
+
+/// Polls the future only when it is woken; hence, never returns if a wake-up is lost.
+fn block_on<F: Future>(future: F) -> (F::Output, usize) {
+    let waker = Arc::new(ThreadWaker {
+        thread: std::thread::current(),
+        num_wakes: AtomicUsize::new(0),
+    });
+    let waker_of_task = Waker::from(waker.clone());
+    let mut cx = Context::from_waker(&waker_of_task);
+    let mut future = pin!(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return (output, waker.num_wakes.load(Ordering::SeqCst)),
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+#[test]
+fn initialized_async_resolves_immediately_when_some() {
+    let x = ConcurrentOption::some(3);
+    let (_, num_wakes) = block_on(x.initialized_async());
+    assert_eq!(num_wakes, 0);
+
+    let (value, _) = block_on(x.get_cloned_async());
+    assert_eq!(value, 3);
+}
+
+#[test]
+fn initialized_async_is_woken_by_initialization() {
+    let x = ConcurrentOption::<String>::none();
+
+    std::thread::scope(|s| {
+        let reader = s.spawn(|| block_on(x.get_cloned_async()));
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        assert!(x.initialize_if_none(7.to_string()));
+
+        let (value, num_wakes) = reader.join().unwrap();
+        assert_eq!(value, 7.to_string());
+        assert!(num_wakes >= 1);
+    });
+}
+
+#[test]
+fn get_cloned_async_keeps_waiting_after_take() {
+    let x = ConcurrentOption::none();
+    let mut cx = Context::from_waker(Waker::noop());
+
+    let mut value = pin!(x.get_cloned_async());
+    assert_eq!(value.as_mut().poll(&mut cx), Poll::Pending);
+
+    // the value is gone before the future is polled again
+    assert!(x.initialize_if_none(1));
+    assert_eq!(x.take(), Some(1));
+    assert_eq!(value.as_mut().poll(&mut cx), Poll::Pending);
+
+    assert!(x.initialize_if_none(2));
+    assert_eq!(value.as_mut().poll(&mut cx), Poll::Ready(2));
+}
+
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn dropped_future_deregisters_its_waker() {
+    let x = ConcurrentOption::none();
+
+    let dropped = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Waker::from(dropped.clone());
+    let mut cx = Context::from_waker(&waker);
+    {
+        let mut initialized = pin!(x.initialized_async());
+        assert_eq!(initialized.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(initialized.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(x.waiter_count(), 1);
+    }
+    assert_eq!(x.waiter_count(), 0);
+
+    let pending = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Waker::from(pending.clone());
+    let mut cx = Context::from_waker(&waker);
+    let mut initialized = pin!(x.initialized_async());
+    assert_eq!(initialized.as_mut().poll(&mut cx), Poll::Pending);
+
+    assert!(x.initialize_if_none(42));
+    assert_eq!(dropped.0.load(Ordering::SeqCst), 0);
+    assert_eq!(pending.0.load(Ordering::SeqCst), 1);
+    assert_eq!(initialized.as_mut().poll(&mut cx), Poll::Ready(()));
+}
+
+#[test]
+fn many_tasks_are_woken() {
+    let x = ConcurrentOption::none();
+    let num_readers = 16;
+
+    std::thread::scope(|s| {
+        let readers: Vec<_> = (0..num_readers)
+            .map(|_| s.spawn(|| block_on(x.get_cloned_async()).0))
+            .collect();
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        assert!(x.initialize_if_none(5));
+
+        for reader in readers {
+            assert_eq!(reader.join().unwrap(), 5);
+        }
+    });
+}
+
+#[test]
+fn initialized_async_deregisters_when_initialized_during_registration() {
+    // the waker initializes the option when it is cloned, which happens while the future registers it;
+    // hence, the re-check right after the registration observes the option as Some
+    fn clone(data: *const ()) -> RawWaker {
+        let option = unsafe { &*(data as *const ConcurrentOption<u32>) };
+        option.initialize_if_none(42);
+        RawWaker::new(data, &VTABLE)
+    }
+    fn noop(_: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+    let x = ConcurrentOption::<u32>::none();
+    let raw = RawWaker::new(&x as *const ConcurrentOption<u32> as *const (), &VTABLE);
+    let waker = unsafe { Waker::from_raw(raw) };
+    let mut cx = Context::from_waker(&waker);
+
+    let mut initialized = pin!(x.initialized_async());
+    assert_eq!(initialized.as_mut().poll(&mut cx), Poll::Ready(()));
+    assert_eq!(x.waiter_count(), 0);
+}
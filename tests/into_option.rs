@@ -17,3 +17,86 @@ fn concurrent_option_into_option() {
     let x: ConcurrentOption<String> = Default::default();
     assert_eq!(x.into_option(), None);
 }
+
+#[test]
+fn exclusive_into_option() {
+    let mut x = ConcurrentOption::some(42.to_string());
+    assert_eq!(x.exclusive_into_option(), Some(42.to_string()));
+    assert!(x.is_none());
+    assert_eq!(x.exclusive_into_option(), None);
+}
+
+#[test]
+fn exclusive_from_option() {
+    let mut x = ConcurrentOption::<String>::none();
+
+    x.exclusive_from_option(None);
+    assert!(x.is_none());
+
+    x.exclusive_from_option(Some(42.to_string()));
+    assert_eq!(x, ConcurrentOption::some(42.to_string()));
+
+    x.exclusive_from_option(Some(7.to_string()));
+    assert_eq!(x, ConcurrentOption::some(7.to_string()));
+
+    x.exclusive_from_option(None);
+    assert!(x.is_none());
+}
+
+#[test]
+fn into_option_with_order() {
+    let x = Some(42.to_string());
+    assert_eq!(
+        x.into_option_with_order(LoadOrdering::Relaxed),
+        Some(42.to_string())
+    );
+
+    let x = ConcurrentOption::some(42.to_string());
+    assert_eq!(
+        (&x).into_option_with_order(LoadOrdering::Acquire),
+        Some(42.to_string())
+    );
+    assert_eq!((&x).into_option(), Some(42.to_string()));
+    assert_eq!(
+        x.into_option_with_order(LoadOrdering::SeqCst),
+        Some(42.to_string())
+    );
+
+    let x = ConcurrentOption::<String>::none();
+    assert_eq!((&x).into_option_with_order(LoadOrdering::Relaxed), None);
+    assert_eq!(x.into_option_with_order(LoadOrdering::Relaxed), None);
+}
+
+#[test]
+fn to_option() {
+    fn snapshot<O: IntoOption<String>>(x: &O) -> Option<String> {
+        x.to_option()
+    }
+
+    let x = Some(42.to_string());
+    assert_eq!(snapshot(&x), Some(42.to_string()));
+    assert_eq!(x, Some(42.to_string()));
+
+    let x = ConcurrentOption::some(42.to_string());
+    assert_eq!(snapshot(&x), Some(42.to_string()));
+    assert_eq!(snapshot(&&x), Some(42.to_string()));
+    assert_eq!(x.take(), Some(42.to_string()));
+    assert_eq!(snapshot(&x), None);
+}
+
+#[test]
+fn exclusive_reset_to() {
+    let mut x = ConcurrentOption::<String>::none();
+
+    x.exclusive_reset_to(None);
+    assert!(x.is_none());
+
+    x.exclusive_reset_to(Some(42.to_string()));
+    assert_eq!(x, ConcurrentOption::some(42.to_string()));
+
+    x.exclusive_reset_to(Some(7.to_string()));
+    assert_eq!(x, ConcurrentOption::some(7.to_string()));
+
+    x.exclusive_reset_to(None);
+    assert!(x.is_none());
+}
@@ -1,4 +1,3 @@
-use core::sync::atomic::Ordering;
 use orx_concurrent_option::*;
 
 #[test]
@@ -11,7 +10,7 @@ fn iter_with_order_when_none() {
 
     let x = ConcurrentOption::<String>::none();
     validate(unsafe { x.iter() });
-    validate(unsafe { x.iter_with_order(Ordering::Relaxed) }.rev());
+    validate(unsafe { x.iter_with_order(LoadOrdering::Relaxed) }.rev());
     validate((&x).into_iter());
 
     fn validate_value(mut iter: impl ExactSizeIterator<Item = String>) {
@@ -21,7 +20,7 @@ fn iter_with_order_when_none() {
     }
 
     let x = ConcurrentOption::<String>::none();
-    validate_value(unsafe { x.iter_with_order(Ordering::Acquire) }.cloned());
+    validate_value(unsafe { x.iter_with_order(LoadOrdering::Acquire) }.cloned());
     validate_value(unsafe { x.iter() }.rev().cloned());
     validate_value(x.into_iter());
 }
@@ -38,8 +37,8 @@ fn exclusive_iter_when_some() {
 
     let x = ConcurrentOption::some(3.to_string());
     unsafe {
-        validate(x.iter_with_order(Ordering::Relaxed));
-        validate(x.iter_with_order(Ordering::Relaxed).rev());
+        validate(x.iter_with_order(LoadOrdering::Relaxed));
+        validate(x.iter_with_order(LoadOrdering::Relaxed).rev());
     }
     validate((&x).into_iter());
 
@@ -53,8 +52,30 @@ fn exclusive_iter_when_some() {
 
     let x = ConcurrentOption::some(3.to_string());
     unsafe {
-        validate_value(x.iter_with_order(Ordering::Relaxed).cloned());
-        validate_value(x.iter_with_order(Ordering::SeqCst).rev().cloned());
+        validate_value(x.iter_with_order(LoadOrdering::Relaxed).cloned());
+        validate_value(x.iter_with_order(LoadOrdering::SeqCst).rev().cloned());
     }
     validate_value(x.into_iter());
 }
+
+#[test]
+fn into_iter_with_order() {
+    fn validate_value(mut iter: impl ExactSizeIterator<Item = String>, expected: Option<String>) {
+        assert_eq!(iter.len(), expected.iter().len());
+        assert_eq!(iter.next(), expected);
+        assert_eq!(iter.len(), 0);
+        assert!(iter.next().is_none());
+    }
+
+    for order in [
+        LoadOrdering::Relaxed,
+        LoadOrdering::Acquire,
+        LoadOrdering::SeqCst,
+    ] {
+        let x = ConcurrentOption::some(3.to_string());
+        validate_value(x.into_iter_with_order(order), Some(3.to_string()));
+
+        let x = ConcurrentOption::<String>::none();
+        validate_value(x.into_iter_with_order(order), None);
+    }
+}
@@ -0,0 +1,130 @@
+use orx_concurrent_option::*;
+
+#[test]
+fn iter_guards() {
+    let slots = [
+        ConcurrentOption::none(),
+        ConcurrentOption::some(1.to_string()),
+        ConcurrentOption::none(),
+        ConcurrentOption::some(3.to_string()),
+    ];
+
+    let visited: Vec<_> = ConcurrentOption::iter_guards(&slots)
+        .map(|(idx, value)| (idx, value.clone()))
+        .collect();
+    assert_eq!(visited, [(1, 1.to_string()), (3, 3.to_string())]);
+
+    // reservations are released
+    assert_eq!(slots[1].take(), Some(1.to_string()));
+    assert_eq!(slots[3].map(|x| x.len()), Some(1));
+}
+
+#[test]
+fn iter_guards_empty() {
+    let slots: [ConcurrentOption<String>; 0] = [];
+    assert_eq!(ConcurrentOption::iter_guards(&slots).count(), 0);
+
+    let slots = [ConcurrentOption::<String>::none(), ConcurrentOption::none()];
+    let mut iter = ConcurrentOption::iter_guards(&slots);
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn iter_guards_blocks_writers_of_current_slot() {
+    let slots = [ConcurrentOption::some(0), ConcurrentOption::some(1)];
+    let mut iter = ConcurrentOption::iter_guards(&slots);
+
+    let (idx, guard) = iter.next().unwrap();
+    assert_eq!(idx, 0);
+    assert_eq!(*guard, 0);
+    assert_eq!(
+        slots[0].try_update_if_some(|x| *x += 1, 4),
+        Err(Contended(()))
+    );
+    assert_eq!(slots[1].try_update_if_some(|x| *x += 1, 4), Ok(true));
+    drop(guard);
+    assert_eq!(slots[0].try_update_if_some(|x| *x += 1, 4), Ok(true));
+
+    let (idx, guard) = iter.next().unwrap();
+    assert_eq!(idx, 1);
+    assert_eq!(format!("{:?}", guard), "ReadGuard(2)");
+}
+
+#[test]
+fn concurrent_iter_guards() {
+    let slots: Vec<_> = (0..64).map(ConcurrentOption::some).collect();
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..10 {
+                    for (idx, value) in ConcurrentOption::iter_guards(&slots) {
+                        assert_eq!(idx % 2, *value % 2);
+                    }
+                }
+            });
+        }
+
+        s.spawn(|| {
+            for (i, slot) in slots.iter().enumerate() {
+                if i % 3 == 0 {
+                    _ = slot.take();
+                } else {
+                    slot.update_if_some(|x| *x += 2);
+                }
+            }
+        });
+    });
+}
+
+#[test]
+fn iter_guarded() {
+    let x = ConcurrentOption::some(3.to_string());
+
+    let mut iter = x.iter_guarded();
+    assert_eq!(iter.len(), 1);
+    assert_eq!(x.try_take(16), Err(Contended(())));
+
+    let guard = iter.next().unwrap();
+    assert!(iter.next().is_none());
+    drop(iter);
+    assert_eq!(x.try_take(16), Err(Contended(())));
+    assert_eq!(guard.as_str(), "3");
+    drop(guard);
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+
+    let collected: Vec<_> = x.iter_guarded().rev().map(|x| x.clone()).collect();
+    assert_eq!(collected, [3.to_string()]);
+
+    _ = x.take();
+    let mut iter = x.iter_guarded();
+    assert_eq!(iter.len(), 0);
+    assert!(iter.next().is_none());
+    assert_eq!(x.state(LoadOrdering::Acquire), State::None);
+}
+
+#[test]
+fn iter_guarded_is_not_invalidated_by_concurrent_take() {
+    let x = ConcurrentOption::some(vec![1, 2, 3]);
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            for _ in 0..100 {
+                for value in x.iter_guarded() {
+                    std::thread::yield_now();
+                    assert_eq!(value.iter().sum::<i32>(), 6);
+                }
+            }
+        });
+        s.spawn(|| {
+            for _ in 0..100 {
+                if let Some(value) = x.take() {
+                    x.set_some(value);
+                }
+            }
+        });
+    });
+
+    assert_eq!(x.take(), Some(vec![1, 2, 3]));
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "last-updated")]
+
+use orx_concurrent_option::*;
+use std::time::Instant;
+
+#[test]
+fn last_updated_not_mutated() {
+    let x = ConcurrentOption::some(3);
+    assert_eq!(x.last_updated(), None);
+
+    _ = x.map(|x| x + 1);
+    _ = x.is_some();
+    assert_eq!(x.last_updated(), None);
+
+    let y = ConcurrentOption::<i32>::none();
+    assert_eq!(y.take(), None);
+    assert_eq!(y.last_updated(), None);
+}
+
+#[test]
+fn last_updated_concurrent_mutations() {
+    let x = ConcurrentOption::some(3);
+
+    let before = Instant::now();
+    x.update_if_some(|x| *x += 1);
+    let first = x.last_updated().unwrap();
+    assert!(first >= before);
+
+    std::thread::sleep(std::time::Duration::from_millis(2));
+    _ = x.replace(7);
+    let second = x.last_updated().unwrap();
+    assert!(second > first);
+
+    std::thread::sleep(std::time::Duration::from_millis(2));
+    _ = x.take();
+    assert!(x.last_updated().unwrap() > second);
+}
+
+#[test]
+fn last_updated_exclusive_mutations() {
+    let mut x = ConcurrentOption::<String>::none();
+
+    let before = Instant::now();
+    x.exclusive_insert(3.to_string());
+    let first = x.last_updated().unwrap();
+    assert!(first >= before);
+
+    std::thread::sleep(std::time::Duration::from_millis(2));
+    _ = x.exclusive_take();
+    assert!(x.last_updated().unwrap() > first);
+}
@@ -0,0 +1,70 @@
+use orx_concurrent_option::*;
+
+concurrent_static!(
+    static NAME: String;
+);
+
+concurrent_static!(
+    /// Documented global.
+    pub static NUMBERS: Vec<usize>;
+);
+
+#[test]
+fn concurrent_static() {
+    assert!(!NAME::is_initialized());
+    assert_eq!(NAME::get(), None);
+
+    assert!(NAME::init(3.to_string()));
+    assert!(NAME::is_initialized());
+    assert_eq!(NAME::get(), Some(&3.to_string()));
+
+    assert!(!NAME::init(7.to_string()));
+    assert_eq!(NAME::get(), Some(&3.to_string()));
+}
+
+#[test]
+fn concurrent_static_concurrent_init() {
+    std::thread::scope(|s| {
+        for i in 0..8 {
+            s.spawn(move || {
+                _ = NUMBERS::init(vec![i; 4]);
+                let numbers = NUMBERS::get().expect("is initialized");
+                assert_eq!(numbers.len(), 4);
+            });
+        }
+    });
+
+    let numbers = NUMBERS::get().expect("is initialized");
+    assert!(numbers.iter().all(|x| *x == numbers[0]));
+}
+
+#[test]
+fn copt_some() {
+    let x = copt!(3.to_string());
+    assert_eq!(x, ConcurrentOption::some(3.to_string()));
+
+    let x = copt!(Some(3));
+    assert_eq!(x, ConcurrentOption::some(Some(3)));
+}
+
+#[test]
+fn copt_none() {
+    let x: ConcurrentOption<String> = copt!(None);
+    assert_eq!(x, ConcurrentOption::none());
+}
+
+#[test]
+fn copt_none_array() {
+    struct NotClone(String);
+
+    let slots: [ConcurrentOption<NotClone>; 3] = copt![None; 3];
+    assert!(slots.iter().all(|x| x.is_none()));
+
+    assert!(slots[1].initialize_if_none(NotClone(1.to_string())));
+    assert_eq!(slots[0].map(|x| x.0.clone()), None);
+    assert_eq!(slots[1].map(|x| x.0.clone()), Some(1.to_string()));
+    assert_eq!(slots[2].map(|x| x.0.clone()), None);
+
+    let slots: [ConcurrentOption<usize>; 0] = copt![None; 0];
+    assert!(slots.is_empty());
+}
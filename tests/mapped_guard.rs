@@ -0,0 +1,87 @@
+use orx_concurrent_option::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Config {
+    endpoint: String,
+    ports: Vec<u16>,
+}
+
+fn config() -> Config {
+    Config {
+        endpoint: "localhost".to_string(),
+        ports: vec![80, 443],
+    }
+}
+
+#[test]
+fn read_guard_map() {
+    let slots = [ConcurrentOption::some(config())];
+    let option = &slots[0];
+
+    let (_, guard) = ConcurrentOption::iter_guards(&slots).next().unwrap();
+    let endpoint = guard.map(|cfg| &cfg.endpoint);
+    assert_eq!(endpoint.as_str(), "localhost");
+    assert_eq!(option.try_take(16), Err(Contended(())));
+
+    let first = endpoint.map(|x| &x[..5]);
+    assert_eq!(&*first, "local");
+    assert_eq!(format!("{:?}", first), "MappedReadGuard(\"local\")");
+    assert_eq!(option.try_take(16), Err(Contended(())));
+
+    drop(first);
+    assert_eq!(option.state(LoadOrdering::Acquire), State::Some);
+    assert_eq!(option.get_cloned(), Some(config()));
+}
+
+#[test]
+fn read_guard_try_map() {
+    let slots = [ConcurrentOption::some(config())];
+
+    let (_, guard) = ConcurrentOption::iter_guards(&slots).next().unwrap();
+    let guard = guard.try_map(|cfg| cfg.ports.get(2)).unwrap_err();
+    let ports = guard.map(|cfg| cfg.ports.as_slice());
+    let ports = ports.try_map(|x| x.get(5)).unwrap_err();
+    let port = ports.try_map(|x| x.get(1)).unwrap();
+    assert_eq!(*port, 443);
+}
+
+#[test]
+fn write_guard_map() {
+    let slots = [ConcurrentOption::some(config())];
+    let option = &slots[0];
+    #[cfg(feature = "generation")]
+    let mut watcher = option.watcher();
+
+    let (_, guard) = ConcurrentOption::iter_guards(&slots).next().unwrap();
+    let mut ports = guard.try_upgrade().unwrap().map(|cfg| &mut cfg.ports);
+    ports.push(8080);
+    assert_eq!(option.state(LoadOrdering::Acquire), State::Reserved);
+
+    let mut last = ports.try_map(|x| x.get_mut(7)).unwrap_err();
+    last.push(8081);
+    let mut last = last.try_map(|x| x.last_mut()).unwrap();
+    *last += 1;
+    assert_eq!(format!("{:?}", last), "MappedWriteGuard(8082)");
+
+    drop(last);
+    #[cfg(feature = "generation")]
+    assert!(watcher.has_changed());
+    assert_eq!(
+        option.map(|cfg| cfg.ports.clone()),
+        Some(vec![80, 443, 8080, 8082])
+    );
+}
+
+#[test]
+fn write_guard_try_map() {
+    let slots = [ConcurrentOption::some(config())];
+
+    let (_, guard) = ConcurrentOption::iter_guards(&slots).next().unwrap();
+    let guard = guard.try_upgrade().unwrap();
+    let guard = guard.try_map(|cfg| cfg.ports.get_mut(2)).unwrap_err();
+    let mut endpoint = guard.try_map(|cfg| Some(&mut cfg.endpoint)).unwrap();
+    endpoint.clear();
+    drop(endpoint);
+
+    assert_eq!(slots[0].map(|cfg| cfg.endpoint.is_empty()), Some(true));
+}
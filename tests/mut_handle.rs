@@ -0,0 +1,211 @@
+use orx_concurrent_option::*;
+
+#[test]
+fn mut_handle_abort_restores_initial_state() {
+    let x = ConcurrentOption::some(3.to_string());
+    #[cfg(feature = "generation")]
+    let mut watcher = x.watcher();
+
+    let handle = unsafe { x.mut_handle(SOME, NONE) }.unwrap();
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Reserved);
+    handle.abort();
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+    #[cfg(feature = "generation")]
+    assert!(!watcher.has_changed());
+
+    let y = ConcurrentOption::<String>::none();
+    let handle = unsafe { y.mut_handle(NONE, SOME) }.unwrap();
+    handle.abort();
+    assert_eq!(y.state(LoadOrdering::Acquire), State::None);
+
+    assert_eq!(x.take(), Some(3.to_string()));
+}
+
+#[test]
+fn mut_handle_conditional_take() {
+    fn take_if_even(x: &ConcurrentOption<u32>) -> Option<u32> {
+        let mut handle = unsafe { x.mut_handle(SOME, SOME) }?;
+        match unsafe { *handle.get_mut() } % 2 == 0 {
+            true => {
+                handle.set_success_state(State::None);
+                Some(unsafe { (*handle.value.get()).assume_init_read() })
+            }
+            false => {
+                handle.abort();
+                None
+            }
+        }
+    }
+
+    let x = ConcurrentOption::some(3);
+    assert_eq!(take_if_even(&x), None);
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+
+    x.update_if_some(|x| *x += 1);
+    assert_eq!(take_if_even(&x), Some(4));
+    assert_eq!(x.state(LoadOrdering::Acquire), State::None);
+    assert_eq!(take_if_even(&x), None);
+}
+
+#[test]
+fn some_mut_handle() {
+    let x = ConcurrentOption::some(vec![1]);
+    #[cfg(feature = "generation")]
+    let mut watcher = x.watcher();
+
+    let mut handle = unsafe { x.some_mut_handle(SOME) }.unwrap();
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Reserved);
+    handle.push(2);
+    assert_eq!(format!("{:?}", handle), "SomeMutHandle([1, 2])");
+    drop(handle);
+    #[cfg(feature = "generation")]
+    assert!(watcher.has_changed());
+    assert_eq!(x.get_cloned(), Some(vec![1, 2]));
+
+    let handle = unsafe { x.some_mut_handle(NONE) }.unwrap();
+    handle.abort();
+    #[cfg(feature = "generation")]
+    assert!(!watcher.has_changed());
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+
+    let handle = unsafe { x.mut_handle(SOME, NONE) }.unwrap();
+    let handle = handle.into_some().unwrap().into_handle();
+    let taken = unsafe { (*handle.value.get()).assume_init_read() };
+    drop(handle);
+    assert_eq!(taken, vec![1, 2]);
+
+    assert!(unsafe { x.some_mut_handle(SOME) }.is_none());
+    let handle = unsafe { x.mut_handle(NONE, NONE) }.unwrap();
+    assert!(handle.into_some().is_err());
+}
+
+#[test]
+fn mut_handle_debug() {
+    let x = ConcurrentOption::some(3);
+    let handle = unsafe { x.mut_handle(SOME, NONE) }.unwrap();
+    assert_eq!(
+        format!("{:?}", handle),
+        "MutHandle { initial_state: Some, success_state: None }"
+    );
+    handle.abort();
+}
+
+#[test]
+fn mut_handle_typed_valid_transitions() {
+    let x = ConcurrentOption::<u32>::none();
+
+    let handle = unsafe { x.mut_handle_typed(State::None, State::Some) }.unwrap();
+    let handle = handle.unwrap();
+    unsafe { (*handle.value.get()).write(7) };
+    drop(handle);
+    assert_eq!(x.get_cloned(), Some(7));
+
+    let handle = unsafe { x.mut_handle_typed(State::None, State::Some) }.unwrap();
+    assert!(handle.is_none());
+
+    let handle = unsafe { x.mut_handle_typed(State::Some, State::Some) }.unwrap();
+    let mut handle = handle.unwrap().into_some().unwrap();
+    *handle += 1;
+    drop(handle);
+    assert_eq!(x.get_cloned(), Some(8));
+}
+
+#[test]
+fn mut_handle_typed_custom_state() {
+    #[derive(Clone, Copy)]
+    struct Claimed;
+
+    impl CustomState for Claimed {
+        fn id(self) -> u8 {
+            0
+        }
+
+        fn holds_value(self) -> bool {
+            false
+        }
+    }
+
+    let claimed = State::Custom(Claimed.state());
+    let x = ConcurrentOption::<u32>::none();
+
+    let handle = unsafe { x.mut_handle_typed(State::None, claimed) }.unwrap();
+    drop(handle);
+    assert_eq!(x.state(LoadOrdering::Acquire), claimed);
+
+    let handle = unsafe { x.mut_handle_typed(claimed, State::None) }.unwrap();
+    drop(handle);
+    assert_eq!(x.state(LoadOrdering::Acquire), State::None);
+}
+
+#[test]
+fn mut_handle_typed_invalid_transitions() {
+    let x = ConcurrentOption::some(3);
+    let cases = [
+        (
+            State::Reserved,
+            State::Some,
+            InvalidTransition::ReservedInitialState,
+        ),
+        (
+            State::Frozen,
+            State::Some,
+            InvalidTransition::FrozenInitialState,
+        ),
+        (
+            State::Some,
+            State::Reserved,
+            InvalidTransition::ReservedSuccessState,
+        ),
+        (
+            State::Some,
+            State::Frozen,
+            InvalidTransition::FrozenSuccessState,
+        ),
+        (
+            State::Custom(SOME),
+            State::Some,
+            InvalidTransition::InvalidCustomState(SOME),
+        ),
+        (
+            State::Some,
+            State::Custom(7),
+            InvalidTransition::InvalidCustomState(7),
+        ),
+    ];
+
+    for (initial, success, error) in cases {
+        let handle = unsafe { x.mut_handle_typed(initial, success) };
+        assert_eq!(handle.unwrap_err(), error);
+        assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+    }
+
+    assert_eq!(x.get_cloned(), Some(3));
+}
+
+#[test]
+fn invalid_transition_display() {
+    assert_eq!(
+        InvalidTransition::ReservedInitialState.to_string(),
+        "a mut handle cannot be created from the RESERVED state"
+    );
+    assert_eq!(
+        InvalidTransition::InvalidCustomState(7).to_string(),
+        "7 is not a custom state"
+    );
+}
+
+#[test]
+#[should_panic(expected = "a mut handle cannot release the optional as RESERVED")]
+fn set_success_state_reserved_panics() {
+    let x = ConcurrentOption::some(3);
+    let mut handle = unsafe { x.mut_handle(SOME, SOME) }.unwrap();
+    handle.set_success_state(State::Reserved);
+}
+
+#[test]
+#[should_panic(expected = "7 is not a custom state")]
+fn set_success_state_invalid_custom_panics() {
+    let x = ConcurrentOption::some(3);
+    let mut handle = unsafe { x.mut_handle(SOME, SOME) }.unwrap();
+    handle.set_success_state(State::Custom(7));
+}
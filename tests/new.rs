@@ -1,4 +1,3 @@
-use core::sync::atomic::Ordering;
 use orx_concurrent_option::*;
 
 #[test]
@@ -9,7 +8,15 @@ fn some() {
 
     assert!(x.is_some());
     assert!(!x.is_none());
-    assert_eq!(x.state(Ordering::Relaxed), State::Some);
+    assert_eq!(x.state(LoadOrdering::Relaxed), State::Some);
+}
+
+#[test]
+fn some_cloned() {
+    let value = 3.to_string();
+    let x = ConcurrentOption::some_cloned(&value);
+    assert_eq!(x, ConcurrentOption::some(3.to_string()));
+    assert_eq!(x.state(LoadOrdering::Relaxed), State::Some);
 }
 
 #[test]
@@ -19,12 +26,12 @@ fn none() {
     assert_eq!(x, ConcurrentOption::none());
     assert!(!x.is_some());
     assert!(x.is_none());
-    assert_eq!(x.state(Ordering::Relaxed), State::None);
+    assert_eq!(x.state(LoadOrdering::Relaxed), State::None);
 
     let x = ConcurrentOption::default();
     assert_ne!(x, ConcurrentOption::some(3.to_string()));
     assert_eq!(x, ConcurrentOption::none());
     assert!(!x.is_some());
     assert!(x.is_none());
-    assert_eq!(x.state(Ordering::Relaxed), State::None);
+    assert_eq!(x.state(LoadOrdering::Relaxed), State::None);
 }
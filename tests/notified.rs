@@ -0,0 +1,135 @@
+#![cfg(feature = "tokio")]
+
+use orx_concurrent_option::*;
+use std::{
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::Thread,
+};
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Polls the future only when it is woken; hence, never returns if a notification is lost.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+fn is_notified<F: Future>(future: std::pin::Pin<&mut F>) -> bool {
+    let mut cx = Context::from_waker(Waker::noop());
+    future.poll(&mut cx).is_ready()
+}
+
+#[test]
+fn notified_on_set_by_initializing_methods() {
+    let x = ConcurrentOption::<String>::none();
+
+    let mut notified = pin!(x.notified_on_set());
+    assert!(x.initialize_if_none(1.to_string()));
+    assert!(is_notified(notified.as_mut()));
+
+    _ = x.take();
+    let mut notified = pin!(x.notified_on_set());
+    assert_eq!(x.replace(2.to_string()), None);
+    assert!(is_notified(notified.as_mut()));
+
+    _ = x.take();
+    let mut notified = pin!(x.notified_on_set());
+    if let Entry::Vacant(entry) = x.entry() {
+        drop(entry.insert(3.to_string()).downgrade());
+    }
+    assert!(is_notified(notified.as_mut()));
+}
+
+#[test]
+fn notified_on_set_ignores_mutations_of_some() {
+    let x = ConcurrentOption::some(1);
+
+    let mut notified = pin!(x.notified_on_set());
+    assert_eq!(x.replace(2), Some(1));
+    x.update_if_some(|x| *x += 1);
+    assert!(!x.initialize_if_none(7));
+    assert!(!is_notified(notified.as_mut()));
+
+    _ = x.take();
+    assert!(!is_notified(notified.as_mut()));
+}
+
+#[test]
+fn notified_on_take_by_taking_methods() {
+    let x = ConcurrentOption::some(1);
+
+    let mut notified = pin!(x.notified_on_take());
+    assert_eq!(x.take_if(|x| *x == 1), Some(1));
+    assert!(is_notified(notified.as_mut()));
+
+    x.initialize_if_none(2);
+    let mut notified = pin!(x.notified_on_take());
+    assert_eq!(x.take_if(|x| *x == 1), None);
+    assert!(!is_notified(notified.as_mut()));
+    assert_eq!(x.take(), Some(2));
+    assert!(is_notified(notified.as_mut()));
+}
+
+#[test]
+fn notified_on_take_ignores_releases_of_none() {
+    let x = ConcurrentOption::<i32>::none();
+
+    let mut notified = pin!(x.notified_on_take());
+    drop(x.try_claim());
+    assert_eq!(x.take(), None);
+    assert!(!is_notified(notified.as_mut()));
+}
+
+#[test]
+fn async_consumer_is_woken_by_producer() {
+    let slot = ConcurrentOption::none();
+    let num_items = 100;
+
+    let consumed: Vec<_> = std::thread::scope(|s| {
+        s.spawn(|| {
+            for i in 0..num_items {
+                block_on(async {
+                    let notified = slot.notified_on_take();
+                    if slot.is_some() {
+                        notified.await;
+                    }
+                });
+                assert!(slot.initialize_if_none(i));
+            }
+        });
+
+        let consumer = s.spawn(|| {
+            block_on(async {
+                let mut consumed = vec![];
+                while consumed.len() < num_items {
+                    let notified = slot.notified_on_set();
+                    match slot.take() {
+                        Some(x) => consumed.push(x),
+                        None => notified.await,
+                    }
+                }
+                consumed
+            })
+        });
+
+        consumer.join().unwrap()
+    });
+
+    assert_eq!(consumed, (0..num_items).collect::<Vec<_>>());
+}
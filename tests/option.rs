@@ -1,4 +1,5 @@
-use core::sync::atomic::Ordering;
+#![allow(clippy::bool_assert_comparison, clippy::manual_inspect)]
+
 use orx_concurrent_option::*;
 
 // &self
@@ -21,6 +22,23 @@ fn is_none() {
     assert_eq!(x.is_none(), true);
 }
 
+#[test]
+fn is_some_eventually() {
+    let mut x = ConcurrentOption::some(3.to_string());
+    assert_eq!(x.is_some_eventually(0), Some(true));
+    assert_eq!(x.is_some_eventually(16), Some(true));
+
+    _ = x.exclusive_take();
+    assert_eq!(x.is_some_eventually(0), Some(false));
+    assert_eq!(x.is_some_eventually(16), Some(false));
+
+    let x = ConcurrentOption::some(3.to_string());
+    let handle = unsafe { x.mut_handle(SOME, SOME) };
+    assert_eq!(x.is_some_eventually(16), None);
+    drop(handle);
+    assert_eq!(x.is_some_eventually(16), Some(true));
+}
+
 #[test]
 fn as_ref() {
     let mut x = ConcurrentOption::some(3.to_string());
@@ -44,37 +62,55 @@ fn as_deref() {
 #[test]
 fn state() {
     let mut x = ConcurrentOption::some(3.to_string());
-    assert_eq!(x.state(Ordering::Relaxed), State::Some);
+    assert_eq!(x.state(LoadOrdering::Relaxed), State::Some);
 
     _ = x.exclusive_take();
-    assert_eq!(x.state(Ordering::Relaxed), State::None);
+    assert_eq!(x.state(LoadOrdering::Relaxed), State::None);
 }
 
 #[test]
 fn as_ref_with_order() {
     let mut x = ConcurrentOption::some(3.to_string());
     assert_eq!(
-        unsafe { x.as_ref_with_order(Ordering::Relaxed) },
+        unsafe { x.as_ref_with_order(LoadOrdering::Relaxed) },
         Some(&3.to_string())
     );
 
     _ = x.exclusive_take();
-    assert_eq!(unsafe { x.as_ref_with_order(Ordering::Relaxed) }, None);
+    assert_eq!(unsafe { x.as_ref_with_order(LoadOrdering::Relaxed) }, None);
 }
 
 #[test]
 fn as_deref_with_order() {
     unsafe {
         let mut x = ConcurrentOption::some(3.to_string());
-        assert_eq!(x.as_deref_with_order(Ordering::Relaxed), Some("3"));
+        assert_eq!(x.as_deref_with_order(LoadOrdering::Relaxed), Some("3"));
 
         _ = x.exclusive_take();
-        assert_eq!(x.as_deref_with_order(Ordering::Relaxed), None);
+        assert_eq!(x.as_deref_with_order(LoadOrdering::Relaxed), None);
     }
 }
 
 // &mut self
 
+#[test]
+fn exclusive_state() {
+    let mut x = ConcurrentOption::some(3.to_string());
+    assert_eq!(x.exclusive_state(), State::Some);
+    assert!(x.exclusive_is_some());
+    assert!(!x.exclusive_is_none());
+
+    _ = x.exclusive_take();
+    assert_eq!(x.exclusive_state(), State::None);
+    assert!(!x.exclusive_is_some());
+    assert!(x.exclusive_is_none());
+
+    x.exclusive_insert(4.to_string());
+    _ = x.freeze();
+    assert_eq!(x.exclusive_state(), State::Frozen);
+    assert!(x.exclusive_is_some());
+}
+
 #[test]
 fn take() {
     let x = ConcurrentOption::some(3.to_string());
@@ -87,6 +123,18 @@ fn take() {
     assert_eq!(y, None);
 }
 
+#[test]
+fn take_or_init() {
+    let x = ConcurrentOption::some(3.to_string());
+    let y = x.take_or_init(|| 7.to_string());
+    assert!(x.is_none());
+    assert_eq!(y, 3.to_string());
+
+    let y = x.take_or_init(|| 7.to_string());
+    assert!(x.is_none());
+    assert_eq!(y, 7.to_string());
+}
+
 #[test]
 fn exclusive_take() {
     let mut x = ConcurrentOption::some(3.to_string());
@@ -119,6 +167,26 @@ fn take_if() {
     assert_eq!(prev, Some(43));
 }
 
+#[test]
+fn take_if_map() {
+    let x = ConcurrentOption::some(42);
+
+    let taken = x.take_if_map(|v| {
+        *v += 1;
+        None::<bool>
+    });
+    assert_eq!(x, ConcurrentOption::some(43));
+    assert_eq!(taken, None);
+
+    let taken = x.take_if_map(|v| Some(*v % 2 == 1));
+    assert_eq!(x, ConcurrentOption::none());
+    assert_eq!(taken, Some((43, true)));
+
+    let taken = x.take_if_map(|v| Some(*v));
+    assert_eq!(x, ConcurrentOption::none());
+    assert_eq!(taken, None);
+}
+
 #[test]
 fn exclusive_take_if() {
     let mut x = ConcurrentOption::some(42);
@@ -147,13 +215,38 @@ fn exclusive_as_mut() {
         x
     });
     unsafe {
-        assert_eq!(x.as_deref_with_order(Ordering::Relaxed), Some("ABC"));
+        assert_eq!(x.as_deref_with_order(LoadOrdering::Relaxed), Some("ABC"));
     }
 
     _ = x.exclusive_take();
     assert!(x.exclusive_as_mut().is_none());
 }
 
+#[test]
+fn exclusive_as_pin_mut() {
+    use core::{marker::PhantomPinned, pin::pin};
+
+    struct NotUnpin {
+        value: String,
+        _pin: PhantomPinned,
+    }
+
+    let mut x = pin!(ConcurrentOption::some(NotUnpin {
+        value: "abc".to_string(),
+        _pin: PhantomPinned,
+    }));
+    if let Some(v) = unsafe { x.as_mut().exclusive_as_pin_mut() } {
+        unsafe { v.get_unchecked_mut() }
+            .value
+            .make_ascii_uppercase();
+    }
+    assert_eq!(x.map(|x| x.value.clone()), Some("ABC".to_string()));
+    assert!(unsafe { x.as_mut().exclusive_as_pin_mut() }.is_some());
+
+    let x = pin!(ConcurrentOption::<NotUnpin>::none());
+    assert!(unsafe { x.exclusive_as_pin_mut() }.is_none());
+}
+
 #[test]
 fn exclusive_as_deref_mut() {
     let mut x = ConcurrentOption::some("abc".to_string());
@@ -162,7 +255,7 @@ fn exclusive_as_deref_mut() {
         x
     });
     unsafe {
-        assert_eq!(x.as_deref_with_order(Ordering::Relaxed), Some("ABC"));
+        assert_eq!(x.as_deref_with_order(LoadOrdering::Relaxed), Some("ABC"));
     }
 
     _ = x.exclusive_take();
@@ -222,6 +315,25 @@ fn exclusive_get_or_insert_with() {
     assert_eq!(x, ConcurrentOption::some(7));
 }
 
+#[test]
+fn exclusive_map_or_insert_with() {
+    let mut x = ConcurrentOption::<u32>::none();
+
+    let y = x.exclusive_map_or_insert_with(
+        || 5,
+        |y| {
+            *y += 1;
+            *y * 10
+        },
+    );
+    assert_eq!(y, 60);
+
+    let y = x.exclusive_map_or_insert_with(|| 100, |y| *y * 10);
+    assert_eq!(y, 60);
+
+    assert_eq!(x, ConcurrentOption::some(6));
+}
+
 // self
 
 #[test]
@@ -323,6 +435,40 @@ fn copied() {
     assert_eq!(copied, Some(12));
 }
 
+#[test]
+fn cloned_ref() {
+    let x = 12.to_string();
+    let opt_x = ConcurrentOption::some(&x);
+    assert_eq!(opt_x.cloned_ref(), Some(12.to_string()));
+    assert!(opt_x.is_some());
+
+    let opt_x = ConcurrentOption::<&String>::none();
+    assert_eq!(opt_x.cloned_ref(), None);
+
+    let mut x = 12.to_string();
+    let opt_x = ConcurrentOption::some(&mut x);
+    assert_eq!(opt_x.cloned_ref(), Some(12.to_string()));
+    assert!(opt_x.is_some());
+    assert_eq!(opt_x.cloned(), Some(12.to_string()));
+}
+
+#[test]
+fn copied_ref() {
+    let x = 12;
+    let opt_x = ConcurrentOption::some(&x);
+    assert_eq!(opt_x.copied_ref(), Some(12));
+    assert_eq!(opt_x, ConcurrentOption::some(&12));
+
+    let opt_x = ConcurrentOption::<&i32>::none();
+    assert_eq!(opt_x.copied_ref(), None);
+
+    let mut x = 12;
+    let opt_x = ConcurrentOption::some(&mut x);
+    assert_eq!(opt_x.copied_ref(), Some(12));
+    assert!(opt_x.is_some());
+    assert_eq!(opt_x.copied(), Some(12));
+}
+
 #[test]
 fn filter() {
     fn is_even(n: &i32) -> bool {
@@ -404,6 +550,15 @@ fn map_or_else() {
     assert_eq!(x.map_or_else(|| 2 * k, |v| v.len()), 42);
 }
 
+#[test]
+fn map_or_default() {
+    let x = ConcurrentOption::some("foo");
+    assert_eq!(x.map_or_default(|v| v.len()), 3);
+
+    let x = ConcurrentOption::<&str>::none();
+    assert_eq!(x.map_or_default(|v| v.len()), 0);
+}
+
 #[test]
 fn xor() {
     let mut opt = ConcurrentOption::<i32>::none();
@@ -415,3 +570,66 @@ fn xor() {
     *val = 3;
     assert_eq!(opt.unwrap(), 3);
 }
+
+#[test]
+fn map_ref_or() {
+    let default = "default".to_string();
+
+    let x = ConcurrentOption::some(("a".to_string(), 1));
+    let y = unsafe { x.map_ref_or(&default, |v| &v.0) };
+    assert_eq!(y, "a");
+    let y = unsafe { x.map_ref_or_else(|| &default, |v| &v.0) };
+    assert_eq!(y, "a");
+
+    _ = x.take();
+    let y = unsafe { x.map_ref_or(&default, |v| &v.0) };
+    assert!(core::ptr::eq(y, &default));
+    let y = unsafe { x.map_ref_or_else(|| &default, |v| &v.0) };
+    assert!(core::ptr::eq(y, &default));
+}
+
+#[test]
+fn with_ref() {
+    let x = ConcurrentOption::some(3.to_string());
+    assert_eq!(x.with_ref(|x| x.clone()), Some(3.to_string()));
+    assert_eq!(x.with_ref(|x| x.len()), Some(1));
+
+    let x = ConcurrentOption::<String>::none();
+    assert_eq!(x.with_ref(|x| x.clone()), None);
+}
+
+#[test]
+fn as_deref_mut() {
+    let x = ConcurrentOption::some(vec![1, 2]);
+    if let Some(x) = unsafe { x.as_deref_mut() } {
+        x[0] = 10;
+    }
+    if let Some(x) = unsafe { x.as_deref_mut_with_order(LoadOrdering::Acquire) } {
+        x[1] = 20;
+    }
+    assert_eq!(unsafe { x.as_deref() }, Some(&[10, 20][..]));
+
+    let x = ConcurrentOption::<Vec<i32>>::none();
+    assert!(unsafe { x.as_deref_mut() }.is_none());
+    assert!(unsafe { x.as_deref_mut_with_order(LoadOrdering::Relaxed) }.is_none());
+}
+
+#[test]
+fn get_cloned_get_copied() {
+    let x = ConcurrentOption::some(3.to_string());
+    assert_eq!(x.get_cloned(), Some(3.to_string()));
+    assert_eq!(
+        x.get_cloned_with_order(LoadOrdering::Relaxed),
+        Some(3.to_string())
+    );
+    _ = x.take();
+    assert_eq!(x.get_cloned(), None);
+    assert_eq!(x.get_cloned_with_order(LoadOrdering::SeqCst), None);
+
+    let x = ConcurrentOption::some(3);
+    assert_eq!(x.get_copied(), Some(3));
+    assert_eq!(x.get_copied_with_order(LoadOrdering::Acquire), Some(3));
+    _ = x.take();
+    assert_eq!(x.get_copied(), None);
+    assert_eq!(x.get_copied_with_order(LoadOrdering::Relaxed), None);
+}
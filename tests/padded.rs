@@ -0,0 +1,82 @@
+#![cfg(feature = "std")]
+
+use orx_concurrent_option::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn cache_line_size_is_stable() {
+    let line = cache_line_size();
+    assert!(line.is_power_of_two());
+    assert!(line >= 16);
+    assert_eq!(cache_line_size(), line);
+    assert!(DEFAULT_CACHE_LINE_SIZE.is_power_of_two());
+}
+
+#[test]
+fn padded_slots_do_not_share_cache_lines() {
+    let line = cache_line_size();
+    let slots = ConcurrentOption::<[u8; 200]>::padded_vec(5);
+    assert_eq!(slots.len(), 5);
+    assert!(slots.stride() >= 200);
+    assert_eq!(slots.stride() % line, 0);
+
+    let addresses: Vec<_> = slots.iter().map(|x| x as *const _ as usize).collect();
+    for (i, address) in addresses.iter().enumerate() {
+        assert_eq!(address % line, 0);
+        assert_eq!(address - addresses[0], i * slots.stride());
+    }
+}
+
+#[test]
+fn padded_slots_empty() {
+    let slots = ConcurrentOption::<String>::padded_vec(0);
+    assert!(slots.is_empty());
+    assert_eq!(slots.iter().count(), 0);
+    assert!(slots.get(0).is_none());
+}
+
+#[test]
+#[should_panic]
+fn padded_slots_index_out_of_bounds() {
+    let slots = ConcurrentOption::<String>::padded_vec(2);
+    _ = &slots[2];
+}
+
+#[test]
+fn padded_slots_drop_values() {
+    struct Counted<'a>(&'a AtomicUsize);
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let num_dropped = AtomicUsize::new(0);
+    let slots = ConcurrentOption::padded_vec(4);
+    slots[1].initialize_if_none(Counted(&num_dropped));
+    slots[3].initialize_if_none(Counted(&num_dropped));
+    drop(slots);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn padded_slots_concurrent_writers() {
+    let num_threads = 8;
+    let slots = ConcurrentOption::padded_vec(num_threads);
+
+    std::thread::scope(|s| {
+        for t in 0..num_threads {
+            let slot = &slots[t];
+            s.spawn(move || {
+                for i in 0..1000 {
+                    _ = slot.replace(i + t);
+                }
+            });
+        }
+    });
+
+    for (t, slot) in slots.iter().enumerate() {
+        assert_eq!(slot.get_cloned(), Some(999 + t));
+    }
+    assert_eq!(format!("{:?}", slots).matches("Some").count(), num_threads);
+}
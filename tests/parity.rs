@@ -0,0 +1,181 @@
+//! Parity table of the `Option` methods and their `ConcurrentOption` counterparts.
+//!
+//! Each entry of the `parity!` table calls an `Option` method and its counterpart on the same initial values,
+//! and asserts that the results are equal. The counterpart is one of the following kinds:
+//! * `concurrent`: thread safe (or partially thread safe unsafe) method requiring `&self`,
+//! * `exclusive`: method requiring `&mut self`, usually prefixed with `exclusive_`,
+//! * `consuming`: method requiring `self`.
+//!
+//! Every stable `Option` method must either be in the table or in `NOT_APPLICABLE` with a reason;
+//! this is checked by the `every_option_method_is_covered` test.
+
+#![allow(unused_mut, unused_unsafe, clippy::unnecessary_lazy_evaluations)]
+
+use orx_concurrent_option::*;
+use std::pin::Pin;
+
+const OPTION_METHODS: &[&str] = &[
+    "is_some",
+    "is_some_and",
+    "is_none",
+    "is_none_or",
+    "as_ref",
+    "as_mut",
+    "as_pin_ref",
+    "as_pin_mut",
+    "as_slice",
+    "as_mut_slice",
+    "expect",
+    "unwrap",
+    "unwrap_or",
+    "unwrap_or_else",
+    "unwrap_or_default",
+    "unwrap_unchecked",
+    "map",
+    "inspect",
+    "map_or",
+    "map_or_else",
+    "ok_or",
+    "ok_or_else",
+    "as_deref",
+    "as_deref_mut",
+    "iter",
+    "iter_mut",
+    "and",
+    "and_then",
+    "filter",
+    "or",
+    "or_else",
+    "xor",
+    "insert",
+    "get_or_insert",
+    "get_or_insert_default",
+    "get_or_insert_with",
+    "take",
+    "take_if",
+    "replace",
+    "zip",
+    "unzip",
+    "copied",
+    "cloned",
+    "transpose",
+    "flatten",
+];
+
+const NOT_APPLICABLE: &[(&str, &str)] = &[(
+    "as_pin_ref",
+    "a pinned shared reference would escape the reservation; use `exclusive_as_pin_mut` or `with_ref`",
+)];
+
+#[allow(non_camel_case_types)]
+enum Kind {
+    concurrent,
+    exclusive,
+    consuming,
+}
+
+macro_rules! parity {
+    ($($method:ident: $kind:ident, $initial:expr, |$o:ident| { $option:expr } { $concurrent:expr })*) => {
+        const COVERED: &[&str] = &[$(stringify!($method)),*];
+
+        mod methods {
+            use super::*;
+
+            $(
+                #[test]
+                fn $method() {
+                    let _kind = Kind::$kind;
+                    for initial in $initial {
+                        let mut $o = initial.clone();
+                        let expected = $option;
+
+                        let mut $o = concurrent(initial.clone());
+                        let actual = $concurrent;
+
+                        assert_eq!(actual, expected, "initial: {:?}", initial);
+                    }
+                }
+            )*
+        }
+    };
+}
+
+fn concurrent<T>(option: Option<T>) -> ConcurrentOption<T> {
+    ConcurrentOption::from(option)
+}
+
+fn ints() -> [Option<i32>; 2] {
+    [Some(3), None]
+}
+
+fn somes() -> [Option<i32>; 1] {
+    [Some(3)]
+}
+
+fn strings() -> [Option<String>; 2] {
+    [Some(3.to_string()), None]
+}
+
+parity! {
+    is_some: concurrent, ints(), |o| { o.is_some() } { o.is_some() }
+    is_some_and: concurrent, ints(), |o| { o.is_some_and(|x| x > 1) } { o.is_some_and(|x| *x > 1) }
+    is_none: concurrent, ints(), |o| { o.is_none() } { o.is_none() }
+    is_none_or: concurrent, ints(), |o| { o.is_none_or(|x| x > 5) } { o.is_none_or(|x| *x > 5) }
+    as_ref: concurrent, ints(), |o| { o.as_ref().copied() } { unsafe { o.as_ref() }.copied() }
+    as_mut: exclusive, ints(), |o| { o.as_mut().map(|x| { *x += 1; *x }) } { o.exclusive_as_mut().map(|x| { *x += 1; *x }) }
+    as_pin_mut: exclusive, ints(), |o| { Pin::new(&mut o).as_pin_mut().map(|x| *x) } { unsafe { Pin::new(&mut o).exclusive_as_pin_mut() }.map(|x| *x) }
+    as_slice: concurrent, ints(), |o| { o.as_slice().to_vec() } { unsafe { o.as_slice() }.to_vec() }
+    as_mut_slice: exclusive, ints(), |o| { o.as_mut_slice().len() } { o.exclusive_as_mut_slice().len() }
+    expect: consuming, somes(), |o| { o.expect("is some") } { o.expect("is some") }
+    unwrap: consuming, somes(), |o| { o.unwrap() } { o.unwrap() }
+    unwrap_or: consuming, ints(), |o| { o.unwrap_or(7) } { o.unwrap_or(7) }
+    unwrap_or_else: consuming, ints(), |o| { o.unwrap_or_else(|| 7) } { o.unwrap_or_else(|| 7) }
+    unwrap_or_default: consuming, ints(), |o| { o.unwrap_or_default() } { o.unwrap_or_default() }
+    unwrap_unchecked: consuming, somes(), |o| { unsafe { o.unwrap_unchecked() } } { unsafe { o.unwrap_unchecked() } }
+    map: concurrent, ints(), |o| { o.map(|x| x * 2) } { o.map(|x| x * 2) }
+    inspect: concurrent, ints(), |o| { o.inspect(|x| assert_eq!(*x, 3)).is_some() } { o.inspect(|x| assert_eq!(*x, 3)).is_some() }
+    map_or: concurrent, ints(), |o| { o.map_or(0, |x| x * 2) } { o.map_or(0, |x| x * 2) }
+    map_or_else: concurrent, ints(), |o| { o.map_or_else(|| 0, |x| x * 2) } { o.map_or_else(|| 0, |x| x * 2) }
+    ok_or: consuming, ints(), |o| { o.ok_or("none") } { o.ok_or("none") }
+    ok_or_else: consuming, ints(), |o| { o.ok_or_else(|| "none") } { o.ok_or_else(|| "none") }
+    as_deref: concurrent, strings(), |o| { o.as_deref().map(str::len) } { unsafe { o.as_deref() }.map(str::len) }
+    as_deref_mut: exclusive, strings(), |o| { o.as_deref_mut().map(|x| x.len()) } { o.exclusive_as_deref_mut().map(|x| x.len()) }
+    iter: concurrent, ints(), |o| { o.iter().copied().collect::<Vec<_>>() } { unsafe { o.iter() }.copied().collect::<Vec<_>>() }
+    iter_mut: exclusive, ints(), |o| { o.iter_mut().map(|x| { *x += 1; *x }).collect::<Vec<_>>() } { o.exclusive_iter_mut().map(|x| { *x += 1; *x }).collect::<Vec<_>>() }
+    and: concurrent, ints(), |o| { o.and(Some('a')) } { o.and(Some('a')) }
+    and_then: concurrent, ints(), |o| { o.and_then(|x| x.checked_sub(1)) } { o.and_then(|x| x.checked_sub(1)) }
+    filter: concurrent, ints(), |o| { o.filter(|x| *x > 1) } { unsafe { o.filter(|x| *x > 1) }.copied() }
+    or: consuming, ints(), |o| { o.or(Some(7)) } { o.or(Some(7)) }
+    or_else: consuming, ints(), |o| { o.or_else(|| Some(7)) } { o.or_else(|| Some(7)) }
+    xor: consuming, ints(), |o| { o.xor(Some(7)) } { o.xor(Some(7)) }
+    insert: exclusive, ints(), |o| { *o.insert(7) } { *o.exclusive_insert(7) }
+    get_or_insert: exclusive, ints(), |o| { *o.get_or_insert(7) } { *o.exclusive_get_or_insert(7) }
+    get_or_insert_default: exclusive, ints(), |o| { *o.get_or_insert_default() } { *o.exclusive_get_or_insert_default() }
+    get_or_insert_with: exclusive, ints(), |o| { *o.get_or_insert_with(|| 7) } { *o.exclusive_get_or_insert_with(|| 7) }
+    take: concurrent, ints(), |o| { (o.take(), o) } { (o.take(), o.exclusive_take()) }
+    take_if: concurrent, ints(), |o| { (o.take_if(|x| *x > 1), o) } { (o.take_if(|x| *x > 1), o.exclusive_take()) }
+    replace: concurrent, ints(), |o| { (o.replace(7), o) } { (o.replace(7), o.exclusive_take()) }
+    zip: consuming, ints(), |o| { o.zip(Some('a')) } { o.zip(Some('a')) }
+    unzip: consuming, [Some((3, 'a')), None], |o| { o.unzip() } { o.unzip() }
+    copied: consuming, [Some(&3), None], |o| { o.copied() } { o.copied() }
+    cloned: consuming, [Some(&3), None], |o| { o.cloned() } { o.cloned() }
+    transpose: consuming, [Some(Ok(3)), Some(Err('e')), None], |o| { o.transpose() } { o.transpose() }
+    flatten: consuming, [Some(Some(3)), Some(None), None], |o| { o.flatten() } { o.flatten() }
+}
+
+#[test]
+fn every_option_method_is_covered() {
+    for method in OPTION_METHODS {
+        let covered = COVERED.contains(method);
+        let not_applicable = NOT_APPLICABLE.iter().any(|(m, _)| m == method);
+        assert!(
+            covered ^ not_applicable,
+            "`Option::{}` must be either covered or listed as not applicable",
+            method
+        );
+    }
+
+    for method in COVERED {
+        assert!(OPTION_METHODS.contains(method));
+    }
+}
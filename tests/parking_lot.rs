@@ -0,0 +1,102 @@
+#![cfg(feature = "parking_lot")]
+
+use orx_concurrent_option::*;
+use std::time::Duration;
+
+#[test]
+fn writers_wait_for_long_reservations() {
+    let num_threads = 8;
+    let num_updates = 100;
+    let x = ConcurrentOption::some(0);
+
+    std::thread::scope(|s| {
+        for _ in 0..num_threads {
+            s.spawn(|| {
+                for _ in 0..num_updates {
+                    x.update_if_some(|x| {
+                        std::thread::sleep(Duration::from_micros(50));
+                        *x += 1;
+                    });
+                }
+            });
+        }
+    });
+
+    assert_eq!(x.get_cloned(), Some(num_threads * num_updates));
+}
+
+#[test]
+fn readers_wait_for_long_reservations() {
+    let x = ConcurrentOption::some(1);
+
+    std::thread::scope(|s| {
+        let handle = unsafe { x.mut_handle(SOME, SOME) }.unwrap();
+        let readers: Vec<_> = (0..4).map(|_| s.spawn(|| x.get_cloned())).collect();
+        std::thread::sleep(Duration::from_millis(20));
+        unsafe { *handle.get_mut() = 2 };
+        drop(handle);
+
+        for reader in readers {
+            assert_eq!(reader.join().unwrap(), Some(2));
+        }
+    });
+}
+
+#[test]
+fn waiters_are_woken_by_initialization() {
+    let x = ConcurrentOption::<String>::none();
+
+    std::thread::scope(|s| {
+        let waiters: Vec<_> = (0..4)
+            .map(|_| s.spawn(|| x.wait_for_some().clone()))
+            .collect();
+
+        std::thread::sleep(Duration::from_millis(20));
+        x.initialize_if_none(7.to_string());
+
+        for waiter in waiters {
+            assert_eq!(waiter.join().unwrap(), 7.to_string());
+        }
+    });
+}
+
+#[test]
+fn waiters_of_distinct_options_are_independent() {
+    let options: Vec<_> = (0..256).map(|_| ConcurrentOption::none()).collect();
+
+    std::thread::scope(|s| {
+        let waiters: Vec<_> = options
+            .iter()
+            .step_by(16)
+            .map(|x| s.spawn(|| x.wait_and_take()))
+            .collect();
+
+        std::thread::sleep(Duration::from_millis(20));
+        for (i, x) in options.iter().enumerate() {
+            assert!(x.initialize_if_none(i));
+        }
+
+        let taken: Vec<_> = waiters.into_iter().map(|x| x.join().unwrap()).collect();
+        assert_eq!(taken, (0..256).step_by(16).collect::<Vec<_>>());
+    });
+}
+
+#[test]
+fn rendezvous_handoff() {
+    let num_items = 1000;
+    let slot = ConcurrentOption::none();
+
+    let consumed: Vec<_> = std::thread::scope(|s| {
+        s.spawn(|| {
+            for i in 0..num_items {
+                slot.wait_for_none();
+                assert!(slot.initialize_if_none(i));
+            }
+        });
+
+        let consumer = s.spawn(|| (0..num_items).map(|_| slot.wait_and_take()).collect());
+        consumer.join().unwrap()
+    });
+
+    assert_eq!(consumed, (0..num_items).collect::<Vec<_>>());
+}
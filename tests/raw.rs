@@ -1,4 +1,3 @@
-use core::sync::atomic::Ordering;
 use orx_concurrent_option::*;
 
 #[test]
@@ -16,11 +15,11 @@ fn get_raw() {
 #[test]
 fn get_raw_with_order() {
     let x = ConcurrentOption::<String>::none();
-    let p = x.get_raw_with_order(Ordering::Relaxed);
+    let p = x.get_raw_with_order(LoadOrdering::Relaxed);
     assert!(p.is_none());
 
     let x = ConcurrentOption::some(3.to_string());
-    let p = x.get_raw_with_order(Ordering::Relaxed);
+    let p = x.get_raw_with_order(LoadOrdering::Relaxed);
     assert!(p.is_some());
     assert_eq!(unsafe { p.unwrap().as_ref() }, Some(&3.to_string()));
 }
@@ -48,19 +47,49 @@ fn get_raw_mut() {
 #[test]
 fn get_raw_mut_with_order() {
     let x = ConcurrentOption::<String>::none();
-    let p = x.get_raw_mut_with_order(Ordering::Relaxed);
+    let p = x.get_raw_mut_with_order(LoadOrdering::Relaxed);
     assert!(p.is_none());
 
     let x = ConcurrentOption::some(3.to_string());
-    let p = x.get_raw_mut_with_order(Ordering::Relaxed);
+    let p = x.get_raw_mut_with_order(LoadOrdering::Relaxed);
     assert!(p.is_some());
     assert_eq!(unsafe { p.unwrap().as_ref() }, Some(&3.to_string()));
 
-    let p = x.get_raw_mut_with_order(Ordering::Relaxed).unwrap();
+    let p = x.get_raw_mut_with_order(LoadOrdering::Relaxed).unwrap();
     assert_eq!(unsafe { p.as_ref() }, Some(&3.to_string()));
 
-    let p = x.get_raw_mut_with_order(Ordering::Relaxed);
+    let p = x.get_raw_mut_with_order(LoadOrdering::Relaxed);
     let p = p.unwrap();
     let _ = unsafe { p.replace(7.to_string()) }; // only write leads to memory leak
     assert_eq!(unsafe { x.as_ref() }, Some(&7.to_string()));
 }
+
+#[cfg(feature = "generation")]
+#[test]
+fn get_raw_versioned() {
+    let x = ConcurrentOption::<String>::none();
+    assert!(x.get_raw_versioned().is_none());
+
+    let x = ConcurrentOption::some(3.to_string());
+    let (p, version) = x.get_raw_versioned().unwrap();
+    assert_eq!(unsafe { p.as_ref() }, Some(&3.to_string()));
+    assert!(x.is_still(version));
+
+    let (_, same_version) = x.get_raw_versioned().unwrap();
+    assert_eq!(version, same_version);
+
+    // ABA: taken and re-initialized with the same value
+    let taken = x.take();
+    assert!(x.initialize_if_none(taken.unwrap()));
+    assert!(!x.is_still(version));
+
+    let (_, new_version) = x.get_raw_versioned().unwrap();
+    assert_ne!(version, new_version);
+    assert!(x.is_still(new_version));
+
+    // a mutation in progress has not bumped the version yet
+    let handle = unsafe { x.mut_handle(SOME, SOME) };
+    assert!(!x.is_still(new_version));
+    drop(handle);
+    assert!(!x.is_still(new_version));
+}
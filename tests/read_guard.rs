@@ -0,0 +1,97 @@
+use orx_concurrent_option::*;
+
+#[test]
+fn get_guard_of_none() {
+    let x = ConcurrentOption::<String>::none();
+    assert!(x.get_guard().is_none());
+    assert_eq!(x.state(LoadOrdering::Acquire), State::None);
+}
+
+#[test]
+fn get_guard_blocks_writers_until_dropped() {
+    let x = ConcurrentOption::some(3.to_string());
+
+    let guard = x.get_guard().unwrap();
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+    assert_eq!(format!("{:?}", guard), "ReadGuard(\"3\")");
+    assert!(!x.initialize_if_none(7.to_string()));
+    assert_eq!(x.try_take(16), Err(Contended(())));
+
+    drop(guard);
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+    assert_eq!(x.take(), Some(3.to_string()));
+}
+
+#[test]
+fn get_guards_are_shared() {
+    let x = ConcurrentOption::some(3.to_string());
+
+    let first = x.get_guard().unwrap();
+    let second = x.get_guard().unwrap();
+    assert_eq!(first.as_str(), "3");
+    assert_eq!(second.as_str(), "3");
+    assert_eq!(x.get_cloned(), Some(3.to_string()));
+
+    let first = first.try_upgrade().unwrap_err();
+    drop(second);
+
+    let mut first = first.try_upgrade().unwrap();
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Reserved);
+    first.push('!');
+    drop(first);
+
+    assert_eq!(x.take(), Some("3!".to_string()));
+}
+
+#[test]
+fn try_upgrade_fails_while_reserved() {
+    let x = ConcurrentOption::some(3);
+
+    let guard = x.get_guard().unwrap();
+
+    std::thread::scope(|s| {
+        // reserves the option, and then, waits for the guard to be dropped
+        let taker = s.spawn(|| x.take());
+        while x.state(LoadOrdering::Acquire) != State::Reserved {
+            std::thread::yield_now();
+        }
+
+        let guard = guard.try_upgrade().unwrap_err();
+        assert_eq!(*guard, 3);
+        drop(guard);
+
+        assert_eq!(taker.join().unwrap(), Some(3));
+    });
+
+    assert!(x.is_none());
+}
+
+#[test]
+fn get_guard_blocks_concurrent_take() {
+    let x = ConcurrentOption::some(vec![1, 2, 3]);
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..50 {
+                    if let Some(guard) = x.get_guard() {
+                        assert_eq!(guard.iter().sum::<i32>(), 6);
+                    }
+                    std::thread::yield_now();
+                }
+            });
+        }
+
+        s.spawn(|| {
+            for _ in 0..50 {
+                if let Some(value) = x.take() {
+                    assert_eq!(value, [1, 2, 3]);
+                    _ = x.initialize_if_none(value);
+                }
+                std::thread::yield_now();
+            }
+        });
+    });
+
+    assert_eq!(x.take(), Some(vec![1, 2, 3]));
+}
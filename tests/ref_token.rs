@@ -0,0 +1,44 @@
+use orx_concurrent_option::*;
+
+#[test]
+fn ref_token() {
+    let x = ConcurrentOption::some(3.to_string());
+
+    {
+        let token = unsafe { x.as_ref_token() }.unwrap();
+        assert_eq!(token.as_str(), "3");
+        assert_eq!(format!("{:?}", token), "RefToken(\"3\")");
+
+        let other = unsafe { x.as_ref_token() }.unwrap();
+        assert_eq!(*other, *token);
+        assert_eq!(x.map(|x| x.len()), Some(1)); // reads are allowed
+    }
+
+    x.update_if_some(|x| x.push('!'));
+    assert_eq!(
+        unsafe { x.as_ref_token() }.as_deref(),
+        Some(&"3!".to_string())
+    );
+
+    _ = x.take();
+    assert!(unsafe { x.as_ref_token() }.is_none());
+}
+
+#[cfg(feature = "ref-tracking")]
+#[test]
+#[should_panic(expected = "ConcurrentOption is mutated while a RefToken to its value is alive")]
+fn ref_token_mutation_while_alive() {
+    let x = ConcurrentOption::some(3.to_string());
+    let token = unsafe { x.as_ref_token() }.unwrap();
+    x.update_if_some(|x| x.push('!'));
+    assert_eq!(token.as_str(), "3!");
+}
+
+#[cfg(feature = "ref-tracking")]
+#[test]
+#[should_panic(expected = "ConcurrentOption is mutated while a RefToken to its value is alive")]
+fn ref_token_take_while_alive() {
+    let x = ConcurrentOption::some(3.to_string());
+    let _token = unsafe { x.as_ref_token() }.unwrap();
+    _ = x.take_if(|_| true);
+}
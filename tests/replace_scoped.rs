@@ -0,0 +1,54 @@
+use orx_concurrent_option::*;
+
+#[test]
+fn replace_scoped_restores_some() {
+    let x = ConcurrentOption::some(1.to_string());
+
+    let len = x.replace_scoped(22.to_string(), || {
+        assert_eq!(x.get_cloned(), Some(22.to_string()));
+        x.map(|x| x.len())
+    });
+    assert_eq!(len, Some(2));
+
+    assert_eq!(x.take(), Some(1.to_string()));
+}
+
+#[test]
+fn replace_scoped_restores_none() {
+    let x = ConcurrentOption::<String>::none();
+
+    x.replace_scoped(3.to_string(), || {
+        assert!(x.update_if_some(|x| x.push('!')));
+        assert_eq!(x.get_cloned(), Some("3!".to_string()));
+    });
+
+    assert!(x.is_none());
+}
+
+#[test]
+fn replace_scoped_restores_on_panic() {
+    let x = ConcurrentOption::some(1);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        x.replace_scoped(2, || {
+            assert_eq!(x.get_cloned(), Some(2));
+            panic!("failed while overridden");
+        })
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+    assert_eq!(x.take(), Some(1));
+}
+
+#[test]
+fn replace_scoped_nested() {
+    let x = ConcurrentOption::some(1);
+
+    x.replace_scoped(2, || {
+        x.replace_scoped(3, || assert_eq!(x.get_cloned(), Some(3)));
+        assert_eq!(x.get_cloned(), Some(2));
+    });
+
+    assert_eq!(x.take(), Some(1));
+}
@@ -0,0 +1,86 @@
+use orx_concurrent_option::*;
+
+#[test]
+fn reserve_some_and_mutate() {
+    let x = ConcurrentOption::some(vec![1]);
+    #[cfg(feature = "generation")]
+    let mut watcher = x.watcher();
+
+    let mut guard = x.reserve(SOME, SOME).unwrap();
+    assert_eq!(guard.initial_state(), State::Some);
+    assert_eq!(guard.success_state(), State::Some);
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Reserved);
+    assert!(x.try_reserve(SOME, SOME).is_none());
+
+    unsafe { guard.value().assume_init_mut() }.push(2);
+    drop(guard);
+
+    #[cfg(feature = "generation")]
+    assert!(watcher.has_changed());
+    assert_eq!(x.get_cloned(), Some(vec![1, 2]));
+}
+
+#[test]
+fn reserve_from_wrong_state() {
+    let x = ConcurrentOption::<i32>::none();
+    assert!(x.reserve(SOME, SOME).is_none());
+    assert!(x.try_reserve(SOME, SOME).is_none());
+    assert!(x.reserve(NONE, NONE).is_some());
+    assert!(x.is_none());
+}
+
+#[test]
+fn take_through_guard() {
+    let x = ConcurrentOption::some(3.to_string());
+
+    let mut guard = x.reserve(SOME, SOME).unwrap();
+    let value = unsafe { guard.value().assume_init_read() };
+    unsafe { guard.set_success_state(NONE) };
+    assert_eq!(guard.success_state(), State::None);
+    drop(guard);
+
+    assert_eq!(value, 3.to_string());
+    assert!(x.is_none());
+}
+
+#[test]
+fn guard_waits_for_shared_readers() {
+    let x = ConcurrentOption::some(1);
+    let reader = x.initialized().unwrap();
+
+    std::thread::scope(|s| {
+        let writer = s.spawn(|| {
+            let mut guard = x.reserve(SOME, SOME).unwrap();
+            *unsafe { guard.value().assume_init_mut() } += 1;
+        });
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(*reader, 1);
+        drop(reader);
+        writer.join().unwrap();
+    });
+
+    assert_eq!(x.get_cloned(), Some(2));
+}
+
+#[test]
+#[should_panic]
+fn reserve_mismatching_states_panics() {
+    let x = ConcurrentOption::<i32>::none();
+    _ = x.reserve(NONE, SOME);
+}
+
+#[test]
+#[should_panic]
+fn reserve_frozen_panics() {
+    let x = ConcurrentOption::some(1);
+    _ = x.freeze();
+    _ = x.try_reserve(FROZEN, SOME);
+}
+
+#[test]
+#[should_panic]
+fn release_into_reserved_panics() {
+    let x = ConcurrentOption::some(1);
+    let mut guard = x.reserve(SOME, SOME).unwrap();
+    unsafe { guard.set_success_state(RESERVED) };
+}
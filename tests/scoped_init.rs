@@ -0,0 +1,62 @@
+#![cfg(feature = "std")]
+
+use orx_concurrent_option::*;
+
+#[test]
+fn scoped_init_initializes() {
+    let x = ConcurrentOption::<String>::none();
+
+    std::thread::scope(|s| {
+        let init = x.scoped_init(s, || 42.to_string());
+        assert!(init.join());
+    });
+
+    assert_eq!(x.get_cloned(), Some(42.to_string()));
+}
+
+#[test]
+fn scoped_init_already_some() {
+    let x = ConcurrentOption::some(1.to_string());
+
+    std::thread::scope(|s| {
+        let init = x.scoped_init(s, || 42.to_string());
+        let value = unsafe { init.join_get() };
+        assert_eq!(value, Some(&1.to_string()));
+    });
+
+    let x = ConcurrentOption::some(1.to_string());
+    std::thread::scope(|s| {
+        assert!(!x.scoped_init(s, || 42.to_string()).join());
+    });
+}
+
+#[test]
+fn scoped_init_concurrent_readers() {
+    let x = ConcurrentOption::<Vec<usize>>::none();
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| loop {
+                match x.map(|x| x.len()) {
+                    Some(len) => break assert_eq!(len, 100),
+                    None => std::thread::yield_now(),
+                }
+            });
+        }
+
+        let init = x.scoped_init(s, || (0..100).collect());
+        let value = unsafe { init.join_get() }.unwrap();
+        assert_eq!(value.iter().sum::<usize>(), 4950);
+    });
+}
+
+#[test]
+#[should_panic(expected = "failed to compute")]
+fn scoped_init_propagates_panic() {
+    let x = ConcurrentOption::<String>::none();
+
+    std::thread::scope(|s| {
+        let init = x.scoped_init(s, || panic!("failed to compute"));
+        _ = unsafe { init.join_get() };
+    });
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "alloc")]
+
+use orx_concurrent_option::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn inline_and_boxed_payloads() {
+    let x: SmallConcurrentOption<String, 32> = ConcurrentOption::none();
+    const { assert!(SmallBox::<String, 32>::IS_INLINE) };
+    assert!(x.initialize_if_none(SmallBox::new(3.to_string())));
+    assert!(x.update_if_some(|x| x.push('!')));
+    assert_eq!(x.map(|x| x.len()), Some(2));
+
+    let old = x.replace(SmallBox::new(7.to_string()));
+    assert_eq!(old.map(SmallBox::into_inner), Some("3!".to_string()));
+    assert_eq!(format!("{:?}", x.take()), "Some(SmallBox(\"7\"))");
+
+    let y: SmallConcurrentOption<[String; 4], 32> = ConcurrentOption::none();
+    const { assert!(!SmallBox::<[String; 4], 32>::IS_INLINE) };
+    assert!(y.initialize_if_none([0, 1, 2, 3].map(|i| i.to_string()).into()));
+    assert!(y.update_if_some(|y| y[3].push('!')));
+    assert_eq!(
+        y.get_cloned().map(SmallBox::into_inner),
+        Some(["0", "1", "2", "3!"].map(String::from))
+    );
+    assert_eq!(
+        y.take(),
+        Some(SmallBox::new(["0", "1", "2", "3!"].map(String::from)))
+    );
+}
+
+#[test]
+fn payloads_are_dropped_once() {
+    static NUM_DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Clone)]
+    struct Counted<const N: usize>([u8; N]);
+    impl<const N: usize> Drop for Counted<N> {
+        fn drop(&mut self) {
+            NUM_DROPPED.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    {
+        let x: SmallConcurrentOption<Counted<4>, 8> =
+            ConcurrentOption::some(Counted([0; 4]).into());
+        let y: SmallConcurrentOption<Counted<64>, 8> =
+            ConcurrentOption::some(Counted([0; 64]).into());
+        let z = y.clone();
+        _ = x.replace(Counted([1; 4]).into());
+        assert_eq!(NUM_DROPPED.load(Ordering::Relaxed), 1);
+        _ = z.take().map(SmallBox::into_inner);
+        assert_eq!(NUM_DROPPED.load(Ordering::Relaxed), 2);
+    }
+    assert_eq!(NUM_DROPPED.load(Ordering::Relaxed), 4);
+}
+
+#[test]
+fn concurrent_updates_on_boxed_payload() {
+    let x: SmallConcurrentOption<[usize; 16], 16> = ConcurrentOption::some(SmallBox::new([0; 16]));
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..100 {
+                    x.update_if_some(|x| x.iter_mut().for_each(|x| *x += 1));
+                }
+            });
+        }
+    });
+
+    assert_eq!(x.take().map(SmallBox::into_inner), Some([400; 16]));
+}
@@ -0,0 +1,101 @@
+use orx_concurrent_option::spsc::*;
+
+#[test]
+fn push_pop_wraps_around() {
+    let mut ring = Ring::<usize, 3>::new();
+    assert_eq!(ring.capacity(), 3);
+
+    {
+        let (mut producer, mut consumer) = ring.split();
+        assert!(consumer.is_empty());
+        assert_eq!(consumer.pop(), None);
+
+        for round in 0..5 {
+            for i in 0..3 {
+                assert_eq!(producer.push(round * 10 + i), Ok(()));
+            }
+            assert!(producer.is_full());
+            assert_eq!(producer.push(42), Err(42));
+
+            assert_eq!(consumer.pop(), Some(round * 10));
+            assert_eq!(producer.push(round * 10 + 3), Ok(()));
+
+            for i in 1..4 {
+                assert_eq!(consumer.pop(), Some(round * 10 + i));
+            }
+            assert!(consumer.is_empty());
+        }
+
+        assert_eq!(producer.push(7), Ok(()));
+    }
+
+    // split again continues from the positions of the previous halves
+    let (mut producer, mut consumer) = ring.split();
+    assert_eq!(producer.push(8), Ok(()));
+    assert_eq!(consumer.pop(), Some(7));
+
+    let mut destination = None;
+    assert!(consumer.pop_into(&mut destination));
+    assert_eq!(destination, Some(8));
+    assert!(!consumer.pop_into(&mut destination));
+    assert_eq!(destination, Some(8));
+}
+
+#[test]
+fn remaining_values_are_dropped_with_the_ring() {
+    let value = std::sync::Arc::new(0);
+
+    let mut ring = Ring::<_, 4>::new();
+    {
+        let (mut producer, _) = ring.split();
+        for _ in 0..3 {
+            producer.push(value.clone()).unwrap();
+        }
+    }
+    assert_eq!(std::sync::Arc::strong_count(&value), 4);
+
+    drop(ring);
+    assert_eq!(std::sync::Arc::strong_count(&value), 1);
+}
+
+#[test]
+#[should_panic(expected = "capacity of the ring must be positive")]
+fn zero_capacity_panics() {
+    let _ = Ring::<usize, 0>::new();
+}
+
+#[test]
+fn concurrent_producer_consumer_preserves_order() {
+    let num_items = 1_000;
+    let mut ring = Ring::<String, 8>::new();
+    let (mut producer, mut consumer) = ring.split();
+
+    std::thread::scope(|s| {
+        s.spawn(move || {
+            for i in 0..num_items {
+                let mut value = i.to_string();
+                while let Err(back) = producer.push(value) {
+                    value = back;
+                    std::thread::yield_now();
+                }
+            }
+        });
+
+        let received = s
+            .spawn(move || {
+                let mut received = Vec::with_capacity(num_items);
+                while received.len() < num_items {
+                    match consumer.pop() {
+                        Some(value) => received.push(value),
+                        None => std::thread::yield_now(),
+                    }
+                }
+                received
+            })
+            .join()
+            .unwrap();
+
+        let expected: Vec<_> = (0..num_items).map(|i| i.to_string()).collect();
+        assert_eq!(received, expected);
+    });
+}
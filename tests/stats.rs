@@ -0,0 +1,101 @@
+#![cfg(feature = "stats")]
+
+use orx_concurrent_option::*;
+
+#[test]
+fn last_exit_concurrent_methods() {
+    let x = ConcurrentOption::<String>::none();
+    assert_eq!(x.last_exit(), None);
+
+    _ = x.take();
+    assert_eq!(x.last_exit(), None);
+
+    _ = x.replace(1.to_string());
+    assert_eq!(x.last_exit(), None);
+
+    _ = x.replace(2.to_string());
+    assert_eq!(x.last_exit(), Some(ExitReason::Replaced));
+
+    x.set_some(3.to_string());
+    assert_eq!(x.last_exit(), Some(ExitReason::Replaced));
+
+    _ = x.take_if(|x| x == "42");
+    assert_eq!(x.last_exit(), Some(ExitReason::Replaced));
+
+    _ = x.take_if(|x| x == "3");
+    assert_eq!(x.last_exit(), Some(ExitReason::Taken));
+
+    _ = unsafe { x.insert(4.to_string()) };
+    _ = unsafe { x.insert(5.to_string()) };
+    assert_eq!(x.last_exit(), Some(ExitReason::Replaced));
+
+    _ = x.take_if_map(|x| x.chars().next());
+    assert_eq!(x.last_exit(), Some(ExitReason::Taken));
+
+    assert!(x.set_lazy(6.to_string()));
+    assert!(x.set_lazy(7.to_string()));
+    assert_eq!(x.last_exit(), Some(ExitReason::Replaced));
+
+    assert_eq!(x.num_values_dropped(), 3);
+}
+
+#[test]
+fn last_exit_exclusive_methods() {
+    let mut x = ConcurrentOption::some(1.to_string());
+
+    _ = x.exclusive_replace(2.to_string());
+    assert_eq!(x.last_exit(), Some(ExitReason::Replaced));
+
+    _ = x.exclusive_insert(3.to_string());
+    assert_eq!(x.last_exit(), Some(ExitReason::Replaced));
+
+    x.exclusive_from_option(Some(4.to_string()));
+    assert_eq!(x.last_exit(), Some(ExitReason::Replaced));
+
+    _ = x.exclusive_take();
+    assert_eq!(x.last_exit(), Some(ExitReason::Taken));
+
+    x.exclusive_reset_to(Some(5.to_string()));
+    assert_eq!(x.last_exit(), Some(ExitReason::Taken));
+
+    x.exclusive_reset_to(Some(6.to_string()));
+    assert_eq!(x.last_exit(), Some(ExitReason::Replaced));
+
+    x.exclusive_reset_to(None);
+    assert_eq!(x.last_exit(), Some(ExitReason::Cleared));
+
+    assert_eq!(x.num_values_dropped(), 4);
+}
+
+#[test]
+fn last_exit_mut_handle() {
+    let x = ConcurrentOption::some(1.to_string());
+
+    let handle = unsafe { x.mut_handle(SOME, SOME) }.unwrap();
+    drop(handle);
+    assert_eq!(x.last_exit(), None);
+
+    let handle = unsafe { x.mut_handle(SOME, NONE) }.unwrap();
+    let value = unsafe { (*handle.value.get()).assume_init_read() };
+    drop(handle);
+    assert_eq!(value, 1.to_string());
+    assert_eq!(x.last_exit(), Some(ExitReason::Taken));
+    assert_eq!(x.num_values_dropped(), 0);
+}
+
+#[test]
+fn num_values_dropped_concurrent() {
+    let x = ConcurrentOption::some(0);
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for i in 0..100 {
+                    x.set_some(i);
+                }
+            });
+        }
+    });
+
+    assert_eq!(x.num_values_dropped(), 400);
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "stress")]
+
+use orx_concurrent_option::{stress::Stress, *};
+
+#[test]
+fn stress_initialize_take_replace() {
+    let option = ConcurrentOption::<String>::none();
+    let is_valid = |x: &String| x == "7" || x == "9";
+
+    let report = Stress::new(&option, 200)
+        .readers(4, |o, _| assert!(o.map(is_valid).unwrap_or(true)))
+        .writers(2, |o, i| match i % 2 {
+            0 => _ = o.replace(7.to_string()),
+            _ => _ = o.initialize_if_none(9.to_string()),
+        })
+        .takers(2, |o, i| {
+            if i % 3 == 0 {
+                _ = o.take();
+            }
+        })
+        .invariant(|o| o.map(is_valid).unwrap_or(true))
+        .run();
+
+    assert_eq!(report.num_threads, 8);
+    assert_eq!(report.num_actions, 8 * 200);
+    assert_eq!(report.num_invariant_checks, 8 * 200);
+}
+
+#[test]
+fn stress_exponential_update_counter() {
+    let option = ConcurrentOption::some(0usize);
+
+    let reports = Stress::new(&option, 100)
+        .writers(1, |o, _| _ = o.update_if_some(|x| *x += 1))
+        .readers(1, |o, _| assert!(o.is_some()))
+        .run_exponential(4);
+
+    let num_threads: Vec<_> = reports.iter().map(|r| r.num_threads).collect();
+    assert_eq!(num_threads, [2, 4, 8, 16]);
+
+    let num_updates = (1 + 2 + 4 + 8) * 100;
+    assert_eq!(option.map(|x| *x), Some(num_updates));
+}
+
+#[test]
+#[should_panic]
+fn stress_invariant_violation() {
+    let option = ConcurrentOption::some(0usize);
+    Stress::new(&option, 10)
+        .writers(2, |o, _| _ = o.update_if_some(|x| *x += 1))
+        .invariant(|o| o.map(|x| *x < 5).unwrap_or(true))
+        .run();
+}
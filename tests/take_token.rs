@@ -0,0 +1,72 @@
+#![cfg(feature = "generation")]
+
+use orx_concurrent_option::*;
+
+#[test]
+fn take_tagged_and_reinstall() {
+    let x = ConcurrentOption::some(1.to_string());
+    let mut watcher = x.watcher();
+
+    let (value, token) = x.take_tagged().unwrap();
+    assert!(watcher.has_changed());
+    assert!(x.take_tagged().is_none());
+
+    assert_eq!(x.reinstall(token, value + "!"), Ok(()));
+    assert!(watcher.has_changed());
+    assert_eq!(x.take(), Some("1!".to_string()));
+}
+
+#[test]
+fn reinstall_fails_after_intervening_writer() {
+    let x = ConcurrentOption::some(1);
+    let (value, token) = x.take_tagged().unwrap();
+
+    assert!(x.initialize_if_none(2));
+    assert_eq!(x.reinstall(token, value), Err(1));
+    assert_eq!(x.take(), Some(2));
+
+    assert!(x.initialize_if_none(3));
+    let (value, token) = x.take_tagged().unwrap();
+    assert!(x.initialize_if_none(4));
+    _ = x.take();
+    let mut watcher = x.watcher();
+    assert_eq!(x.reinstall(token, value), Err(3));
+    assert!(!watcher.has_changed());
+    assert!(x.is_none());
+}
+
+#[test]
+fn single_check_in_among_concurrent_takers() {
+    let x = ConcurrentOption::some(0usize);
+    let num_threads = 4;
+    let num_iters = 100;
+
+    std::thread::scope(|s| {
+        for _ in 0..num_threads {
+            s.spawn(|| {
+                let mut num_checked_in = 0;
+                while num_checked_in < num_iters {
+                    if let Some((value, token)) = x.take_tagged() {
+                        assert_eq!(x.reinstall(token, value + 1), Ok(()));
+                        num_checked_in += 1;
+                    }
+                }
+            });
+        }
+    });
+
+    assert_eq!(x.take(), Some(num_threads * num_iters));
+}
+
+#[test]
+fn reinstall_fails_on_another_option() {
+    let x = ConcurrentOption::some(1);
+    let y = ConcurrentOption::some(2);
+    let (value, token) = x.take_tagged().unwrap();
+    _ = y.take();
+
+    // both options are None and at the same generation
+    assert_eq!(y.reinstall(token, value), Err(1));
+    assert!(y.is_none());
+    assert!(x.is_none());
+}
@@ -0,0 +1,55 @@
+use orx_concurrent_option::testing::*;
+use std::cell::Cell;
+
+#[test]
+fn parity_of_reads() {
+    for initial in [None, Some(3.to_string())] {
+        assert_option_parity(initial, |x| (x.is_some(), x.is_none()));
+        assert_option_parity(Some(1), |x| x.as_mut().map(|x| *x));
+    }
+}
+
+#[test]
+fn parity_of_mutations() {
+    for initial in [None, Some(3), Some(42)] {
+        assert_option_parity(initial, |x| x.take());
+        assert_option_parity(initial, |x| x.replace(7));
+        assert_option_parity(initial, |x| *x.insert(7));
+        assert_option_parity(initial, |x| *x.get_or_insert(7));
+        assert_option_parity(initial, |x| *x.get_or_insert_with(&mut || 7));
+        assert_option_parity(initial, |x| x.take_if(&mut |x| *x > 10));
+        assert_option_parity(initial, |x| {
+            x.take_if(&mut |x| {
+                *x += 100;
+                false
+            })
+        });
+    }
+}
+
+#[test]
+fn parity_of_sequences() {
+    for initial in [None, Some(vec![1])] {
+        assert_option_parity(initial, |x| {
+            let mut results = vec![];
+            results.push(x.replace(vec![2]));
+            if let Some(x) = x.as_mut() {
+                x.push(3);
+            }
+            results.push(x.take_if(&mut |x| x.len() > 5));
+            results.push(x.take());
+            results.push(Some(x.get_or_insert_with(&mut || vec![4]).clone()));
+            results
+        });
+    }
+}
+
+#[test]
+#[should_panic(expected = "results of ConcurrentOption (left) and Option (right) differ")]
+fn parity_violation_panics() {
+    let num_calls = Cell::new(0);
+    assert_option_parity(Some(1), |x| {
+        num_calls.set(num_calls.get() + 1);
+        x.is_some() && num_calls.get() == 1
+    });
+}
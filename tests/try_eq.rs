@@ -0,0 +1,71 @@
+use orx_concurrent_option::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[test]
+fn try_eq_variants() {
+    let x = ConcurrentOption::some(3.to_string());
+    let y = ConcurrentOption::some(3.to_string());
+    let z = ConcurrentOption::some(7.to_string());
+    let n = ConcurrentOption::<String>::none();
+
+    assert_eq!(x.try_eq(&x), Some(true));
+    assert_eq!(x.try_eq(&y), Some(true));
+    assert_eq!(x.try_eq(&z), Some(false));
+    assert_eq!(x.try_eq(&n), Some(false));
+    assert_eq!(n.try_eq(&x), Some(false));
+    assert_eq!(n.try_eq(&n), Some(true));
+}
+
+#[test]
+fn try_eq_frozen() {
+    let x = ConcurrentOption::some(3);
+    let y = ConcurrentOption::some(3);
+    _ = x.freeze();
+    assert_eq!(x.try_eq(&y), Some(true));
+    assert_eq!(y.try_eq(&x), Some(true));
+}
+
+#[test]
+fn try_eq_reserved_returns_none() {
+    let x = ConcurrentOption::some(3);
+    let y = ConcurrentOption::<i32>::none();
+
+    let handle = unsafe { y.mut_handle(NONE, NONE) };
+    assert_eq!(x.try_eq(&y), None);
+    assert_eq!(y.try_eq(&x), None);
+    assert_eq!(y.try_eq(&y), None);
+    drop(handle);
+    assert_eq!(x.try_eq(&y), Some(false));
+
+    let guard = x.lock_mut();
+    assert_eq!(x.try_eq(&x), None);
+    drop(guard);
+    assert_eq!(x.try_eq(&x), Some(true));
+}
+
+#[test]
+fn try_eq_does_not_block_behind_writers() {
+    let x = ConcurrentOption::some(0);
+    let y = ConcurrentOption::some(0);
+    let done = AtomicBool::new(false);
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let handle = unsafe { x.mut_handle(SOME, SOME) };
+            while !done.load(Ordering::Relaxed) {
+                std::thread::yield_now();
+            }
+            drop(handle);
+        });
+
+        while x.state(LoadOrdering::Acquire) != State::Reserved {
+            std::thread::yield_now();
+        }
+        for _ in 0..100 {
+            assert_eq!(x.try_eq(&y), None);
+        }
+        done.store(true, Ordering::Relaxed);
+    });
+
+    assert_eq!(x.try_eq(&y), Some(true));
+}
@@ -0,0 +1,249 @@
+#![cfg(feature = "std")]
+
+use orx_concurrent_option::*;
+#[cfg(feature = "async")]
+use std::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+#[cfg(feature = "async")]
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::yield_now(),
+        }
+    }
+}
+
+#[test]
+fn wait_for_none_returns_immediately_when_none() {
+    let x = ConcurrentOption::<String>::none();
+    x.wait_for_none();
+}
+
+#[test]
+fn wait_for_none_single_slot_backpressure() {
+    let slot = ConcurrentOption::none();
+    let num_items = 1000;
+
+    let consumed: Vec<_> = std::thread::scope(|s| {
+        s.spawn(|| {
+            for i in 0..num_items {
+                slot.wait_for_none();
+                assert!(slot.initialize_if_none(i));
+            }
+        });
+
+        let consumer = s.spawn(|| {
+            let mut consumed = vec![];
+            while consumed.len() < num_items {
+                match slot.take() {
+                    Some(x) => consumed.push(x),
+                    None => std::thread::yield_now(),
+                }
+            }
+            consumed
+        });
+
+        consumer.join().unwrap()
+    });
+
+    assert_eq!(consumed, (0..num_items).collect::<Vec<_>>());
+}
+
+#[test]
+fn wait_for_some_returns_immediately_when_some() {
+    let x = ConcurrentOption::some(3.to_string());
+    assert_eq!(x.wait_for_some().get(), "3");
+
+    let mut x = ConcurrentOption::some(7);
+    _ = x.freeze();
+    assert_eq!(*x.wait_for_some(), 7);
+    assert_eq!(x.exclusive_take(), Some(7));
+}
+
+#[test]
+fn wait_for_some_waits_for_initialization() {
+    let num_readers = 8;
+    let x = ConcurrentOption::<String>::none();
+
+    std::thread::scope(|s| {
+        let readers: Vec<_> = (0..num_readers)
+            .map(|_| s.spawn(|| x.wait_for_some().clone()))
+            .collect();
+
+        s.spawn(|| {
+            let _handle = unsafe { x.mut_handle(NONE, NONE) }; // reserved for a while
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        });
+        s.spawn(|| {
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            x.initialize_if_none(42.to_string());
+        });
+
+        for reader in readers {
+            assert_eq!(reader.join().unwrap(), 42.to_string());
+        }
+    });
+}
+
+#[test]
+fn wait_for_some_blocks_writers_until_dropped() {
+    let x = ConcurrentOption::some(1);
+    let value = x.wait_for_some();
+
+    std::thread::scope(|s| {
+        let taker = s.spawn(|| x.take());
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        assert_eq!(*value, 1);
+        drop(value);
+        assert_eq!(taker.join().unwrap(), Some(1));
+    });
+}
+
+#[test]
+fn wait_and_take_returns_immediately_when_some() {
+    let x = ConcurrentOption::some(3.to_string());
+    assert_eq!(x.wait_and_take(), 3.to_string());
+    assert!(x.is_none());
+}
+
+#[test]
+#[should_panic]
+fn wait_and_take_panics_when_frozen() {
+    let x = ConcurrentOption::some(3);
+    _ = x.freeze();
+    _ = x.wait_and_take();
+}
+
+#[test]
+fn wait_and_take_rendezvous() {
+    let num_producers = 4;
+    let num_consumers = 4;
+    let num_items_per_producer = 250;
+    let slot = ConcurrentOption::none();
+
+    let mut consumed: Vec<_> = std::thread::scope(|s| {
+        for p in 0..num_producers {
+            let slot = &slot;
+            s.spawn(move || {
+                for i in 0..num_items_per_producer {
+                    let value = p * num_items_per_producer + i;
+                    while !slot.initialize_if_none(value) {
+                        slot.wait_for_none();
+                    }
+                }
+            });
+        }
+
+        let num_items_per_consumer = num_producers * num_items_per_producer / num_consumers;
+        let consumers: Vec<_> = (0..num_consumers)
+            .map(|_| {
+                let slot = &slot;
+                s.spawn(move || {
+                    (0..num_items_per_consumer)
+                        .map(|_| slot.wait_and_take())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        consumers
+            .into_iter()
+            .flat_map(|c| c.join().unwrap())
+            .collect()
+    });
+
+    consumed.sort();
+    assert_eq!(
+        consumed,
+        (0..num_producers * num_items_per_producer).collect::<Vec<_>>()
+    );
+    assert!(slot.is_none());
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn wait_for_none_async_waits_while_some_or_reserved() {
+    let x = ConcurrentOption::some(42);
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut waiting = pin!(x.wait_for_none_async());
+
+    assert_eq!(waiting.as_mut().poll(&mut cx), Poll::Pending);
+
+    {
+        let _handle = unsafe { x.mut_handle(SOME, NONE) };
+        assert_eq!(waiting.as_mut().poll(&mut cx), Poll::Pending);
+    }
+
+    assert_eq!(waiting.as_mut().poll(&mut cx), Poll::Ready(()));
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn wait_for_none_async_registers_until_dropped() {
+    struct NoopWake;
+    impl std::task::Wake for NoopWake {
+        fn wake(self: std::sync::Arc<Self>) {}
+    }
+
+    let x = ConcurrentOption::some(42);
+    let waker = Waker::from(std::sync::Arc::new(NoopWake));
+    let mut cx = Context::from_waker(&waker);
+    {
+        let mut waiting = pin!(x.wait_for_none_async());
+        assert_eq!(waiting.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(waiting.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(x.waiter_count(), 1);
+    }
+    assert_eq!(x.waiter_count(), 0);
+    assert_eq!(x.take(), Some(42));
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn wait_for_none_async_deregisters_when_taken_during_registration() {
+    use std::task::{RawWaker, RawWakerVTable};
+
+    // the waker takes the value when it is cloned, which happens while the future registers it;
+    // hence, the re-check right after the registration observes the option as None
+    fn clone(data: *const ()) -> RawWaker {
+        let option = unsafe { &*(data as *const ConcurrentOption<u32>) };
+        _ = option.take();
+        RawWaker::new(data, &VTABLE)
+    }
+    fn noop(_: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+    let x = ConcurrentOption::some(42u32);
+    let raw = RawWaker::new(&x as *const ConcurrentOption<u32> as *const (), &VTABLE);
+    let waker = unsafe { Waker::from_raw(raw) };
+    let mut cx = Context::from_waker(&waker);
+
+    let mut waiting = pin!(x.wait_for_none_async());
+    assert_eq!(waiting.as_mut().poll(&mut cx), Poll::Ready(()));
+    assert_eq!(x.waiter_count(), 0);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn wait_for_none_async_concurrent_consumer() {
+    let slot = ConcurrentOption::some(0);
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            while slot.take().is_none() {
+                std::thread::yield_now();
+            }
+        });
+
+        block_on(slot.wait_for_none_async());
+    });
+
+    assert!(slot.is_none());
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "std")]
+
+use orx_concurrent_option::*;
+use std::time::{Duration, Instant};
+
+/// Gives the waiting threads enough time to pass the spinning and yielding phases and park.
+fn let_waiters_park() {
+    std::thread::sleep(Duration::from_millis(50));
+}
+
+#[test]
+fn initialize_if_none_wakes_wait_for_some() {
+    let num_readers = 8;
+    let x = ConcurrentOption::<String>::none();
+
+    std::thread::scope(|s| {
+        let readers: Vec<_> = (0..num_readers)
+            .map(|_| s.spawn(|| x.wait_for_some().clone()))
+            .collect();
+
+        let_waiters_park();
+        assert!(x.initialize_if_none(7.to_string()));
+
+        for reader in readers {
+            assert_eq!(reader.join().unwrap(), 7.to_string());
+        }
+    });
+}
+
+#[test]
+fn take_wakes_wait_for_none() {
+    let x = ConcurrentOption::some(3);
+
+    std::thread::scope(|s| {
+        let waiter = s.spawn(|| x.wait_for_none());
+
+        let_waiters_park();
+        assert_eq!(x.take(), Some(3));
+        waiter.join().unwrap();
+    });
+}
+
+#[test]
+fn replace_wakes_waiters_of_reserved_option() {
+    let x = ConcurrentOption::some(1);
+
+    std::thread::scope(|s| {
+        let handle = unsafe { x.mut_handle(SOME, NONE) }.unwrap();
+        let waiter = s.spawn(|| x.wait_and_take());
+
+        let_waiters_park();
+        drop(handle);
+        assert_eq!(x.replace(2), None);
+        assert_eq!(waiter.join().unwrap(), 2);
+    });
+}
+
+#[test]
+fn parked_waiters_are_woken_promptly() {
+    let x = ConcurrentOption::none();
+
+    std::thread::scope(|s| {
+        let waiter = s.spawn(|| {
+            let value = x.wait_and_take();
+            (value, Instant::now())
+        });
+
+        let_waiters_park();
+        let initialized_at = Instant::now();
+        assert!(x.initialize_if_none(42));
+
+        let (value, woken_at) = waiter.join().unwrap();
+        assert_eq!(value, 42);
+        assert!(woken_at.duration_since(initialized_at) < Duration::from_secs(1));
+    });
+}
+
+#[test]
+fn waiters_of_distinct_options_are_independent() {
+    let options: Vec<_> = (0..256).map(|_| ConcurrentOption::none()).collect();
+
+    std::thread::scope(|s| {
+        let waiters: Vec<_> = options
+            .iter()
+            .step_by(16)
+            .map(|x| s.spawn(|| x.wait_and_take()))
+            .collect();
+
+        let_waiters_park();
+        for (i, x) in options.iter().enumerate() {
+            assert!(x.initialize_if_none(i));
+        }
+
+        let taken: Vec<_> = waiters.into_iter().map(|x| x.join().unwrap()).collect();
+        assert_eq!(taken, (0..256).step_by(16).collect::<Vec<_>>());
+    });
+}
+
+#[test]
+fn waiter_count_counts_parked_threads() {
+    let num_waiters = 4;
+    let x = ConcurrentOption::<usize>::none();
+    assert_eq!(x.waiter_count(), 0);
+
+    std::thread::scope(|s| {
+        let waiters: Vec<_> = (0..num_waiters)
+            .map(|_| s.spawn(|| *x.wait_for_some()))
+            .collect();
+
+        while x.waiter_count() < num_waiters {
+            std::thread::yield_now();
+        }
+        #[cfg(not(any(feature = "futex", feature = "parking_lot")))]
+        assert_eq!(x.waiter_count(), num_waiters);
+
+        assert!(x.initialize_if_none(42));
+        for waiter in waiters {
+            assert_eq!(waiter.join().unwrap(), 42);
+        }
+    });
+
+    #[cfg(not(any(feature = "futex", feature = "parking_lot")))]
+    assert_eq!(x.waiter_count(), 0);
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "generation")]
+
+use orx_concurrent_option::*;
+
+#[test]
+fn watcher_concurrent_mutations() {
+    let x = ConcurrentOption::<String>::none();
+    let mut watcher = x.watcher();
+    assert!(!watcher.has_changed());
+
+    assert!(x.initialize_if_none(3.to_string()));
+    assert!(watcher.has_changed());
+
+    assert!(!x.initialize_if_none(7.to_string()));
+    assert!(!watcher.has_changed());
+
+    assert!(x.update_if_some(|x| x.push('!')));
+    assert!(watcher.has_changed());
+
+    assert_eq!(x.replace(4.to_string()), Some("3!".to_string()));
+    assert!(watcher.has_changed());
+
+    assert_eq!(x.take_if(|x| x == "7"), None);
+    assert!(watcher.has_changed());
+
+    assert_eq!(x.take(), Some(4.to_string()));
+    assert!(watcher.has_changed());
+
+    assert_eq!(x.take(), None);
+    assert!(!watcher.has_changed());
+}
+
+#[test]
+fn watcher_reads_are_not_changes() {
+    let x = ConcurrentOption::some(3.to_string());
+    let mut watcher = x.watcher();
+
+    assert_eq!(x.map(|x| x.len()), Some(1));
+    assert!(x.is_some_and(|x| x == "3"));
+    assert_eq!(x.clone_into_option(), Some(3.to_string()));
+    assert_eq!(unsafe { x.as_ref() }, Some(&3.to_string()));
+    assert!(x.get_raw().is_some());
+
+    assert!(!watcher.has_changed());
+}
+
+#[test]
+fn watcher_mut_handle_is_change() {
+    let x = ConcurrentOption::some(3.to_string());
+    let mut watcher = x.watcher();
+
+    let handle = unsafe { x.mut_handle(SOME, SOME) };
+    assert!(!watcher.has_changed());
+    drop(handle);
+    assert!(watcher.has_changed());
+}
+
+#[test]
+fn watcher_multiple_watchers() {
+    let x = ConcurrentOption::some(3);
+    let mut first = x.watcher();
+    let mut second = x.watcher();
+
+    x.update_if_some(|x| *x += 1);
+
+    assert!(first.has_changed());
+    assert!(!first.has_changed());
+
+    let mut third = x.watcher();
+    assert!(!third.has_changed());
+
+    assert!(second.has_changed());
+    assert!(!second.has_changed());
+}
+
+#[test]
+fn watcher_with_concurrent_writers() {
+    let x = ConcurrentOption::<usize>::none();
+    let mut watcher = x.watcher();
+
+    std::thread::scope(|s| {
+        for i in 0..4 {
+            let x = &x;
+            s.spawn(move || {
+                for j in 0..100 {
+                    match j % 2 {
+                        0 => _ = x.replace(i * j),
+                        _ => _ = x.take(),
+                    }
+                }
+            });
+        }
+    });
+
+    assert!(watcher.has_changed());
+    assert!(!watcher.has_changed());
+}
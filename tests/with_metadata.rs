@@ -0,0 +1,61 @@
+use core::sync::atomic::AtomicU64;
+use orx_concurrent_option::*;
+
+#[test]
+fn with_metadata_sequential() {
+    let x = ConcurrentOptionWith::<String, AtomicU64>::none(0);
+    assert_eq!(x.metadata(), 0);
+    assert!(!x.update_if_some_with_metadata(|_, m| *m += 1));
+    assert_eq!(x.metadata(), 0);
+
+    assert_eq!(x.replace_with_metadata("a".to_string(), 1), None);
+    assert_eq!(x.metadata(), 1);
+
+    assert!(x.update_if_some_with_metadata(|v, m| {
+        v.push('b');
+        *m += 1;
+    }));
+    assert_eq!(
+        x.map_with_metadata(|v, m| (v.cloned(), m)),
+        (Some("ab".to_string()), 2)
+    );
+
+    assert_eq!(x.take_with_metadata(10), Some("ab".to_string()));
+    assert_eq!(x.take_with_metadata(20), None);
+    assert_eq!(x.into_parts(), (None, 10));
+}
+
+#[test]
+fn with_metadata_concurrent_consistency() {
+    let num_threads = 8;
+    let num_updates = 1000;
+
+    let x = ConcurrentOptionWith::<u64, AtomicU64>::some(0, 0);
+
+    std::thread::scope(|s| {
+        for _ in 0..num_threads {
+            s.spawn(|| {
+                for _ in 0..num_updates {
+                    x.update_if_some_with_metadata(|v, m| {
+                        *v += 1;
+                        *m += 1;
+                    });
+                    x.map_with_metadata(|v, m| assert_eq!(v.copied(), Some(m)));
+                }
+            });
+        }
+    });
+
+    let expected = num_threads * num_updates;
+    assert_eq!(x.into_parts(), (Some(expected), expected));
+}
+
+#[test]
+fn with_metadata_option_access() {
+    let x = ConcurrentOptionWith::<i32, AtomicU64>::some(42, 7);
+    assert_eq!(x.option().map(|x| *x), Some(42));
+    assert_eq!(x.option().state(LoadOrdering::Relaxed), State::Some);
+
+    let y: ConcurrentOption<i32> = x.into();
+    assert_eq!(y.into_option(), Some(42));
+}
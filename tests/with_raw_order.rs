@@ -0,0 +1,38 @@
+use core::sync::atomic::Ordering;
+use orx_concurrent_option::*;
+
+#[test]
+fn with_raw_order_matches_with_order() {
+    let x = ConcurrentOption::some(3.to_string());
+    let y = ConcurrentOption::some(4.to_string());
+
+    for order in [Ordering::Relaxed, Ordering::Acquire, Ordering::SeqCst] {
+        let load_order = LoadOrdering::try_from(order).unwrap();
+        unsafe {
+            assert_eq!(x.state_with_raw_order(order), x.state(load_order));
+            assert!(x.is_some_with_raw_order(order));
+            assert!(!x.is_none_with_raw_order(order));
+            assert_eq!(x.as_ref_with_raw_order(order), Some(&3.to_string()));
+            assert_eq!(x.as_deref_with_raw_order(order), Some("3"));
+            assert_eq!(x.iter_with_raw_order(order).count(), 1);
+            assert_eq!(x.get_cloned_with_raw_order(order), Some(3.to_string()));
+            assert!(!x.eq_with_raw_order(&y, order));
+            assert!(x.eq_snapshot_with_raw_order(&Some(3.to_string()), order));
+            assert_eq!(
+                x.cmp_with_raw_order(&y, order),
+                x.cmp_with_order(&y, load_order)
+            );
+            assert_eq!(
+                x.get_raw_with_raw_order(order),
+                x.get_raw_with_order(load_order)
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn with_raw_order_invalid_ordering_panics() {
+    let x = ConcurrentOption::some(3);
+    _ = unsafe { x.is_some_with_raw_order(Ordering::Release) };
+}
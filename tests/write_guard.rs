@@ -0,0 +1,183 @@
+use orx_concurrent_option::*;
+
+#[test]
+fn upgrade_then_downgrade() {
+    let slots = [ConcurrentOption::some(1.to_string())];
+    #[cfg(feature = "generation")]
+    let mut watcher = slots[0].watcher();
+
+    let (_, guard) = ConcurrentOption::iter_guards(&slots).next().unwrap();
+    assert_eq!(guard.as_str(), "1");
+
+    let mut guard = guard.try_upgrade().unwrap();
+    guard.push('!');
+    assert_eq!(format!("{:?}", guard), "WriteGuard(\"1!\")");
+
+    let guard = guard.downgrade();
+    #[cfg(feature = "generation")]
+    assert!(watcher.has_changed());
+    assert_eq!(guard.as_str(), "1!");
+    assert_eq!(format!("{:?}", guard), "ReadGuard(\"1!\")");
+
+    drop(guard);
+    #[cfg(feature = "generation")]
+    assert!(!watcher.has_changed());
+    assert_eq!(slots[0].take(), Some("1!".to_string()));
+}
+
+#[test]
+fn read_guard_without_upgrade_does_not_bump_generation() {
+    let slots = [ConcurrentOption::some(3)];
+    #[cfg(feature = "generation")]
+    let mut watcher = slots[0].watcher();
+
+    let (_, guard) = ConcurrentOption::iter_guards(&slots).next().unwrap();
+    assert_eq!(*guard, 3);
+    drop(guard);
+
+    #[cfg(feature = "generation")]
+    assert!(!watcher.has_changed());
+}
+
+#[test]
+fn writers_are_blocked_during_upgrade_and_downgrade() {
+    let slots = [ConcurrentOption::some(3)];
+    let option = &slots[0];
+
+    let (_, guard) = ConcurrentOption::iter_guards(&slots).next().unwrap();
+    assert_eq!(option.state(LoadOrdering::Acquire), State::Some);
+    assert_eq!(option.try_take(16), Err(Contended(())));
+
+    let mut guard = guard.try_upgrade().unwrap();
+    assert_eq!(option.state(LoadOrdering::Acquire), State::Reserved);
+    *guard = 42;
+
+    let guard = guard.downgrade();
+    assert_eq!(option.state(LoadOrdering::Acquire), State::Some);
+    assert_eq!(option.try_take(16), Err(Contended(())));
+    assert_eq!(option.get_cloned(), Some(42));
+    assert_eq!(*guard, 42);
+
+    drop(guard);
+    assert_eq!(option.state(LoadOrdering::Acquire), State::Some);
+    assert_eq!(option.take(), Some(42));
+}
+
+#[test]
+fn lock_mut() {
+    let x = ConcurrentOption::<String>::none();
+    assert!(x.lock_mut().is_none());
+
+    _ = x.initialize_if_none(1.to_string());
+    #[cfg(feature = "generation")]
+    let mut watcher = x.watcher();
+
+    let mut guard = x.lock_mut().unwrap();
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Reserved);
+    guard.push('!');
+    guard.push('?');
+    drop(guard);
+
+    #[cfg(feature = "generation")]
+    assert!(watcher.has_changed());
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Some);
+    assert_eq!(x.take(), Some("1!?".to_string()));
+}
+
+#[test]
+fn lock_mut_concurrent_increments() {
+    let x = ConcurrentOption::some((0usize, 0usize));
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..100 {
+                    let mut guard = x.lock_mut().unwrap();
+                    guard.0 += 1;
+                    std::thread::yield_now();
+                    guard.1 += 1;
+                    assert_eq!(guard.0, guard.1);
+                }
+            });
+        }
+    });
+
+    assert_eq!(x.take(), Some((400, 400)));
+}
+
+#[test]
+fn try_lock() {
+    let x = ConcurrentOption::<String>::none();
+    assert!(matches!(x.try_lock(), Ok(None)));
+
+    _ = x.initialize_if_none(1.to_string());
+    #[cfg(feature = "generation")]
+    let mut watcher = x.watcher();
+
+    let mut guard = x.try_lock().unwrap().unwrap();
+    assert_eq!(x.state(LoadOrdering::Acquire), State::Reserved);
+    assert!(matches!(x.try_lock(), Err(Contended(()))));
+    guard.push('!');
+    drop(guard);
+
+    #[cfg(feature = "generation")]
+    assert!(watcher.has_changed());
+    assert_eq!(x.take(), Some("1!".to_string()));
+}
+
+#[test]
+fn try_lock_concurrent_increments() {
+    let x = ConcurrentOption::some((0usize, 0usize));
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                let mut num_locked = 0;
+                while num_locked < 100 {
+                    match x.try_lock() {
+                        Ok(Some(mut guard)) => {
+                            guard.0 += 1;
+                            guard.1 += 1;
+                            num_locked += 1;
+                        }
+                        Ok(None) => unreachable!(),
+                        Err(Contended(())) => std::thread::yield_now(),
+                    }
+                }
+            });
+        }
+    });
+
+    assert_eq!(x.take(), Some((400, 400)));
+}
+
+#[test]
+fn downgrade_lets_readers_proceed() {
+    let x = ConcurrentOption::some(1);
+    #[cfg(feature = "generation")]
+    let mut watcher = x.watcher();
+
+    let mut guard = x.lock_mut().unwrap();
+    *guard = 2;
+    let value = guard.downgrade();
+    assert_eq!(format!("{:?}", value), "ReadGuard(2)");
+    #[cfg(feature = "generation")]
+    assert!(watcher.has_changed());
+    assert_eq!(*value, 2);
+
+    // shared readers and further guards do not block
+    assert_eq!(x.get_cloned(), Some(2));
+    let other = x.get_guard().unwrap();
+    assert_eq!(*other, 2);
+
+    // writers wait until the guards are dropped
+    std::thread::scope(|s| {
+        let taker = s.spawn(|| x.take());
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(*value, 2);
+        drop(value);
+        drop(other);
+        assert_eq!(taker.join().unwrap(), Some(2));
+    });
+    assert!(x.is_none());
+}
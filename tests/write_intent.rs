@@ -0,0 +1,73 @@
+#![cfg(feature = "write-intent")]
+
+use orx_concurrent_option::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[test]
+fn write_intent_multiple_writers() {
+    let x = ConcurrentOption::some(3);
+    assert!(!x.is_writer_pending());
+
+    let first = x.announce_write();
+    let second = x.announce_write();
+    assert!(x.is_writer_pending());
+
+    drop(first);
+    assert!(x.is_writer_pending());
+
+    drop(second);
+    assert!(!x.is_writer_pending());
+}
+
+#[test]
+fn write_intent_does_not_change_behavior() {
+    let x = ConcurrentOption::some(3);
+    let _intent = x.announce_write();
+
+    assert_eq!(x.map(|x| *x), Some(3));
+    assert!(x.update_if_some(|x| *x += 1));
+    assert_eq!(x.take(), Some(4));
+    assert!(x.initialize_if_none(7));
+    assert_eq!(x.clone_into_option(), Some(7));
+}
+
+#[test]
+fn write_intent_readers_back_off() {
+    let x = ConcurrentOption::some(0usize);
+    let done = AtomicBool::new(false);
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                while !done.load(Ordering::Relaxed) {
+                    if x.is_writer_pending() {
+                        std::thread::yield_now();
+                        continue;
+                    }
+                    assert!(x.map(|x| *x <= 100).unwrap_or(false));
+                }
+            });
+        }
+
+        s.spawn(|| {
+            for _ in 0..100 {
+                let _intent = x.announce_write();
+                assert!(x.update_if_some(|x| *x += 1));
+            }
+            done.store(true, Ordering::Relaxed);
+        });
+    });
+
+    assert!(!x.is_writer_pending());
+    assert_eq!(x.take(), Some(100));
+}
+
+#[test]
+#[should_panic]
+fn write_intent_count_does_not_wrap() {
+    let x = ConcurrentOption::some(3);
+    let intents: Vec<_> = (0..u16::MAX).map(|_| x.announce_write()).collect();
+    assert!(x.is_writer_pending());
+    let _one_too_many = x.announce_write();
+    drop(intents);
+}